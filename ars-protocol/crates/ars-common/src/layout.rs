@@ -0,0 +1,8 @@
+/// Upgrade space at the end of every program account
+///
+/// Each `#[account]` struct ends with `_reserved: [u8; ACCOUNT_RESERVED_LEN]`,
+/// zeroed when the account is created. A later release adds a field by
+/// declaring it before `_reserved` and shrinking `_reserved` by its size, so
+/// the account keeps its length and existing accounts read the new field as
+/// zero instead of needing a realloc migration.
+pub const ACCOUNT_RESERVED_LEN: usize = 64;
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+pub mod layout;
+pub mod log;
+pub mod params;
+pub mod reentrancy;
+
+pub use layout::*;
+pub use params::*;
+pub use reentrancy::*;
+
+#[error_code(offset = 9000)]
+pub enum CommonError {
+    #[msg("Reentrant call rejected")]
+    Reentrancy,
+    #[msg("Unknown registry parameter")]
+    UnknownParam,
+    #[msg("Registry parameter read as the wrong kind")]
+    ParamKindMismatch,
+    #[msg("Registry parameter out of range")]
+    ParamOutOfRange,
+    #[msg("Parameter registry is full")]
+    RegistryFull,
+    #[msg("No registry parameter update pending")]
+    NoPendingParam,
+    #[msg("Registry parameter timelock has not expired")]
+    ParamTimelockActive,
+    #[msg("Invalid parameter registry account")]
+    InvalidRegistry,
+}
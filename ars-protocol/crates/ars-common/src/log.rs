@@ -0,0 +1,34 @@
+/// Emit an Anchor event and log it as `EventName key=value ...`
+///
+/// Takes the same struct literal `emit!` does. The program log line gives
+/// log-based monitoring a stable, greppable format without decoding the
+/// base64 event data; values are written with their `Debug` form, so
+/// pubkeys come out in base58.
+#[macro_export]
+macro_rules! log_event {
+    ($event:ident { $($field:ident $(: $value:expr)?),* $(,)? }) => {{
+        let event = $event { $($field $(: $value)?),* };
+        ::anchor_lang::prelude::msg!(
+            concat!(stringify!($event) $(, " ", stringify!($field), "={:?}")*)
+            $(, event.$field)*
+        );
+        ::anchor_lang::prelude::emit!(event);
+    }};
+}
+
+/// Log intermediate values as `trace name key=value ...` when the calling
+/// program is built with its `trace` feature
+///
+/// Meant for the intermediate math (caps, VHR inputs, consensus values) and
+/// for devnet builds only. Compiles to nothing otherwise, so the arguments
+/// are not evaluated and mainnet builds pay no compute for them.
+#[macro_export]
+macro_rules! trace {
+    ($name:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        #[cfg(feature = "trace")]
+        ::anchor_lang::prelude::msg!(
+            concat!("trace ", $name $(, " ", stringify!($key), "={:?}")*)
+            $(, $value)*
+        );
+    }};
+}
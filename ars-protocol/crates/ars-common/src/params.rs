@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+
+use crate::CommonError;
+
+/// Protocol parameter registry
+///
+/// Tunable caps, thresholds, intervals and fees live in one versioned
+/// ParamTable, stored by ars-core in its ParameterRegistry PDA. Each ParamKey
+/// has a stable numeric id, a kind, bounds and the default used until
+/// governance sets a value, so adding a parameter means adding a key here; no
+/// account layout changes. Any program can read the table from the registry
+/// account with `read_param_table` and the typed getters below.
+///
+/// Scope: every parameter added since the registry is a key here, and new
+/// parameters must be too. The ProtocolParam fields that predate it (mint/burn
+/// cap, stability fee, VHR threshold, epoch duration, reputation and accuracy
+/// curves, stake minimums and the inactivity periods) stay on GlobalState:
+/// they change through ParamUpdate proposals with optimistic bands and
+/// emergency rollback, which address them by ProtocolParam, so moving them
+/// would change the proposal payload format as well as the account layout.
+
+/// Entries a registry account has room for
+pub const MAX_REGISTRY_PARAMS: usize = 64;
+
+/// What a parameter's value measures, checked by the typed getters
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParamKind {
+    /// Token amount in base units
+    Amount,
+    /// Basis points (0-10000)
+    Bps,
+    /// Duration in seconds
+    Seconds,
+    /// Agent tier, as its discriminant (0 = Bronze)
+    Tier,
+    /// Agent reputation score
+    Reputation,
+}
+
+/// Registry keys. Ids are stored on chain and must never be reused.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u16)]
+pub enum ParamKey {
+    /// Fee on the ARU leg of outcome market buys and sells
+    MarketFeeBps = 0,
+    /// Time an agent has to appeal a proposed slash
+    SlashAppealWindow = 1,
+    /// Slashes above this amount need a second party
+    SlashConfirmationThreshold = 2,
+    /// Lowest tier allowed to submit ILI updates
+    IliSubmissionMinTier = 3,
+    /// Lowest tier allowed to trigger the circuit breaker regardless of
+    /// reputation
+    BreakerTriggerMinTier = 4,
+    /// Reputation that lets an agent below BreakerTriggerMinTier trigger the
+    /// circuit breaker
+    BreakerTriggerMinReputation = 5,
+}
+
+impl ParamKey {
+    pub fn kind(self) -> ParamKind {
+        match self {
+            ParamKey::MarketFeeBps => ParamKind::Bps,
+            ParamKey::SlashAppealWindow => ParamKind::Seconds,
+            ParamKey::SlashConfirmationThreshold => ParamKind::Amount,
+            ParamKey::IliSubmissionMinTier | ParamKey::BreakerTriggerMinTier => ParamKind::Tier,
+            ParamKey::BreakerTriggerMinReputation => ParamKind::Reputation,
+        }
+    }
+
+    /// Inclusive range governance may set
+    pub fn bounds(self) -> (u64, u64) {
+        match self {
+            ParamKey::MarketFeeBps => (0, 500),
+            ParamKey::SlashAppealWindow => (60 * 60, 7 * 24 * 60 * 60),
+            ParamKey::SlashConfirmationThreshold => (0, 100_000_000_000),
+            // Bronze to Platinum
+            ParamKey::IliSubmissionMinTier | ParamKey::BreakerTriggerMinTier => (0, 3),
+            ParamKey::BreakerTriggerMinReputation => (0, 1_000_000),
+        }
+    }
+
+    /// Value in effect until the key is set
+    pub fn default_value(self) -> u64 {
+        match self {
+            ParamKey::MarketFeeBps => 30,
+            ParamKey::SlashAppealWindow => 24 * 60 * 60,
+            ParamKey::SlashConfirmationThreshold => 1_000_000_000,
+            // Silver
+            ParamKey::IliSubmissionMinTier => 1,
+            // Platinum
+            ParamKey::BreakerTriggerMinTier => 3,
+            ParamKey::BreakerTriggerMinReputation => 100,
+        }
+    }
+}
+
+impl TryFrom<u16> for ParamKey {
+    type Error = anchor_lang::error::Error;
+
+    fn try_from(id: u16) -> Result<Self> {
+        Ok(match id {
+            0 => ParamKey::MarketFeeBps,
+            1 => ParamKey::SlashAppealWindow,
+            2 => ParamKey::SlashConfirmationThreshold,
+            3 => ParamKey::IliSubmissionMinTier,
+            4 => ParamKey::BreakerTriggerMinTier,
+            5 => ParamKey::BreakerTriggerMinReputation,
+            _ => return err!(CommonError::UnknownParam),
+        })
+    }
+}
+
+/// One set parameter, with a value queued behind the update timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParamEntry {
+    /// ParamKey id
+    pub key: u16,
+    /// Value in effect
+    pub value: u64,
+    /// Value queued by propose (meaningful while pending_eta != 0)
+    pub pending_value: u64,
+    /// When the queued value can be applied; 0 if none queued
+    pub pending_eta: i64,
+}
+
+impl ParamEntry {
+    pub const LEN: usize = 2 + // key
+        8 + // value
+        8 + // pending_value
+        8; // pending_eta
+}
+
+/// Versioned set of registry parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ParamTable {
+    /// Incremented on every applied change
+    pub version: u32,
+    /// Entries in ParamKey id order
+    pub entries: Vec<ParamEntry>,
+}
+
+impl ParamTable {
+    /// Serialized size with room for MAX_REGISTRY_PARAMS entries
+    pub const MAX_LEN: usize = 4 + // version
+        4 + MAX_REGISTRY_PARAMS * ParamEntry::LEN; // entries
+
+    fn position(&self, key: ParamKey) -> std::result::Result<usize, usize> {
+        self.entries.binary_search_by_key(&(key as u16), |entry| entry.key)
+    }
+
+    /// Value in effect for `key`, or its default if never set
+    pub fn get(&self, key: ParamKey) -> u64 {
+        self.position(key)
+            .map(|i| self.entries[i].value)
+            .unwrap_or_else(|_| key.default_value())
+    }
+
+    fn typed(&self, key: ParamKey, kind: ParamKind) -> Result<u64> {
+        require!(key.kind() == kind, CommonError::ParamKindMismatch);
+        Ok(self.get(key))
+    }
+
+    /// Token amount parameter
+    pub fn amount(&self, key: ParamKey) -> Result<u64> {
+        self.typed(key, ParamKind::Amount)
+    }
+
+    /// Basis point parameter
+    pub fn bps(&self, key: ParamKey) -> Result<u16> {
+        let value = self.typed(key, ParamKind::Bps)?;
+        u16::try_from(value)
+            .ok()
+            .filter(|bps| *bps <= 10_000)
+            .ok_or_else(|| error!(CommonError::ParamOutOfRange))
+    }
+
+    /// Duration parameter in seconds
+    pub fn seconds(&self, key: ParamKey) -> Result<i64> {
+        let value = self.typed(key, ParamKind::Seconds)?;
+        i64::try_from(value).map_err(|_| error!(CommonError::ParamOutOfRange))
+    }
+
+    /// Agent tier parameter, as the tier's discriminant
+    pub fn tier(&self, key: ParamKey) -> Result<u8> {
+        let value = self.typed(key, ParamKind::Tier)?;
+        u8::try_from(value).map_err(|_| error!(CommonError::ParamOutOfRange))
+    }
+
+    /// Reputation score parameter
+    pub fn reputation(&self, key: ParamKey) -> Result<i32> {
+        let value = self.typed(key, ParamKind::Reputation)?;
+        i32::try_from(value).map_err(|_| error!(CommonError::ParamOutOfRange))
+    }
+
+    /// Queue `value` for `key`, applicable at `eta`. Proposing again
+    /// replaces the queued value.
+    pub fn propose(&mut self, key: ParamKey, value: u64, eta: i64) -> Result<()> {
+        let (min, max) = key.bounds();
+        require!((min..=max).contains(&value), CommonError::ParamOutOfRange);
+
+        let index = match self.position(key) {
+            Ok(index) => index,
+            Err(index) => {
+                require!(self.entries.len() < MAX_REGISTRY_PARAMS, CommonError::RegistryFull);
+                self.entries.insert(
+                    index,
+                    ParamEntry {
+                        key: key as u16,
+                        value: key.default_value(),
+                        pending_value: 0,
+                        pending_eta: 0,
+                    },
+                );
+                index
+            }
+        };
+        let entry = &mut self.entries[index];
+        entry.pending_value = value;
+        entry.pending_eta = eta;
+        Ok(())
+    }
+
+    /// Apply the value queued for `key` once `now` has reached its eta,
+    /// returning (old value, new value)
+    pub fn apply(&mut self, key: ParamKey, now: i64) -> Result<(u64, u64)> {
+        let index = self.position(key).map_err(|_| error!(CommonError::NoPendingParam))?;
+        let entry = &mut self.entries[index];
+        require!(entry.pending_eta != 0, CommonError::NoPendingParam);
+        require!(now >= entry.pending_eta, CommonError::ParamTimelockActive);
+
+        let old_value = entry.value;
+        entry.value = entry.pending_value;
+        entry.pending_value = 0;
+        entry.pending_eta = 0;
+        let new_value = entry.value;
+        self.version = self.version.wrapping_add(1);
+        Ok((old_value, new_value))
+    }
+}
+
+/// Read the ParamTable from a registry account owned by `program_id`; the
+/// caller checks the account's address.
+/// Layout: [discriminator: 8][ParamTable]...
+pub fn read_param_table(registry: &AccountInfo, program_id: &Pubkey) -> Result<ParamTable> {
+    require_keys_eq!(*registry.owner, *program_id, CommonError::InvalidRegistry);
+    let data = registry.try_borrow_data()?;
+    let mut table_data = data.get(8..).ok_or(CommonError::InvalidRegistry)?;
+    ParamTable::deserialize(&mut table_data).map_err(|_| error!(CommonError::InvalidRegistry))
+}
+
+/// Like `read_param_table`, but an uncreated registry (no data at the
+/// address) reads as the default table so dependent instructions keep
+/// working before governance initializes it.
+pub fn read_param_table_or_default(registry: &AccountInfo, program_id: &Pubkey) -> Result<ParamTable> {
+    if registry.data_is_empty() {
+        return Ok(ParamTable::default());
+    }
+    read_param_table(registry, program_id)
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::CommonError;
+
+/// Reentrancy guard for accounts exposing a `locked: bool` flag
+///
+/// Anchor only writes account data back when the instruction exits, so a
+/// flag set in memory is invisible to a nested call into the same program.
+/// `lock` therefore persists the flag before the handler performs any CPI;
+/// `unlock` clears it and the normal exit writes it back.
+///
+/// The flag is a field of the protected account rather than a separate
+/// guard object, so it lands in the same write set as the state it guards.
+/// If any inner CPI fails the runtime discards the flushed lock along with
+/// every other write, and nothing depends on `Drop` running. The only way
+/// to leave the account locked is a handler returning `Ok` without calling
+/// `unlock`, so every success path must end with it.
+pub trait Lockable {
+    fn locked(&self) -> bool;
+    fn set_locked(&mut self, locked: bool);
+}
+
+/// Mark `account` as in use and flush it to account data
+pub fn lock<'info, T>(account: &mut Account<'info, T>, program_id: &Pubkey) -> Result<()>
+where
+    T: Lockable + AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    require!(!account.locked(), CommonError::Reentrancy);
+    account.set_locked(true);
+    account.exit(program_id)
+}
+
+/// Release the guard taken by `lock`; written back with the handler's other
+/// changes to `account` when the instruction exits
+pub fn unlock<'info, T>(account: &mut Account<'info, T>)
+where
+    T: Lockable + AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    account.set_locked(false);
+}
@@ -0,0 +1,248 @@
+//! solana-program-test harness shared by the program integration tests:
+//! running an Anchor program natively, sending transactions, creating or
+//! reading token and program accounts, bootstrapping an ars-reserve vault
+//! and checking account layouts.
+
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::Instruction,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+pub use anchor_lang;
+pub use solana_program_test;
+pub use solana_sdk;
+
+/// ProgramTest that runs `$program`'s Anchor entrypoint natively, e.g.
+/// `program_test!(ars_reserve)`
+#[macro_export]
+macro_rules! program_test {
+    ($program:ident) => {{
+        fn process_instruction(
+            program_id: &$crate::solana_sdk::pubkey::Pubkey,
+            accounts: &[$crate::solana_sdk::account_info::AccountInfo],
+            data: &[u8],
+        ) -> $crate::solana_sdk::entrypoint::ProgramResult {
+            // Anchor's entry wants accounts that live for 'info
+            let accounts = Box::leak(Box::new(accounts.to_vec()));
+            $program::entry(program_id, accounts, data)
+        }
+
+        $crate::solana_program_test::ProgramTest::new(
+            stringify!($program),
+            $program::id(),
+            $crate::solana_program_test::processor!(process_instruction),
+        )
+    }};
+}
+
+/// Check that each account type ends with zeroed `_reserved` bytes that its
+/// LEN accounts for, so fields can be added later without reallocating: an
+/// all-zero body of `LEN - 8` bytes must decode with zero reserved bytes and,
+/// with those filled, re-encode to exactly LEN - 8 bytes ending in them.
+/// Types listed after `variable:` (Options, strings, vectors, policy
+/// payloads) decode at their smallest and only have to fit within LEN.
+#[macro_export]
+macro_rules! assert_reserved_layout {
+    (@check $ty:ty, $exact:expr) => {{
+        use $crate::anchor_lang::{AnchorDeserialize, AnchorSerialize};
+
+        const MARK: u8 = 0xa5;
+        let zeroed = vec![0u8; <$ty>::LEN - 8];
+        let mut account = <$ty>::deserialize(&mut zeroed.as_slice()).unwrap();
+        assert!(
+            account._reserved.iter().all(|b| *b == 0),
+            "{} decodes non-zero reserved bytes",
+            stringify!($ty)
+        );
+        account._reserved.fill(MARK);
+        let data = account.try_to_vec().unwrap();
+        if $exact {
+            assert_eq!(8 + data.len(), <$ty>::LEN, "{} LEN", stringify!($ty));
+        } else {
+            assert!(8 + data.len() <= <$ty>::LEN, "{} exceeds LEN", stringify!($ty));
+        }
+        assert!(
+            data.ends_with(&vec![MARK; account._reserved.len()]),
+            "{} does not end with its reserved bytes",
+            stringify!($ty)
+        );
+    }};
+    (variable: $($ty:ty),* $(,)?) => {
+        $( $crate::assert_reserved_layout!(@check $ty, false); )*
+    };
+    ($($ty:ty),* $(,)?) => {
+        $( $crate::assert_reserved_layout!(@check $ty, true); )*
+    };
+}
+
+/// Send `instructions` in one transaction paid and signed by the context
+/// payer plus `signers`
+pub async fn send(
+    ctx: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await
+}
+
+/// Create an SPL mint with the payer as mint authority
+pub async fn create_mint(ctx: &mut ProgramTestContext, decimals: u8) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    send(
+        ctx,
+        &[
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::id(), &mint.pubkey(), &payer, None, decimals)
+                .unwrap(),
+        ],
+        &[&mint],
+    )
+    .await
+    .unwrap();
+    mint.pubkey()
+}
+
+pub async fn create_token_account(ctx: &mut ProgramTestContext, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    send(
+        ctx,
+        &[
+            system_instruction::create_account(
+                &payer,
+                &account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::id(), &account.pubkey(), mint, owner)
+                .unwrap(),
+        ],
+        &[&account],
+    )
+    .await
+    .unwrap();
+    account.pubkey()
+}
+
+/// Initialize an ars-reserve vault with the payer as authority and
+/// placeholder asset vaults, returning the vault PDA
+pub async fn init_vault(ctx: &mut ProgramTestContext) -> Pubkey {
+    let authority = ctx.payer.pubkey();
+    let (vault, _) = Pubkey::find_program_address(&[b"vault", authority.as_ref()], &ars_reserve::id());
+    let initialize = Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::Initialize {
+            vault,
+            authority,
+            usdc_vault: Pubkey::new_unique(),
+            sol_vault: Pubkey::new_unique(),
+            msol_vault: Pubkey::new_unique(),
+            jitosol_vault: Pubkey::new_unique(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::Initialize {
+            min_vhr: 15_000,
+            rebalance_threshold_bps: 500,
+        }
+        .data(),
+    };
+    send(ctx, &[initialize], &[]).await.unwrap();
+    vault
+}
+
+/// Mint `amount` of a create_mint mint into `account`
+pub async fn mint_to(ctx: &mut ProgramTestContext, mint: &Pubkey, account: &Pubkey, amount: u64) {
+    let authority = ctx.payer.pubkey();
+    send(
+        ctx,
+        &[spl_token::instruction::mint_to(&spl_token::id(), mint, account, &authority, &[], amount)
+            .unwrap()],
+        &[],
+    )
+    .await
+    .unwrap();
+}
+
+pub fn packed<T: Pack>(value: T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    T::pack(value, &mut data).unwrap();
+    data
+}
+
+/// Funded account holding `data`, for ProgramTest::add_account
+pub fn account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Deserialize the Anchor account at `address`
+pub async fn fetch<T: AccountDeserialize>(ctx: &mut ProgramTestContext, address: Pubkey) -> T {
+    let account = ctx
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+pub async fn token_balance(ctx: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = ctx
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap();
+    spl_token::state::Account::unpack(&account.data).unwrap().amount
+}
+
+pub async fn mint_supply(ctx: &mut ProgramTestContext, mint: Pubkey) -> u64 {
+    let account = ctx
+        .banks_client
+        .get_account(mint)
+        .await
+        .unwrap()
+        .unwrap();
+    spl_token::state::Mint::unpack(&account.data).unwrap().supply
+}
+
+/// Move the bank clock to `unix_timestamp`
+pub async fn set_time(ctx: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    ctx.set_sysvar(&clock);
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::state::VoteRecord;
+
+/// Batch voting module for ARS
+///
+/// vote_batch casts several votes in one transaction. Its VoteRecords cannot
+/// be declared in the Accounts struct, so they are created here the same way
+/// Anchor's `init` would create them.
+
+/// Maximum number of votes in one vote_batch
+pub const MAX_BATCH_VOTES: usize = 16;
+
+/// One vote of a vote_batch, for the (proposal, vote_record) remaining
+/// account pair at the same position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct BatchVote {
+    /// Vote direction
+    pub vote_yes: bool,
+    /// Stake committed to the vote
+    pub stake_amount: u64,
+}
+
+/// Create the VoteRecord PDA of `voter` on `proposal`, paid by `payer`,
+/// and return its bump. Fails if the voter already voted on the proposal.
+pub fn create_vote_record<'info>(
+    vote_record: &AccountInfo<'info>,
+    proposal: &Pubkey,
+    voter: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(
+        &[b"vote_record", proposal.as_ref(), voter.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(*vote_record.key, address, ErrorCode::InvalidVoteRecord);
+    require!(
+        vote_record.owner == &system_program::ID && vote_record.data_is_empty(),
+        ErrorCode::AlreadyVoted
+    );
+
+    let bump_seed = [bump];
+    let seeds: &[&[u8]] = &[b"vote_record", proposal.as_ref(), voter.as_ref(), &bump_seed];
+    let signer = &[seeds];
+    let rent = Rent::get()?.minimum_balance(VoteRecord::LEN);
+    let lamports = vote_record.lamports();
+
+    if lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: vote_record.clone(),
+                },
+                signer,
+            ),
+            rent,
+            VoteRecord::LEN as u64,
+            &crate::ID,
+        )?;
+    } else {
+        // Lamports sent to the address ahead of time must not block the vote
+        let top_up = rent.saturating_sub(lamports);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: vote_record.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: vote_record.clone(),
+                },
+                signer,
+            ),
+            VoteRecord::LEN as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign {
+                    account_to_assign: vote_record.clone(),
+                },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+
+    Ok(bump)
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Compressed vote records
+///
+/// Large proposals can record votes as leaves of an SPL concurrent Merkle tree
+/// instead of one account per vote. Only the tree root lives on-chain; claims
+/// must present the leaf fields plus a Merkle proof (passed as remaining
+/// accounts to spl-account-compression's verify_leaf).
+
+/// Default tree shape: 2^14 = 16,384 votes per proposal
+pub const VOTE_TREE_MAX_DEPTH: u32 = 14;
+pub const VOTE_TREE_MAX_BUFFER_SIZE: u32 = 64;
+
+/// Hash a compressed vote into a Merkle leaf
+pub fn compressed_vote_leaf(
+    proposal_id: u64,
+    voter: &Pubkey,
+    vote_yes: bool,
+    stake_amount: u64,
+    voting_power: u64,
+) -> [u8; 32] {
+    hashv(&[
+        b"ars_vote",
+        &proposal_id.to_le_bytes(),
+        voter.as_ref(),
+        &[vote_yes as u8],
+        &stake_amount.to_le_bytes(),
+        &voting_power.to_le_bytes(),
+    ])
+    .to_bytes()
+}
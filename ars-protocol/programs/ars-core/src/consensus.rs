@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ConsensusScored;
+use crate::state::{within_consensus_band, AgentRegistry, AgentStats, ILIPendingUpdate};
+use ars_common::log_event;
+
+/// ILI consensus scoring
+///
+/// The submission that completes a consensus round scores every agent in it
+/// against the median. A value within CONSENSUS_BAND_BPS counts as a
+/// successful update and earns CONSENSUS_REPUTATION_REWARD; an outlier loses
+/// OUTLIER_REPUTATION_PENALTY. The submitter must pass the (agent_registry,
+/// agent_stats) pair of every other submission in the round, in submission
+/// order, as remaining accounts.
+
+/// Score a completed round. Returns how many submissions matched consensus.
+pub fn score_consensus_round<'info>(
+    submitter: &mut AgentRegistry,
+    submitter_stats: &mut AgentStats,
+    round: &[ILIPendingUpdate],
+    median: u64,
+    epoch: u64,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<u8> {
+    let others = round.iter().filter(|u| u.agent != submitter.agent_pubkey).count();
+    require!(
+        remaining_accounts.len() == others * 2,
+        ErrorCode::ConsensusAccountsMismatch
+    );
+
+    let mut accurate_count: u8 = 0;
+    let mut pairs = remaining_accounts.chunks_exact(2);
+    for update in round {
+        let accurate = within_consensus_band(update.ili_value, median);
+        accurate_count += accurate as u8;
+
+        let reputation_score = if update.agent == submitter.agent_pubkey {
+            submitter.record_consensus(accurate);
+            if accurate {
+                submitter_stats.record_success(epoch);
+            }
+            submitter.reputation_score
+        } else {
+            let pair = pairs.next().ok_or(ErrorCode::ConsensusAccountsMismatch)?;
+            let mut agent_registry = Account::<AgentRegistry>::try_from(&pair[0])?;
+            let mut agent_stats = Account::<AgentStats>::try_from(&pair[1])?;
+            require!(
+                agent_registry.agent_pubkey == update.agent && agent_stats.agent == update.agent,
+                ErrorCode::ConsensusAccountsMismatch
+            );
+
+            agent_registry.record_consensus(accurate);
+            if accurate {
+                agent_stats.record_success(epoch);
+            }
+            agent_registry.exit(&crate::ID)?;
+            agent_stats.exit(&crate::ID)?;
+            agent_registry.reputation_score
+        };
+
+        log_event!(ConsensusScored {
+            agent: update.agent,
+            ili_value: update.ili_value,
+            median,
+            accurate,
+            reputation_score,
+        });
+    }
+
+    Ok(accurate_count)
+}
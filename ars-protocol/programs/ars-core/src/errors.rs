@@ -10,26 +10,58 @@ pub enum ErrorCode {
     // Authorization errors
     #[msg("Unauthorized access")]
     Unauthorized,
+    #[msg("Single-key path disabled while the authority is a multisig")]
+    SingleKeyPathDisabled,
 
     // Admin transfer errors
     #[msg("Timelock has not expired")]
     TimelockNotExpired,
     #[msg("No pending admin transfer")]
     NoPendingTransfer,
+    #[msg("No pending update for this parameter")]
+    NoPendingParamUpdate,
+    #[msg("Authority has signed within the inactivity period")]
+    AuthorityStillActive,
 
     // Agent registration errors
-    #[msg("Insufficient stake amount (minimum 100 ARU)")]
+    #[msg("Insufficient stake amount (below min_agent_stake)")]
     InsufficientStake,
     #[msg("Agent is not active")]
     AgentNotActive,
+    #[msg("Agent tier too low for this action")]
+    InsufficientTier,
+    #[msg("Agent has been active within the silence period")]
+    AgentNotStale,
+    #[msg("Proposals of this type can only be created by a registered agent")]
+    ProposerNotAgent,
+    #[msg("Agent name is too long")]
+    AgentNameTooLong,
+    #[msg("Agent endpoint URI is too long")]
+    AgentUriTooLong,
 
     // ILI update errors
     #[msg("ILI update too frequent (5 minute minimum)")]
     UpdateTooFrequent,
     #[msg("Invalid Ed25519 signature")]
     InvalidSignature,
+    #[msg("Signature verification instruction not found")]
+    MissingSignatureInstruction,
+    #[msg("Invalid agent signing key")]
+    InvalidAgentKey,
+    #[msg("Agent is not registered with this key type")]
+    AgentKeyTypeMismatch,
+    #[msg("Signer is neither the agent nor its operator")]
+    NotAgentAuthority,
     #[msg("Insufficient agents for consensus (minimum 3 required)")]
     InsufficientConsensus,
+    #[msg("Smoothing factor must be between 1 and 10000 basis points")]
+    InvalidSmoothingFactor,
+    #[msg("ILI oracle has not reached consensus yet")]
+    OracleNotInitialized,
+    #[msg("Signed ILI submission is outside the accepted time window")]
+    StaleSubmission,
+    #[msg("Signed ILI submission is not newer than the agent's last one")]
+    ReplayedSubmission,
 
     // Proposal errors
     #[msg("Proposal is not active")]
@@ -38,18 +70,144 @@ pub enum ErrorCode {
     InvalidVotingPeriod,
     #[msg("Invalid stake amount")]
     InvalidStakeAmount,
+    #[msg("Proposal has the wrong policy type")]
+    InvalidPolicyType,
+    #[msg("Invalid policy parameters")]
+    InvalidPolicyParams,
+    #[msg("Voting period has not ended")]
+    VotingPeriodActive,
+    #[msg("Proposal did not reach the required supermajority")]
+    SupermajorityNotReached,
+    #[msg("Proposal did not reach quorum")]
+    QuorumNotReached,
+    #[msg("Approval threshold must be between 5001 and 10000 bps")]
+    InvalidApprovalThreshold,
+    #[msg("ILI override window has expired")]
+    OverrideExpired,
+    #[msg("Proposal has not been resolved")]
+    ProposalNotResolved,
+    #[msg("Vote has already been settled")]
+    VoteAlreadySettled,
+    #[msg("Participation reward already claimed for this vote")]
+    ParticipationRewardClaimed,
+    #[msg("Participation reward above maximum (1 ARU)")]
+    InvalidParticipationReward,
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+    #[msg("Proposal is not queued for execution")]
+    ProposalNotQueued,
+    #[msg("Proposal can no longer be vetoed")]
+    ProposalNotVetoable,
+    #[msg("Too many guardians")]
+    TooManyGuardians,
+    #[msg("Nothing to refund")]
+    NothingToRefund,
+    #[msg("Delegation does not apply to this vote")]
+    InvalidDelegation,
+    #[msg("Delegation expiry out of range")]
+    InvalidDelegationExpiry,
+    #[msg("Agent revoked a delegation after this proposal started")]
+    DelegationChangedDuringVote,
+    #[msg("Unstake cooldown has not ended")]
+    UnstakeCooldownActive,
+    #[msg("No pending unstake")]
+    NoPendingUnstake,
+    #[msg("No rewards to claim")]
+    NothingToClaim,
+    #[msg("Commission above the maximum")]
+    InvalidCommission,
+    #[msg("Proposal conviction is below the threshold")]
+    ConvictionNotReached,
+    #[msg("Agent has votes that can still be settled")]
+    VotesUnsettled,
+    #[msg("Agent has not initiated an exit")]
+    ExitNotInitiated,
+    #[msg("Remaining accounts do not match the consensus round's agents")]
+    ConsensusAccountsMismatch,
+    #[msg("Lock duration out of range")]
+    InvalidLockDuration,
+    #[msg("Lock has not ended")]
+    LockNotExpired,
+    #[msg("Lock has no voting power left")]
+    LockExpired,
+    #[msg("Voter has neither an agent registry nor a voter lock")]
+    NoVotingAccount,
+    #[msg("Vote stake is within the agent's available stake")]
+    VoteWithinStake,
+    #[msg("Account required by this policy type is missing")]
+    MissingPolicyAccount,
+    #[msg("Account does not match the protocol configuration")]
+    InvalidPolicyAccount,
+    #[msg("Proposal has not been executed")]
+    ProposalNotExecuted,
+    #[msg("Proposal metadata URI is too long")]
+    ProposalUriTooLong,
+    #[msg("Outcome evaluation window has not ended")]
+    OutcomeWindowActive,
+    #[msg("Outcome horizon out of range")]
+    InvalidOutcomeCriterion,
+    #[msg("Proposal outcome has already been evaluated")]
+    OutcomeAlreadyEvaluated,
+    #[msg("Outcome would be evaluated after the voters' stake is unlocked")]
+    OutcomeAfterVoteLock,
+    #[msg("Proposal outcome has not been evaluated")]
+    OutcomeNotEvaluated,
+    #[msg("An identical proposal is still open for voting")]
+    DuplicateProposal,
+    #[msg("Vote batch is empty, too large, or does not match its accounts")]
+    InvalidVoteBatch,
+    #[msg("Vote record address does not match the proposal and voter")]
+    InvalidVoteRecord,
+    #[msg("Agent has already voted on this proposal")]
+    AlreadyVoted,
+    #[msg("Parameter change is too large for an optimistic proposal")]
+    OutsideOptimisticBand,
+    #[msg("Fast track needs distinct, active, high-reputation co-sponsors")]
+    InvalidCosponsors,
 
     // Circuit breaker errors
     #[msg("Circuit breaker is active")]
     CircuitBreakerActive,
+    #[msg("Circuit breaker is not active")]
+    CircuitBreakerNotActive,
     #[msg("Insufficient reputation score")]
     InsufficientReputation,
-    #[msg("Insufficient deposit for griefing protection (minimum 10 ARU)")]
+    #[msg("Action is restricted during the post-breaker cooldown")]
+    BreakerCooldownActive,
+    #[msg("Invalid breaker cooldown period")]
+    InvalidBreakerCooldown,
+    #[msg("Latest oracle round was already checked for an anomaly")]
+    AnomalyRoundChecked,
+    #[msg("Invalid anomaly detector configuration")]
+    InvalidAnomalyConfig,
+    #[msg("Insufficient deposit for griefing protection")]
     InsufficientDeposit,
 
+    // Bug bounty errors
+    #[msg("Bounty reward tiers must not increase with lower severity")]
+    InvalidBountyTiers,
+
     // Slashing errors
     #[msg("Slash amount exceeds agent stake")]
     SlashAmountTooHigh,
+    #[msg("Slash above the threshold needs a guardian or an executed proposal")]
+    SlashNotConfirmed,
+    #[msg("Slash is not awaiting execution")]
+    SlashNotPending,
+    #[msg("Slash appeal window has closed")]
+    SlashAppealWindowClosed,
+    #[msg("Slash can still be appealed")]
+    SlashAppealWindowActive,
+    #[msg("Appealed slash has no matching governance decision")]
+    SlashAppealUnresolved,
+    #[msg("Stake is held for a pending slash")]
+    SlashPending,
+
+    // Insurance fund errors
+    #[msg("Reserve VHR is not below the threshold")]
+    NoReserveShortfall,
+    #[msg("Insurance fund balance too low")]
+    InsufficientInsuranceFund,
 
     // General validation errors
     #[msg("Invalid epoch duration")]
@@ -82,6 +240,8 @@ pub enum ErrorCode {
     BurnCapExceeded,
     #[msg("Epoch duration not complete")]
     EpochNotComplete,
+    #[msg("Program was built for a different profile")]
+    BuildProfileMismatch,
 
     // Percolator integration errors
     #[msg("Invalid Percolator program ID")]
@@ -92,4 +252,104 @@ pub enum ErrorCode {
     InvalidPercolatorSlab,
     #[msg("Overflow in calculation")]
     Overflow,
+
+    // Randomness errors
+    #[msg("Invalid randomness account")]
+    InvalidRandomnessAccount,
+    #[msg("No randomness request pending")]
+    RandomnessNotRequested,
+    #[msg("Randomness request already pending")]
+    RandomnessAlreadyPending,
+    #[msg("Randomness not yet revealed")]
+    RandomnessUnavailable,
+    #[msg("Switchboard randomness account was not committed in the previous slot")]
+    RandomnessNotFresh,
+    #[msg("Committee size must be between 1 and MAX_COMMITTEE_SIZE")]
+    InvalidCommitteeSize,
+    #[msg("Committee candidates do not match the committed set")]
+    CommitteeCandidatesMismatch,
+    #[msg("Committee already drawn from this seed")]
+    CommitteeAlreadyDrawn,
+
+    // ARU price feed errors
+    #[msg("Account is not a registered ARU pool")]
+    InvalidPriceSource,
+    #[msg("TWAP window out of range")]
+    InvalidTwapWindow,
+    #[msg("Pool history does not cover the TWAP window")]
+    TwapWindowUnavailable,
+    #[msg("ARU price is stale")]
+    StaleAruPrice,
+
+    // Compressed vote errors
+    #[msg("Compressed votes already enabled for this proposal")]
+    VoteTreeAlreadySet,
+    #[msg("Proposal does not use compressed votes")]
+    VoteTreeNotSet,
+    #[msg("Invalid vote tree account")]
+    InvalidVoteTree,
+
+    // Reward distribution errors
+    #[msg("Reward root challenge window has not ended")]
+    ChallengeWindowActive,
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Reward claims exceed posted total")]
+    RewardPoolExhausted,
+    #[msg("Reward root was voided by a challenge")]
+    RewardRootVoided,
+    #[msg("Reward root challenge window has ended")]
+    ChallengeWindowClosed,
+    #[msg("Challenged leaf matches on-chain agent stats")]
+    ChallengeFailed,
+    #[msg("Agent stats for this epoch are no longer available")]
+    StatsUnavailable,
+    #[msg("Reward root bond already released")]
+    BondAlreadyReleased,
+
+    // Agent message errors
+    #[msg("Unsupported agent message version")]
+    UnsupportedMessageVersion,
+    #[msg("Legacy agent message format is no longer accepted")]
+    LegacyMessageRejected,
+    #[msg("Legacy cutoff can only be moved earlier")]
+    InvalidLegacyCutoff,
+
+    // Keeper errors
+    #[msg("Keeper bond below minimum (10 ARU)")]
+    KeeperBondTooLow,
+    #[msg("Keeper window is not claimable")]
+    InvalidKeeperWindow,
+    #[msg("Keeper window is not open")]
+    KeeperWindowNotOpen,
+    #[msg("Keeper window has not ended")]
+    KeeperWindowNotEnded,
+    #[msg("Transaction does not run the keeper job")]
+    KeeperJobMissing,
+    #[msg("Keeper still holds open window claims")]
+    KeeperHasOpenClaims,
+
+    // Prediction market errors
+    #[msg("Proposal already has a prediction market")]
+    MarketAlreadyOpen,
+    #[msg("Proposal has no prediction market")]
+    MarketNotOpen,
+    #[msg("Prediction market is closed to trading")]
+    MarketClosed,
+    #[msg("Prediction market has not been resolved")]
+    MarketNotResolved,
+    #[msg("Market liquidity below minimum (10 ARU)")]
+    InsufficientLiquidity,
+    #[msg("Trade output below the requested minimum")]
+    SlippageExceeded,
+    #[msg("Outcome shares are not fully backed by the market's collateral")]
+    MarketUnbacked,
+
+    // Realms adapter errors
+    #[msg("Signer is not the registered Realms governance")]
+    InvalidRealmsGovernance,
+
+    // Account lifecycle errors
+    #[msg("Account cannot be closed yet")]
+    AccountNotClosable,
 }
@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentTier, PolicyType};
+use crate::state::{
+    AgentKeyType, AgentTier, CircuitBreakerReason, ClosedAccountKind, KeeperJob, OutcomeMetric,
+    PolicyParams, PolicyType, ProposalRequirement, ProtocolParam, SlashReason, BOUNTY_TIER_COUNT,
+    POLICY_TYPE_COUNT, RESERVE_ASSET_COUNT,
+};
 
 #[event]
+#[derive(Debug)]
 pub struct ProtocolInitialized {
     pub authority: Pubkey,
     pub epoch_duration: i64,
@@ -9,6 +14,7 @@ pub struct ProtocolInitialized {
 }
 
 #[event]
+#[derive(Debug)]
 pub struct AdminTransferInitiated {
     pub old_authority: Pubkey,
     pub new_authority: Pubkey,
@@ -16,61 +22,880 @@ pub struct AdminTransferInitiated {
 }
 
 #[event]
+#[derive(Debug)]
 pub struct AdminTransferExecuted {
     pub new_authority: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
+#[derive(Debug)]
+pub struct AuthorityAssumed {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub proposal_id: u64,
+    pub authority_last_active: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AuthorityCheckedIn {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ParamUpdateProposed {
+    pub field: ProtocolParam,
+    pub current_value: u64,
+    pub pending_value: u64,
+    pub eta: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ParamUpdateApplied {
+    pub field: ProtocolParam,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RegistryParamProposed {
+    /// ars_common::ParamKey id
+    pub key: u16,
+    pub current_value: u64,
+    pub pending_value: u64,
+    pub eta: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RegistryParamApplied {
+    /// ars_common::ParamKey id
+    pub key: u16,
+    pub old_value: u64,
+    pub new_value: u64,
+    /// Registry version after the change
+    pub version: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct LegacyMessageCutoffUpdated {
+    pub cutoff_epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ApprovalThresholdsUpdated {
+    pub quorum_power: u64,
+    pub approval_threshold_bps: [u16; POLICY_TYPE_COUNT],
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct GuardiansUpdated {
+    pub guardians: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AuthorityMultisigUpdated {
+    pub authority: Pubkey,
+    pub authority_is_multisig: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
 pub struct AgentRegistered {
     pub agent: Pubkey,
     pub tier: AgentTier,
     pub stake_amount: u64,
+    /// AgentMetadata::name_hash, zero if registered without metadata
+    pub name_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentKeyUpdated {
+    pub agent: Pubkey,
+    pub key_type: AgentKeyType,
+    pub evm_address: [u8; 20],
     pub timestamp: i64,
 }
 
 #[event]
+#[derive(Debug)]
+pub struct AgentMetadataUpdated {
+    pub agent: Pubkey,
+    pub name_hash: [u8; 32],
+    pub endpoint_uri: String,
+    pub contact_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentOperatorUpdated {
+    pub agent: Pubkey,
+    pub old_operator: Pubkey,
+    pub new_operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
 pub struct ILIUpdated {
     pub ili_value: u64,
+    pub smoothed_ili: u64,
     pub consensus_agents: u8,
+    /// Submissions within CONSENSUS_BAND_BPS of the median
+    pub accurate_agents: u8,
     pub timestamp: i64,
 }
 
 #[event]
+#[derive(Debug)]
+pub struct ConsensusScored {
+    pub agent: Pubkey,
+    pub ili_value: u64,
+    pub median: u64,
+    pub accurate: bool,
+    pub reputation_score: i32,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ILIOverridden {
+    pub proposal_id: u64,
+    pub ili_value: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ILISmoothingUpdated {
+    pub smoothing_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
 pub struct ProposalCreated {
     pub proposal_id: u64,
     pub proposer: Pubkey,
     pub policy_type: PolicyType,
+    pub description_hash: [u8; 32],
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalRequirementUpdated {
+    pub policy_type: PolicyType,
+    pub requirement: ProposalRequirement,
     pub timestamp: i64,
 }
 
 #[event]
+#[derive(Debug)]
+pub struct ProposalFastTracked {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub cosponsors: Vec<Pubkey>,
+    pub voting_period: i64,
+}
+
+#[event]
+#[derive(Debug)]
 pub struct VoteCast {
     pub proposal_id: u64,
     pub agent: Pubkey,
     pub vote_yes: bool,
     pub stake_amount: u64,
+    pub delegated_stake: u64,
+    pub voting_power: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteChanged {
+    pub proposal_id: u64,
+    pub agent: Pubkey,
+    pub vote_yes: bool,
     pub voting_power: u64,
 }
 
 #[event]
+#[derive(Debug)]
+pub struct VoteWithdrawn {
+    pub proposal_id: u64,
+    pub agent: Pubkey,
+    pub stake_amount: u64,
+    pub voting_power: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VotesDelegated {
+    pub delegator: Pubkey,
+    pub delegatee: Pubkey,
+    pub amount: u64,
+    pub expiry: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct DelegationRevoked {
+    pub delegator: Pubkey,
+    pub delegatee: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct UnstakeRequested {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub pending_unstake: u64,
+    pub new_tier: AgentTier,
+    pub available_at: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct UnstakeWithdrawn {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub stake_amount: u64,
+    pub new_tier: AgentTier,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct StakeIncreased {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub stake_amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct StakeDecreased {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub stake_amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentTierChanged {
+    pub agent: Pubkey,
+    pub old_tier: AgentTier,
+    pub new_tier: AgentTier,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct StaleAgentDeactivated {
+    pub agent: Pubkey,
+    pub last_active: i64,
+    pub deactivated_by: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentReactivated {
+    pub agent: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentExitInitiated {
+    pub agent: Pubkey,
+    pub stake_amount: u64,
+    pub available_at: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentExited {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct StakeDelegated {
+    pub holder: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub holder_stake: u64,
+    pub new_tier: AgentTier,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct StakeUndelegated {
+    pub holder: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub available_at: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct DelegatedStakeWithdrawn {
+    pub holder: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CommissionChangeScheduled {
+    pub agent: Pubkey,
+    pub current_bps: u16,
+    pub pending_bps: u16,
+    pub effective_at: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CommissionUpdated {
+    pub agent: Pubkey,
+    pub old_bps: u16,
+    pub new_bps: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CompoundingUpdated {
+    /// Agent or holder that changed the setting
+    pub owner: Pubkey,
+    pub agent: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct HolderRewardsClaimed {
+    pub holder: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    /// Added to the delegation instead of paid out
+    pub compounded: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AruLocked {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_locked: u64,
+    pub lock_end: i64,
+    pub ve_balance: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct LockWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteStakeCapped {
+    pub proposal_id: u64,
+    pub agent: Pubkey,
+    pub old_stake: u64,
+    pub new_stake: u64,
+    pub voting_power: u64,
+}
+
+#[event]
+#[derive(Debug)]
 pub struct CircuitBreakerTriggered {
     pub agent: Pubkey,
-    pub reason: String,
+    pub reason: CircuitBreakerReason,
+    /// Hash of an off-chain incident report, if any
+    pub details_hash: Option<[u8; 32]>,
     pub timelock_expires: i64,
 }
 
 #[event]
+#[derive(Debug)]
+pub struct PriceAnomalyDetected {
+    pub current_ili: u64,
+    pub smoothed_ili: u64,
+    pub deviation_bps: u64,
+    pub streak: u8,
+    pub round: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BugBountyUpdated {
+    pub scope_hash: [u8; 32],
+    pub reward_tiers: [u64; BOUNTY_TIER_COUNT],
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BugReportCommitted {
+    pub reporter: Pubkey,
+    pub commitment: [u8; 32],
+    pub scope_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CircuitBreakerDeactivated {
+    pub authority: Pubkey,
+    pub cooldown_ends: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RealmsGovernanceUpdated {
+    pub realms_program: Pubkey,
+    pub governance: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RealmsParamUpdated {
+    pub governance: Pubkey,
+    pub field: ProtocolParam,
+    pub value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RealmsRebalanced {
+    pub governance: Pubkey,
+    pub weights: [u16; RESERVE_ASSET_COUNT],
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
 pub struct AgentSlashed {
     pub agent: Pubkey,
+    pub slash_index: u64,
     pub slash_amount: u64,
-    pub reason: String,
+    pub reason: SlashReason,
+    /// Hash of an off-chain evidence document, if any
+    pub details_hash: Option<[u8; 32]>,
     pub new_reputation: i32,
 }
 
+#[event]
+#[derive(Debug)]
+pub struct SlashProposed {
+    pub agent: Pubkey,
+    pub slash_index: u64,
+    pub slash_amount: u64,
+    pub reason: SlashReason,
+    /// Hash of an off-chain evidence document, if any
+    pub details_hash: Option<[u8; 32]>,
+    pub executable_at: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct SlashAppealed {
+    pub agent: Pubkey,
+    pub slash_index: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct SlashDismissed {
+    pub agent: Pubkey,
+    pub slash_index: u64,
+    /// Rejected SlashAgent proposal that decided the appeal
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct KeeperRegistered {
+    pub operator: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct KeeperWindowClaimed {
+    pub keeper: Pubkey,
+    pub job: KeeperJob,
+    pub window: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct KeeperJobCompleted {
+    pub keeper: Pubkey,
+    pub job: KeeperJob,
+    pub window: u64,
+    pub reward: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct KeeperSlashed {
+    pub keeper: Pubkey,
+    pub job: KeeperJob,
+    pub window: u64,
+    pub slash_amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RandomnessRequested {
+    pub randomness_account: Pubkey,
+    pub request_nonce: u64,
+    pub request_slot: u64,
+    pub fallback_slot: u64,
+    pub candidates_hash: [u8; 32],
+    pub committee_size: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RandomnessConsumed {
+    pub request_nonce: u64,
+    pub seed: [u8; 32],
+    pub is_fallback: bool,
+    pub slot: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CommitteeSelected {
+    pub request_nonce: u64,
+    pub members: Vec<Pubkey>,
+    pub lead: Pubkey,
+    pub is_fallback: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AruPriceFeedRegistered {
+    pub pool: Pubkey,
+    pub quote_mint: Pubkey,
+    pub twap_window: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AruPriceUpdated {
+    pub price: u64,
+    pub span: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalSimulated {
+    pub proposal_id: u64,
+    pub simulator: Pubkey,
+    pub supply_before: u64,
+    pub supply_after: u64,
+    pub vhr_before: u16,
+    pub vhr_after: u16,
+    pub result_hash: [u8; 32],
+}
+
+#[event]
+#[derive(Debug)]
+pub struct SimulationAttached {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub result_hash: [u8; 32],
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MarketOpened {
+    pub proposal_id: u64,
+    pub liquidity_provider: Pubkey,
+    pub liquidity: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeSharesMinted {
+    pub proposal_id: u64,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeSharesMerged {
+    pub proposal_id: u64,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeSwapped {
+    pub proposal_id: u64,
+    pub trader: Pubkey,
+    pub sell_yes: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub yes_price_bps: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeBought {
+    pub proposal_id: u64,
+    pub trader: Pubkey,
+    pub buy_yes: bool,
+    pub collateral_in: u64,
+    pub fee: u64,
+    pub shares_out: u64,
+    pub yes_price_bps: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeSold {
+    pub proposal_id: u64,
+    pub trader: Pubkey,
+    pub sell_yes: bool,
+    pub shares_in: u64,
+    pub fee: u64,
+    pub collateral_out: u64,
+    pub yes_price_bps: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MarketResolved {
+    pub proposal_id: u64,
+    pub yes_won: bool,
+    pub burned_reserve: u64,
+    pub final_yes_price_bps: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MarketSettled {
+    pub proposal_id: u64,
+    pub liquidity_provider: Pubkey,
+    pub amount: u64,
+    pub outstanding_collateral: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeRedeemed {
+    pub proposal_id: u64,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConvictionUpdated {
+    pub proposal_id: u64,
+    pub conviction: u64,
+    pub threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalResolved {
+    pub proposal_id: u64,
+    pub passed: bool,
+    pub winning_stake: u64,
+    pub reward_pool: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ParticipationRewardUpdated {
+    pub participation_reward: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ParticipationRewardClaimed {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OutcomeEvaluated {
+    pub proposal_id: u64,
+    pub metric: OutcomeMetric,
+    pub metric_at_execution: u64,
+    pub metric_at_evaluation: u64,
+    pub improved: bool,
+    pub winning_stake: u64,
+    pub reward_pool: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalQueued {
+    pub proposal_id: u64,
+    pub execution_eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalVetoed {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct InsuranceFundInitialized {
+    pub mint: Pubkey,
+    pub insurance_fund: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ShortfallCovered {
+    pub governance: Pubkey,
+    pub amount: u64,
+    pub vhr: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct TreasuryInitialized {
+    pub mint: Pubkey,
+    pub treasury: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct DepositReclaimed {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+    pub policy_type: PolicyType,
+    pub policy_params: PolicyParams,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteSettled {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub won: bool,
+    pub payout: u64,
+    /// Part of the payout credited to holders delegated to the voter
+    pub holder_rewards: u64,
+    /// Commission the voter kept from the holders' part
+    pub commission: u64,
+    /// Voter's part restaked instead of paid out
+    pub compounded: bool,
+    pub slashed: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CompressedVoteCast {
+    pub proposal_id: u64,
+    pub agent: Pubkey,
+    pub vote_yes: bool,
+    pub stake_amount: u64,
+    pub voting_power: u64,
+    pub leaf: [u8; 32],
+    pub leaf_index: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RewardRootPosted {
+    pub epoch: u64,
+    pub root: [u8; 32],
+    pub total_amount: u64,
+    pub reward_per_update: u64,
+    pub poster: Pubkey,
+    pub challenge_ends: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RewardRootChallenged {
+    pub epoch: u64,
+    pub challenger: Pubkey,
+    pub agent: Pubkey,
+    pub claimed_amount: u64,
+    pub expected_amount: u64,
+    pub bond_slashed: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AgentRewardClaimed {
+    pub epoch: u64,
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AccountClosed {
+    pub account: Pubkey,
+    pub kind: ClosedAccountKind,
+    pub rent_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
 // Percolator Integration Events
 
 #[event]
+#[derive(Debug)]
 pub struct PercolatorAllocation {
     pub user_idx: u16,
     pub amount: u64,
@@ -78,6 +903,7 @@ pub struct PercolatorAllocation {
 }
 
 #[event]
+#[derive(Debug)]
 pub struct PercolatorWithdrawal {
     pub user_idx: u16,
     pub amount: u64,
@@ -85,6 +911,7 @@ pub struct PercolatorWithdrawal {
 }
 
 #[event]
+#[derive(Debug)]
 pub struct PercolatorOracleUpdate {
     pub ili_value: u64,
     pub price_e6: u64,
@@ -92,6 +919,7 @@ pub struct PercolatorOracleUpdate {
 }
 
 #[event]
+#[derive(Debug)]
 pub struct PercolatorTradeEvent {
     pub agent: Pubkey,
     pub user_idx: u16,
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+
+use crate::errors::ErrorCode;
+use crate::state::KeeperJob;
+
+/// Keeper job module for ARS
+///
+/// A keeper proves it serviced its window by running the crank and then
+/// complete_keeper_job in the same transaction. The crank's own checks decide
+/// whether it was due; since the transaction is atomic, a crank that fails
+/// takes the completion down with it.
+
+impl KeeperJob {
+    /// Program and instruction discriminator of the crank
+    pub fn instruction(&self) -> (Pubkey, [u8; 8]) {
+        match self {
+            KeeperJob::EpochRollover => {
+                (ars_token::ID, ars_token::instruction::StartNewEpoch::DISCRIMINATOR)
+            }
+            KeeperJob::AccrueInterest => {
+                (ars_reserve::ID, ars_reserve::instruction::AccrueInterest::DISCRIMINATOR)
+            }
+            KeeperJob::ResolveProposal => {
+                (crate::ID, crate::instruction::ResolveProposal::DISCRIMINATOR)
+            }
+        }
+    }
+}
+
+/// Require a top-level instruction before the current one that runs `job`
+pub fn require_job_in_transaction(instructions_sysvar: &AccountInfo, job: KeeperJob) -> Result<()> {
+    let (program_id, discriminator) = job.instruction();
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+
+    for index in 0..current {
+        let ix = load_instruction_at_checked(index, instructions_sysvar)?;
+        if ix.program_id == program_id && ix.data.get(..8) == Some(&discriminator[..]) {
+            return Ok(());
+        }
+    }
+
+    err!(ErrorCode::KeeperJobMissing)
+}
@@ -2,19 +2,70 @@
 // Copy this to lib.rs when ready to build
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use ars_common::{log_event, ParamKey, ParamTable};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use ars_reserve::{program::ArsReserve, ReserveVault};
+use ars_token::{program::ArsToken, MintState};
+use spl_account_compression::{program::SplAccountCompression, Noop};
 
 declare_id!("ARSFehdYbZhSgoQ2p82cHxPLGKrutXezJbYgDwJJA5My");
 
+/// Whether this build includes the devnet-only instructions (`devnet` feature)
+///
+/// The `devnet` feature of each ARS program gates test hooks such as faucets
+/// and bootstrap helpers. It is never enabled for mainnet deployments, and
+/// every program exposes `assert_build_profile` so clients can check which
+/// flavor a program id serves.
+pub const DEVNET_BUILD: bool = cfg!(feature = "devnet");
+
 pub mod state;
 pub mod errors;
 pub mod events;
 pub mod percolator_integration;
+pub mod randomness;
+pub mod compression;
+pub mod merkle;
+pub mod signing;
+pub mod secp256k1;
+pub mod keeper;
+pub mod batch;
+pub mod realms;
+pub mod treasury;
+pub mod simulation;
+pub mod consensus;
+pub mod market;
+pub mod outcome;
+pub mod price_feed;
 
 pub use state::*;
 pub use errors::ErrorCode;
 pub use events::*;
 pub use percolator_integration::*;
+pub use randomness::*;
+pub use compression::*;
+pub use merkle::*;
+pub use signing::*;
+pub use secp256k1::*;
+pub use keeper::*;
+pub use batch::*;
+pub use realms::*;
+pub use treasury::*;
+pub use simulation::*;
+pub use consensus::*;
+pub use market::*;
+pub use outcome::*;
+pub use price_feed::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_security_txt::security_txt! {
+    name: "ARS Core",
+    project_url: "https://github.com/protocoldaemon-sec/agentic-reserve-system",
+    contacts: "link:https://github.com/protocoldaemon-sec/agentic-reserve-system/security/advisories/new",
+    policy: "https://github.com/protocoldaemon-sec/agentic-reserve-system/blob/main/ars-protocol/SECURITY.md",
+    source_code: "https://github.com/protocoldaemon-sec/agentic-reserve-system",
+    source_release: env!("CARGO_PKG_VERSION")
+}
 
 #[program]
 pub mod ars_core {
@@ -47,18 +98,79 @@ pub mod ars_core {
         global_state.min_agent_consensus = 3;
         global_state.proposal_counter = 0;
         global_state.last_update_slot = Clock::get()?.slot;
+        global_state.legacy_message_cutoff_epoch = global_state
+            .epoch_at(Clock::get()?.unix_timestamp)
+            .checked_add(LEGACY_MESSAGE_GRACE_EPOCHS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        global_state.quorum_power = DEFAULT_QUORUM_POWER;
+        global_state.approval_threshold_bps = DEFAULT_APPROVAL_THRESHOLD_BPS;
+        global_state.guardians = [Pubkey::default(); MAX_GUARDIANS];
+        global_state.guardian_count = 0;
+        global_state.previous_params = [
+            mint_burn_cap_bps as u64,
+            0,
+            vhr_threshold as u64,
+            3,
+            DEFAULT_REPUTATION_FLOOR_BPS as u64,
+            DEFAULT_REPUTATION_CEILING_BPS as u64,
+            DEFAULT_REPUTATION_SPAN as u64,
+            epoch_duration as u64,
+            DEFAULT_MIN_AGENT_STAKE,
+            DEFAULT_GRIEFING_DEPOSIT,
+            DEFAULT_AUTHORITY_INACTIVITY_PERIOD as u64,
+            DEFAULT_ACCURACY_FLOOR_BPS as u64,
+            DEFAULT_ACCURACY_CEILING_BPS as u64,
+            DEFAULT_ACCURACY_WINDOW as u64,
+            DEFAULT_AGENT_SILENCE_PERIOD as u64,
+        ];
+        global_state.keeper_reward_pool = 0;
+        global_state.participation_reward = 0;
+        global_state.participation_reward_pool = 0;
+        global_state.reputation_floor_bps = DEFAULT_REPUTATION_FLOOR_BPS;
+        global_state.reputation_ceiling_bps = DEFAULT_REPUTATION_CEILING_BPS;
+        global_state.reputation_span = DEFAULT_REPUTATION_SPAN;
+        global_state.accuracy_floor_bps = DEFAULT_ACCURACY_FLOOR_BPS;
+        global_state.accuracy_ceiling_bps = DEFAULT_ACCURACY_CEILING_BPS;
+        global_state.accuracy_window = DEFAULT_ACCURACY_WINDOW;
+        global_state.breaker_cooldown_period = DEFAULT_BREAKER_COOLDOWN;
+        global_state.breaker_cooldown_ends = 0;
+        global_state.anomaly_deviation_bps = DEFAULT_ANOMALY_DEVIATION_BPS;
+        global_state.anomaly_rounds = DEFAULT_ANOMALY_ROUNDS;
+        global_state.anomaly_streak = 0;
+        global_state.anomaly_checked_round = 0;
+        global_state.proposal_requirements = DEFAULT_PROPOSAL_REQUIREMENTS;
+        global_state.realms_program = SPL_GOVERNANCE_PROGRAM_ID;
+        global_state.realms_governance = None;
+        global_state.authority_is_multisig = false;
+        global_state.pending_params = [0; PROTOCOL_PARAM_COUNT];
+        global_state.pending_param_etas = [0; PROTOCOL_PARAM_COUNT];
+        global_state.min_agent_stake = DEFAULT_MIN_AGENT_STAKE;
+        global_state.min_griefing_deposit = DEFAULT_GRIEFING_DEPOSIT;
+        global_state.authority_last_active = Clock::get()?.unix_timestamp;
+        global_state.authority_inactivity_period = DEFAULT_AUTHORITY_INACTIVITY_PERIOD;
+        global_state.agent_silence_period = DEFAULT_AGENT_SILENCE_PERIOD;
+        global_state.locked = false;
         global_state.bump = ctx.bumps.global_state;
 
         let ili_oracle = &mut ctx.accounts.ili_oracle;
         ili_oracle.authority = ctx.accounts.authority.key();
         ili_oracle.current_ili = 0;
+        ili_oracle.smoothed_ili = 0;
+        ili_oracle.smoothing_bps = 10_000;
+        ili_oracle.override_ili = 0;
+        ili_oracle.override_expires = 0;
         ili_oracle.last_update = 0;
         ili_oracle.update_interval = 300;
         ili_oracle.pending_updates = Vec::new();
         ili_oracle.consensus_threshold = 3;
         ili_oracle.bump = ctx.bumps.ili_oracle;
 
-        emit!(ProtocolInitialized {
+        let audit_log = &mut ctx.accounts.audit_log;
+        // Entries start zeroed, i.e. AuditAction::None
+        audit_log.count = 0;
+        audit_log.bump = ctx.bumps.audit_log;
+
+        log_event!(ProtocolInitialized {
             authority: global_state.authority,
             epoch_duration,
             timestamp: Clock::get()?.unix_timestamp,
@@ -73,10 +185,7 @@ pub mod ars_core {
     ) -> Result<()> {
         let global_state = &mut ctx.accounts.global_state;
         
-        require!(
-            ctx.accounts.authority.key() == global_state.authority,
-            ErrorCode::Unauthorized
-        );
+        global_state.authorize(ctx.accounts.authority.key())?;
         
         let current_time = Clock::get()?.unix_timestamp;
         global_state.pending_authority = Some(new_authority);
@@ -84,7 +193,14 @@ pub mod ars_core {
             .checked_add(48 * 60 * 60)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
         
-        emit!(AdminTransferInitiated {
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::AuthorityTransfer,
+            &new_authority.try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(AdminTransferInitiated {
             old_authority: global_state.authority,
             new_authority,
             timelock_expires: global_state.transfer_timelock,
@@ -111,8 +227,16 @@ pub mod ars_core {
         global_state.authority = new_authority;
         global_state.pending_authority = None;
         global_state.transfer_timelock = 0;
+        global_state.authority_last_active = current_time;
+        
+        ctx.accounts.audit_log.record(
+            new_authority,
+            AuditAction::AuthorityTransfer,
+            &new_authority.try_to_vec()?,
+            current_time,
+        );
         
-        emit!(AdminTransferExecuted {
+        log_event!(AdminTransferExecuted {
             new_authority,
             timestamp: current_time,
         });
@@ -120,12 +244,472 @@ pub mod ars_core {
         Ok(())
     }
 
+    /// Record that the authority key is still held, without changing
+    /// anything. Every authority-signed instruction does this; an
+    /// AssumeAuthority proposal can only execute after
+    /// authority_inactivity_period without one.
+    pub fn authority_check_in(ctx: Context<AuthorityCheckIn>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        
+        log_event!(AuthorityCheckedIn {
+            authority: global_state.authority,
+            timestamp: global_state.authority_last_active,
+        });
+        
+        Ok(())
+    }
+
+    /// Queue a new value for a GlobalState parameter, applicable after
+    /// PARAM_UPDATE_TIMELOCK. Proposing again replaces the queued value and
+    /// restarts the timelock.
+    pub fn propose_param_update(
+        ctx: Context<ProposeParamUpdate>,
+        field: ProtocolParam,
+        value: u64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        PolicyParams::ParamUpdate { field, value }.validate()?;
+        
+        let eta = current_time
+            .checked_add(PARAM_UPDATE_TIMELOCK)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        global_state.pending_params[field as usize] = value;
+        global_state.pending_param_etas[field as usize] = eta;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(field, value, eta).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(ParamUpdateProposed {
+            field,
+            current_value: global_state.get_param(field),
+            pending_value: value,
+            eta,
+        });
+        
+        Ok(())
+    }
+
+    /// Apply a queued parameter value once its timelock has expired. Anyone
+    /// may apply, like execute_admin_transfer.
+    pub fn apply_param_update(
+        ctx: Context<ApplyParamUpdate>,
+        field: ProtocolParam,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+        let eta = global_state.pending_param_etas[field as usize];
+        
+        require!(eta != 0, ErrorCode::NoPendingParamUpdate);
+        require!(current_time >= eta, ErrorCode::TimelockNotExpired);
+        
+        let new_value = global_state.pending_params[field as usize];
+        PolicyParams::ParamUpdate { field, value: new_value }.validate()?;
+        
+        let old_value = global_state.get_param(field);
+        global_state.set_param(field, new_value);
+        global_state.pending_params[field as usize] = 0;
+        global_state.pending_param_etas[field as usize] = 0;
+        
+        ctx.accounts.audit_log.record(
+            global_state.authority,
+            AuditAction::ParamChange,
+            &(field, new_value).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(ParamUpdateApplied {
+            field,
+            old_value,
+            new_value,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Create the parameter registry. Every key reads as its default until
+    /// governance sets it.
+    pub fn initialize_parameter_registry(ctx: Context<InitializeParameterRegistry>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.authorize(ctx.accounts.authority.key())?;
+        
+        let parameter_registry = &mut ctx.accounts.parameter_registry;
+        parameter_registry.table = ParamTable::default();
+        parameter_registry.bump = ctx.bumps.parameter_registry;
+        
+        Ok(())
+    }
+
+    /// Queue a new value for a registry parameter (`key` is a ParamKey id),
+    /// applicable after PARAM_UPDATE_TIMELOCK like propose_param_update
+    pub fn propose_registry_param(
+        ctx: Context<ProposeRegistryParam>,
+        key: u16,
+        value: u64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        
+        let param = ParamKey::try_from(key)?;
+        let eta = current_time
+            .checked_add(PARAM_UPDATE_TIMELOCK)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let table = &mut ctx.accounts.parameter_registry.table;
+        table.propose(param, value, eta)?;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(key, value, eta).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(RegistryParamProposed {
+            key,
+            current_value: table.get(param),
+            pending_value: value,
+            eta,
+        });
+        
+        Ok(())
+    }
+
+    /// Apply a queued registry parameter once its timelock has expired.
+    /// Anyone may apply.
+    pub fn apply_registry_param(ctx: Context<ApplyRegistryParam>, key: u16) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        let table = &mut ctx.accounts.parameter_registry.table;
+        let (old_value, new_value) = table.apply(ParamKey::try_from(key)?, current_time)?;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.global_state.authority,
+            AuditAction::ParamChange,
+            &(key, new_value).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(RegistryParamApplied {
+            key,
+            old_value,
+            new_value,
+            version: table.version,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Move the legacy agent message cutoff earlier (never later)
+    pub fn set_legacy_message_cutoff(
+        ctx: Context<SetLegacyMessageCutoff>,
+        cutoff_epoch: u64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            cutoff_epoch <= global_state.legacy_message_cutoff_epoch,
+            ErrorCode::InvalidLegacyCutoff
+        );
+        
+        global_state.legacy_message_cutoff_epoch = cutoff_epoch;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &cutoff_epoch.try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        log_event!(LegacyMessageCutoffUpdated {
+            cutoff_epoch,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Set the execution quorum and per-PolicyType approval thresholds
+    pub fn set_approval_thresholds(
+        ctx: Context<SetApprovalThresholds>,
+        quorum_power: u64,
+        approval_threshold_bps: [u16; POLICY_TYPE_COUNT],
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            approval_threshold_bps.iter().all(|bps| (5_001..=10_000).contains(bps)),
+            ErrorCode::InvalidApprovalThreshold
+        );
+        
+        global_state.quorum_power = quorum_power;
+        global_state.approval_threshold_bps = approval_threshold_bps;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(quorum_power, approval_threshold_bps).try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        log_event!(ApprovalThresholdsUpdated {
+            quorum_power,
+            approval_threshold_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Set who may create proposals of `policy_type` and the griefing deposit
+    /// they record
+    pub fn set_proposal_requirement(
+        ctx: Context<SetApprovalThresholds>,
+        policy_type: PolicyType,
+        requirement: ProposalRequirement,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        
+        global_state.proposal_requirements[policy_type as usize] = requirement;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(policy_type, requirement).try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        log_event!(ProposalRequirementUpdated {
+            policy_type,
+            requirement,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Appoint the guardians allowed to veto passed proposals, replacing the
+    /// current list. An empty list disables the veto.
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(guardians.len() <= MAX_GUARDIANS, ErrorCode::TooManyGuardians);
+        
+        global_state.guardians = [Pubkey::default(); MAX_GUARDIANS];
+        global_state.guardians[..guardians.len()].copy_from_slice(&guardians);
+        global_state.guardian_count = guardians.len() as u8;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &guardians.try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        log_event!(GuardiansUpdated {
+            guardians,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Declare whether the authority is a multisig vault PDA. Anchor's
+    /// Signer already accepts a PDA signing through the multisig program's
+    /// CPI; the flag only switches off the single-guardian paths.
+    pub fn set_authority_multisig(
+        ctx: Context<SetAuthorityMultisig>,
+        authority_is_multisig: bool,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        
+        global_state.authority_is_multisig = authority_is_multisig;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &authority_is_multisig.try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(AuthorityMultisigUpdated {
+            authority: global_state.authority,
+            authority_is_multisig,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Set the flat participation reward paid per vote
+    pub fn set_participation_reward(
+        ctx: Context<SetParticipationReward>,
+        participation_reward: u64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            participation_reward <= MAX_PARTICIPATION_REWARD,
+            ErrorCode::InvalidParticipationReward
+        );
+        
+        global_state.participation_reward = participation_reward;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &participation_reward.try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        log_event!(ParticipationRewardUpdated {
+            participation_reward,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Set the ILI smoothing factor alpha (basis points, 10000 = raw value)
+    pub fn set_ili_smoothing(
+        ctx: Context<SetIliSmoothing>,
+        smoothing_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            smoothing_bps > 0 && smoothing_bps <= 10_000,
+            ErrorCode::InvalidSmoothingFactor
+        );
+        
+        ctx.accounts.ili_oracle.smoothing_bps = smoothing_bps;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &smoothing_bps.try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        log_event!(ILISmoothingUpdated {
+            smoothing_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    pub fn get_ili(ctx: Context<ViewILI>, source: IliSource) -> Result<u64> {
+        ctx.accounts.ili_oracle.read(source, Clock::get()?.unix_timestamp)
+    }
+
+    /// Fail unless the deployed build matches the expected flavor, so clients
+    /// can check (e.g. by simulation) that a program id serves a mainnet build
+    /// without devnet-only instructions
+    pub fn assert_build_profile(_ctx: Context<AssertBuildProfile>, devnet: bool) -> Result<()> {
+        require!(devnet == DEVNET_BUILD, ErrorCode::BuildProfileMismatch);
+        Ok(())
+    }
+
+    /// Project a proposal's effect on ARU supply and reserve VHR from the live
+    /// ars-token and ars-reserve accounts into the caller's scratch
+    /// ProposalSimulation, leaving both programs untouched
+    #[cfg(feature = "devnet")]
+    pub fn simulate_proposal(ctx: Context<SimulateProposal>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        let mint_state = &ctx.accounts.mint_state;
+        let reserve_vault = &ctx.accounts.reserve_vault;
+        let slot = Clock::get()?.slot;
+        
+        let supply_before = mint_state.total_supply;
+        let liabilities = reserve_vault.total_liabilities()?;
+        let vhr_before = vhr(reserve_vault.total_value, liabilities);
+        let (supply_after, vhr_after) = project_policy(
+            &proposal.policy_params,
+            supply_before,
+            reserve_vault.total_value,
+            liabilities,
+        );
+        let result_hash = simulation_hash(
+            &proposal.key(),
+            &proposal.params_hash,
+            supply_before,
+            supply_after,
+            vhr_before,
+            vhr_after,
+            slot,
+        );
+        
+        let simulation = &mut ctx.accounts.simulation;
+        simulation.proposal = proposal.key();
+        simulation.simulator = ctx.accounts.simulator.key();
+        simulation.params_hash = proposal.params_hash;
+        simulation.supply_before = supply_before;
+        simulation.supply_after = supply_after;
+        simulation.vhr_before = vhr_before;
+        simulation.vhr_after = vhr_after;
+        simulation.slot = slot;
+        simulation.result_hash = result_hash;
+        simulation.bump = ctx.bumps.simulation;
+        
+        log_event!(ProposalSimulated {
+            proposal_id: proposal.id,
+            simulator: simulation.simulator,
+            supply_before,
+            supply_after,
+            vhr_before,
+            vhr_after,
+            result_hash,
+        });
+        
+        Ok(())
+    }
+
+    /// Attach the voter's simulation of a proposal to their vote on it
+    #[cfg(feature = "devnet")]
+    pub fn attach_simulation(ctx: Context<AttachSimulation>) -> Result<()> {
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.simulation_hash = ctx.accounts.simulation.result_hash;
+        
+        log_event!(SimulationAttached {
+            proposal: vote_record.proposal,
+            voter: vote_record.voter,
+            result_hash: vote_record.simulation_hash,
+        });
+        
+        Ok(())
+    }
+
     pub fn register_agent(
         ctx: Context<RegisterAgent>,
         stake_amount: u64,
     ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
         require!(
-            stake_amount >= 100_000_000,
+            stake_amount >= ctx.accounts.global_state.min_agent_stake,
             ErrorCode::InsufficientStake
         );
         
@@ -143,7 +727,25 @@ pub mod ars_core {
         agent_registry.slashed_amount = 0;
         agent_registry.registered_at = current_time;
         agent_registry.last_active = current_time;
+        agent_registry.last_submission = 0;
         agent_registry.is_active = true;
+        agent_registry.key_type = AgentKeyType::Ed25519;
+        agent_registry.evm_address = [0u8; 20];
+        agent_registry.delegated_out = 0;
+        agent_registry.last_revoked_at = 0;
+        agent_registry.slash_count = 0;
+        agent_registry.pending_unstake = 0;
+        agent_registry.unstake_available_at = 0;
+        agent_registry.holder_stake = 0;
+        agent_registry.holder_reward_per_share = 0;
+        agent_registry.commission_bps = 0;
+        agent_registry.pending_commission_bps = 0;
+        agent_registry.commission_effective_at = 0;
+        agent_registry.vote_lock_until = 0;
+        agent_registry.exit_available_at = 0;
+        agent_registry.compound_rewards = false;
+        agent_registry.operator_pubkey = Pubkey::default();
+        agent_registry.pending_slash = 0;
         agent_registry.bump = ctx.bumps.agent_registry;
         
         token::transfer(
@@ -158,59 +760,87 @@ pub mod ars_core {
             stake_amount,
         )?;
         
-        emit!(AgentRegistered {
+        log_event!(AgentRegistered {
             agent: ctx.accounts.agent.key(),
             tier,
             stake_amount,
+            name_hash: ctx.accounts.agent_metadata
+                .as_ref()
+                .map(|metadata| metadata.name_hash())
+                .unwrap_or_default(),
             timestamp: current_time,
         });
         
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
         Ok(())
     }
 
-    pub fn submit_ili_update(
-        ctx: Context<SubmitILIUpdate>,
+    /// Submit an ILI value for the current oracle round, signed by the agent
+    /// or its operator. A submission that completes consensus also scores
+    /// the round (see consensus.rs) and must pass the other submitters'
+    /// (agent_registry, agent_stats) pairs as remaining accounts.
+    pub fn submit_ili_update<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitILIUpdate<'info>>,
         ili_value: u64,
         timestamp: i64,
     ) -> Result<()> {
-        let agent_registry = &ctx.accounts.agent_registry;
+        let agent_registry = &mut ctx.accounts.agent_registry;
         let ili_oracle = &mut ctx.accounts.ili_oracle;
         let global_state = &ctx.accounts.global_state;
         let current_time = Clock::get()?.unix_timestamp;
         
         require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        let min_tier = ars_common::read_param_table_or_default(&ctx.accounts.parameter_registry, ctx.program_id)?
+            .tier(ParamKey::IliSubmissionMinTier)?;
+        require!(
+            agent_registry.agent_tier as u8 >= min_tier,
+            ErrorCode::InsufficientTier
+        );
         require!(
             !global_state.circuit_breaker_active,
             ErrorCode::CircuitBreakerActive
         );
         
-        ili_oracle.pending_updates.push(ILIPendingUpdate {
-            agent: agent_registry.agent_pubkey,
+        // One submission per agent per oracle round
+        let next_submission = agent_registry.last_submission
+            .checked_add(ili_oracle.update_interval)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            current_time >= next_submission,
+            ErrorCode::UpdateTooFrequent
+        );
+        
+        agent_registry.last_submission = current_time;
+        agent_registry.last_active = current_time;
+        agent_registry.total_ili_updates = agent_registry.total_ili_updates.saturating_add(1);
+        
+        let agent_stats = &mut ctx.accounts.agent_stats;
+        let epoch = global_state.epoch_at(current_time);
+        agent_stats.agent = agent_registry.agent_pubkey;
+        agent_stats.bump = ctx.bumps.agent_stats;
+        agent_stats.record_submission(epoch);
+        
+        if let Some((median, round)) = ili_oracle.record_submission(
+            agent_registry.agent_pubkey,
             ili_value,
             timestamp,
-            signature: [0u8; 64],
-        });
-        
-        if ili_oracle.pending_updates.len() >= ili_oracle.consensus_threshold as usize {
-            let mut values: Vec<u64> = ili_oracle.pending_updates
-                .iter()
-                .map(|u| u.ili_value)
-                .collect();
-            values.sort_unstable();
-            
-            let median = if values.len() % 2 == 0 {
-                (values[values.len() / 2 - 1] + values[values.len() / 2]) / 2
-            } else {
-                values[values.len() / 2]
-            };
-            
-            ili_oracle.current_ili = median;
-            ili_oracle.last_update = current_time;
-            ili_oracle.pending_updates.clear();
+            current_time,
+        ) {
+            let accurate_agents = score_consensus_round(
+                agent_registry,
+                agent_stats,
+                &round,
+                median,
+                epoch,
+                ctx.remaining_accounts,
+            )?;
             
-            emit!(ILIUpdated {
+            log_event!(ILIUpdated {
                 ili_value: median,
-                consensus_agents: values.len() as u8,
+                smoothed_ili: ili_oracle.smoothed_ili,
+                consensus_agents: round.len() as u8,
+                accurate_agents,
                 timestamp: current_time,
             });
         }
@@ -218,344 +848,7797 @@ pub mod ars_core {
         Ok(())
     }
 
-    pub fn create_proposal(
-        ctx: Context<CreateProposal>,
-        policy_type: PolicyType,
-        policy_params: Vec<u8>,
-        voting_period: i64,
+    /// Switch the key an agent signs ILI submissions with
+    pub fn set_agent_key(
+        ctx: Context<SetAgentKey>,
+        key_type: AgentKeyType,
+        evm_address: [u8; 20],
     ) -> Result<()> {
-        require!(
-            voting_period > 0 && voting_period <= 604800,
-            ErrorCode::InvalidVotingPeriod
-        );
-        require!(policy_params.len() <= 256, ErrorCode::InvalidAmount);
-
-        let global_state = &mut ctx.accounts.global_state;
-        let proposal = &mut ctx.accounts.proposal;
-        let clock = Clock::get()?;
-
-        proposal.id = global_state.proposal_counter;
-        proposal.proposer = ctx.accounts.proposer.key();
-        proposal.policy_type = policy_type;
-        proposal.policy_params = policy_params;
-        proposal.start_time = clock.unix_timestamp;
-        proposal.end_time = clock.unix_timestamp
-            .checked_add(voting_period)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-        proposal.yes_stake = 0;
-        proposal.no_stake = 0;
-        proposal.quadratic_yes = 0;
-        proposal.quadratic_no = 0;
-        proposal.status = ProposalStatus::Active;
-        proposal.execution_tx = None;
-        proposal.griefing_protection_deposit = 10_000_000;
-        proposal.bump = ctx.bumps.proposal;
-
-        global_state.proposal_counter = global_state.proposal_counter
-            .checked_add(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-
-        emit!(ProposalCreated {
-            proposal_id: proposal.id,
-            proposer: proposal.proposer,
-            policy_type,
-            timestamp: clock.unix_timestamp,
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        
+        let evm_address = match key_type {
+            AgentKeyType::Ed25519 => [0u8; 20],
+            AgentKeyType::Secp256k1 => {
+                require!(evm_address != [0u8; 20], ErrorCode::InvalidAgentKey);
+                evm_address
+            }
+        };
+        
+        agent_registry.key_type = key_type;
+        agent_registry.evm_address = evm_address;
+        
+        log_event!(AgentKeyUpdated {
+            agent: agent_registry.agent_pubkey,
+            key_type,
+            evm_address,
+            timestamp: Clock::get()?.unix_timestamp,
         });
-
+        
         Ok(())
     }
 
-    pub fn vote_on_proposal(
-        ctx: Context<VoteOnProposal>,
-        vote_yes: bool,
-        stake_amount: u64,
-    ) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        let agent_registry = &ctx.accounts.agent_registry;
+    /// Let `operator` submit ILI values and vote for the agent, so the key
+    /// holding the stake can stay offline. The operator cannot unstake,
+    /// exit or claim; slashing still takes the owner's stake. The default
+    /// pubkey removes the operator.
+    pub fn set_operator(ctx: Context<SetAgentKey>, operator: Pubkey) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        require!(operator != agent_registry.agent_pubkey, ErrorCode::InvalidAgentKey);
+        
+        let old_operator = agent_registry.operator_pubkey;
+        agent_registry.operator_pubkey = operator;
+        
+        log_event!(AgentOperatorUpdated {
+            agent: agent_registry.agent_pubkey,
+            old_operator,
+            new_operator: operator,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Publish a name, endpoint URI and contact hash for the agent. Can be
+    /// done before registering, so AgentRegistered carries the name hash.
+    pub fn set_agent_metadata(
+        ctx: Context<SetAgentMetadata>,
+        name: String,
+        endpoint_uri: String,
+        contact_hash: [u8; 32],
+    ) -> Result<()> {
+        let agent_metadata = &mut ctx.accounts.agent_metadata;
         let current_time = Clock::get()?.unix_timestamp;
         
-        require!(
-            current_time >= proposal.start_time && current_time < proposal.end_time,
-            ErrorCode::ProposalNotActive
-        );
-        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        agent_metadata.agent = ctx.accounts.agent.key();
+        agent_metadata.bump = ctx.bumps.agent_metadata;
+        agent_metadata.set(name, endpoint_uri, contact_hash, current_time)?;
         
-        let voting_power = (stake_amount as f64).sqrt() as u64;
+        log_event!(AgentMetadataUpdated {
+            agent: agent_metadata.agent,
+            name_hash: agent_metadata.name_hash(),
+            endpoint_uri: agent_metadata.endpoint_uri.clone(),
+            contact_hash,
+            timestamp: current_time,
+        });
         
-        if vote_yes {
-            proposal.yes_stake = proposal.yes_stake
-                .checked_add(stake_amount)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-            proposal.quadratic_yes = proposal.quadratic_yes
-                .checked_add(voting_power)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Replace the agent's published metadata
+    pub fn update_agent_metadata(
+        ctx: Context<UpdateAgentMetadata>,
+        name: String,
+        endpoint_uri: String,
+        contact_hash: [u8; 32],
+    ) -> Result<()> {
+        let agent_metadata = &mut ctx.accounts.agent_metadata;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        agent_metadata.set(name, endpoint_uri, contact_hash, current_time)?;
+        
+        log_event!(AgentMetadataUpdated {
+            agent: agent_metadata.agent,
+            name_hash: agent_metadata.name_hash(),
+            endpoint_uri: agent_metadata.endpoint_uri.clone(),
+            contact_hash,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Set the commission the agent keeps from its holders' vote rewards.
+    /// A decrease applies at once; an increase is scheduled and applies after
+    /// COMMISSION_CHANGE_NOTICE, replacing any change already scheduled.
+    pub fn set_commission(ctx: Context<SetCommission>, commission_bps: u16) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(commission_bps <= MAX_COMMISSION_BPS, ErrorCode::InvalidCommission);
+        
+        agent_registry.apply_pending_commission(current_time);
+        let old_bps = agent_registry.commission_bps;
+        
+        if commission_bps <= old_bps {
+            agent_registry.commission_bps = commission_bps;
+            agent_registry.pending_commission_bps = 0;
+            agent_registry.commission_effective_at = 0;
+            
+            log_event!(CommissionUpdated {
+                agent: agent_registry.agent_pubkey,
+                old_bps,
+                new_bps: commission_bps,
+            });
         } else {
-            proposal.no_stake = proposal.no_stake
-                .checked_add(stake_amount)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-            proposal.quadratic_no = proposal.quadratic_no
-                .checked_add(voting_power)
+            let effective_at = current_time
+                .checked_add(COMMISSION_CHANGE_NOTICE)
                 .ok_or(ErrorCode::ArithmeticOverflow)?;
+            agent_registry.pending_commission_bps = commission_bps;
+            agent_registry.commission_effective_at = effective_at;
+            
+            log_event!(CommissionChangeScheduled {
+                agent: agent_registry.agent_pubkey,
+                current_bps: old_bps,
+                pending_bps: commission_bps,
+                effective_at,
+            });
         }
         
-        emit!(VoteCast {
-            proposal_id: proposal.id,
+        Ok(())
+    }
+
+    /// Choose whether the agent's vote rewards are restaked or paid out
+    pub fn set_compound_rewards(ctx: Context<SetCommission>, enabled: bool) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        agent_registry.compound_rewards = enabled;
+        
+        log_event!(CompoundingUpdated {
+            owner: agent_registry.agent_pubkey,
             agent: agent_registry.agent_pubkey,
-            vote_yes,
-            stake_amount,
-            voting_power,
+            enabled,
         });
         
         Ok(())
     }
 
-    pub fn trigger_circuit_breaker(
-        ctx: Context<TriggerCircuitBreaker>,
-        reason: String,
+    /// Submit an ILI value signed by an agent's EVM key. The transaction must
+    /// include a secp256k1 precompile instruction over `ili_update_message`
+    /// (or its legacy format until the cutoff epoch); anyone may relay it and
+    /// pay for the agent's stats account.
+    pub fn submit_ili_update_secp256k1<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitILIUpdateSecp256k1<'info>>,
+        ili_value: u64,
+        timestamp: i64,
     ) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
-        let agent_registry = &ctx.accounts.agent_registry;
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let ili_oracle = &mut ctx.accounts.ili_oracle;
+        let global_state = &ctx.accounts.global_state;
         let current_time = Clock::get()?.unix_timestamp;
         
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
         require!(
-            agent_registry.reputation_score >= 100,
-            ErrorCode::InsufficientReputation
+            agent_registry.key_type == AgentKeyType::Secp256k1,
+            ErrorCode::AgentKeyTypeMismatch
+        );
+        let min_tier = ars_common::read_param_table_or_default(&ctx.accounts.parameter_registry, ctx.program_id)?
+            .tier(ParamKey::IliSubmissionMinTier)?;
+        require!(
+            agent_registry.agent_tier as u8 >= min_tier,
+            ErrorCode::InsufficientTier
+        );
+        require!(
+            !global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerActive
         );
         
-        global_state.circuit_breaker_active = true;
-        global_state.circuit_breaker_timelock = current_time
-            .checked_add(24 * 60 * 60)
+        // One submission per agent per oracle round
+        let next_submission = agent_registry.last_submission
+            .checked_add(ili_oracle.update_interval)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            current_time >= next_submission,
+            ErrorCode::UpdateTooFrequent
+        );
         
-        emit!(CircuitBreakerTriggered {
-            agent: agent_registry.agent_pubkey,
-            reason,
-            timelock_expires: global_state.circuit_breaker_timelock,
-        });
+        // Anyone may relay the message, so its timestamp doubles as a nonce:
+        // recent, and later than the last one this agent signed
+        require!(
+            timestamp <= current_time
+                && current_time.saturating_sub(timestamp) <= MAX_SIGNED_SUBMISSION_AGE,
+            ErrorCode::StaleSubmission
+        );
+        require!(
+            timestamp > agent_registry.last_signed_timestamp,
+            ErrorCode::ReplayedSubmission
+        );
+        
+        verify_secp256k1_agent_message(
+            &ctx.accounts.instructions_sysvar,
+            &agent_registry.evm_address,
+            &ili_update_message(&agent_registry.agent_pubkey, ili_value, timestamp),
+            &legacy::ili_update_message(&agent_registry.agent_pubkey, ili_value, timestamp),
+            global_state.epoch_at(current_time),
+            global_state.legacy_message_cutoff_epoch,
+        )?;
+        
+        agent_registry.last_signed_timestamp = timestamp;
+        agent_registry.last_submission = current_time;
+        agent_registry.last_active = current_time;
+        agent_registry.total_ili_updates = agent_registry.total_ili_updates.saturating_add(1);
+        
+        let agent_stats = &mut ctx.accounts.agent_stats;
+        let epoch = global_state.epoch_at(current_time);
+        agent_stats.agent = agent_registry.agent_pubkey;
+        agent_stats.bump = ctx.bumps.agent_stats;
+        agent_stats.record_submission(epoch);
+        
+        if let Some((median, round)) = ili_oracle.record_submission(
+            agent_registry.agent_pubkey,
+            ili_value,
+            timestamp,
+            current_time,
+        ) {
+            let accurate_agents = score_consensus_round(
+                agent_registry,
+                agent_stats,
+                &round,
+                median,
+                epoch,
+                ctx.remaining_accounts,
+            )?;
+            
+            log_event!(ILIUpdated {
+                ili_value: median,
+                smoothed_ili: ili_oracle.smoothed_ili,
+                consensus_agents: round.len() as u8,
+                accurate_agents,
+                timestamp: current_time,
+            });
+        }
         
         Ok(())
     }
 
-    pub fn slash_agent(
-        ctx: Context<SlashAgent>,
-        slash_amount: u64,
-        reason: String,
+    /// Open a proposal. `outcome_criterion` picks the feed and horizon that
+    /// score it once executed; None keeps the smoothed ILI over
+    /// OUTCOME_EVALUATION_EPOCHS epochs.
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        policy_params: PolicyParams,
+        voting_period: i64,
+        metadata: ProposalMetadata,
+        outcome_criterion: Option<OutcomeCriterion>,
     ) -> Result<()> {
-        let global_state = &ctx.accounts.global_state;
-        let agent_registry = &mut ctx.accounts.agent_registry;
-        
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
         require!(
-            ctx.accounts.authority.key() == global_state.authority,
-            ErrorCode::Unauthorized
+            (MIN_VOTING_PERIOD..=MAX_VOTING_PERIOD).contains(&voting_period),
+            ErrorCode::InvalidVotingPeriod
         );
+        policy_params.validate()?;
+        let outcome_criterion = outcome_criterion.unwrap_or_default();
+        outcome_criterion.validate()?;
+        let policy_type = policy_params.policy_type();
         require!(
-            slash_amount <= agent_registry.stake_amount,
-            ErrorCode::SlashAmountTooHigh
+            policy_type != PolicyType::Emergency,
+            ErrorCode::InvalidPolicyType
         );
-        
-        agent_registry.stake_amount = agent_registry.stake_amount
-            .checked_sub(slash_amount)
+
+        let global_state = &mut ctx.accounts.global_state;
+        let deposit = global_state
+            .check_proposer(policy_type, ctx.accounts.agent_registry.as_deref())?;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        proposal.open(
+            global_state.proposal_counter,
+            ctx.accounts.proposer.key(),
+            policy_params,
+            metadata,
+            clock.unix_timestamp,
+            voting_period,
+            ctx.bumps.proposal,
+        )?;
+        proposal.outcome_criterion = outcome_criterion;
+        proposal.griefing_protection_deposit = deposit;
+        collect_deposit(
+            &ctx.accounts.token_program,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.proposer,
+            deposit,
+        )?;
+        ctx.accounts.proposal_hash.register(proposal, clock.unix_timestamp, ctx.bumps.proposal_hash)?;
+
+        global_state.proposal_counter = global_state.proposal_counter
+            .checked_add(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
-        agent_registry.slashed_amount = agent_registry.slashed_amount
-            .checked_add(slash_amount)
+
+        log_event!(ProposalCreated {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            policy_type,
+            description_hash: proposal.metadata.description_hash,
+            uri: proposal.metadata.uri.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Create a proposal with a voting period as short as
+    /// FAST_TRACK_MIN_VOTING_PERIOD. The proposer and FAST_TRACK_COSPONSORS
+    /// other agents, passed as (agent signer, agent registry) remaining account
+    /// pairs, must all be active with at least FAST_TRACK_MIN_REPUTATION. Only
+    /// voting is shortened: the proposal still waits out EXECUTION_DELAY.
+    pub fn create_fast_track_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateFastTrackProposal<'info>>,
+        policy_params: PolicyParams,
+        voting_period: i64,
+        metadata: ProposalMetadata,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        require!(
+            (FAST_TRACK_MIN_VOTING_PERIOD..=MAX_VOTING_PERIOD).contains(&voting_period),
+            ErrorCode::InvalidVotingPeriod
+        );
+        policy_params.validate()?;
+        let policy_type = policy_params.policy_type();
+        require!(
+            policy_type != PolicyType::Emergency,
+            ErrorCode::InvalidPolicyType
+        );
+        require!(
+            ctx.accounts.agent_registry.can_fast_track(),
+            ErrorCode::InsufficientReputation
+        );
+
+        require!(
+            ctx.remaining_accounts.len() == FAST_TRACK_COSPONSORS * 2,
+            ErrorCode::InvalidCosponsors
+        );
+        let proposer = ctx.accounts.proposer.key();
+        let mut cosponsors: Vec<Pubkey> = Vec::with_capacity(FAST_TRACK_COSPONSORS);
+        for pair in ctx.remaining_accounts.chunks(2) {
+            let (cosponsor, registry_info) = (&pair[0], &pair[1]);
+            require!(
+                cosponsor.is_signer
+                    && *cosponsor.key != proposer
+                    && !cosponsors.contains(cosponsor.key),
+                ErrorCode::InvalidCosponsors
+            );
+            let (expected, _) =
+                Pubkey::find_program_address(&[b"agent", cosponsor.key.as_ref()], ctx.program_id);
+            require_keys_eq!(registry_info.key(), expected, ErrorCode::InvalidCosponsors);
+
+            let registry = Account::<AgentRegistry>::try_from(registry_info)?;
+            require!(registry.can_fast_track(), ErrorCode::InvalidCosponsors);
+            cosponsors.push(cosponsor.key());
+        }
+
+        let global_state = &mut ctx.accounts.global_state;
+        let deposit = global_state
+            .check_proposer(policy_type, Some(&*ctx.accounts.agent_registry))?;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        proposal.open(
+            global_state.proposal_counter,
+            proposer,
+            policy_params,
+            metadata,
+            clock.unix_timestamp,
+            voting_period,
+            ctx.bumps.proposal,
+        )?;
+        proposal.griefing_protection_deposit = deposit;
+        collect_deposit(
+            &ctx.accounts.token_program,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.proposer,
+            deposit,
+        )?;
+        ctx.accounts.proposal_hash.register(proposal, clock.unix_timestamp, ctx.bumps.proposal_hash)?;
+
+        global_state.proposal_counter = global_state.proposal_counter
+            .checked_add(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
-        agent_registry.reputation_score = agent_registry.reputation_score
-            .checked_sub(50)
-            .unwrap_or(-1000);
+
+        log_event!(ProposalCreated {
+            proposal_id: proposal.id,
+            proposer,
+            policy_type,
+            description_hash: proposal.metadata.description_hash,
+            uri: proposal.metadata.uri.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+        log_event!(ProposalFastTracked {
+            proposal_id: proposal.id,
+            proposer,
+            cosponsors,
+            voting_period,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Open an optimistic ParamUpdate proposal for a change within the field's
+    /// optimistic band. It passes at resolution unless at least
+    /// OPTIMISTIC_CHALLENGE_STAKE of NO stake was posted during the
+    /// challenge period; no quorum or supermajority is needed.
+    pub fn create_optimistic_proposal(
+        ctx: Context<CreateOptimisticProposal>,
+        field: ProtocolParam,
+        value: u64,
+        metadata: ProposalMetadata,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let policy_params = PolicyParams::ParamUpdate { field, value };
+        policy_params.validate()?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        require!(
+            global_state.within_optimistic_band(field, value),
+            ErrorCode::OutsideOptimisticBand
+        );
+        let deposit = global_state.check_proposer(
+            PolicyType::UpdateParameters,
+            ctx.accounts.agent_registry.as_deref(),
+        )?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        proposal.open(
+            global_state.proposal_counter,
+            ctx.accounts.proposer.key(),
+            policy_params,
+            metadata,
+            current_time,
+            OPTIMISTIC_CHALLENGE_PERIOD,
+            ctx.bumps.proposal,
+        )?;
+        proposal.optimistic = true;
+        proposal.griefing_protection_deposit = deposit;
+        collect_deposit(
+            &ctx.accounts.token_program,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.proposer,
+            deposit,
+        )?;
+        ctx.accounts.proposal_hash.register(proposal, current_time, ctx.bumps.proposal_hash)?;
+
+        global_state.proposal_counter = global_state.proposal_counter
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        log_event!(ProposalCreated {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            policy_type: PolicyType::UpdateParameters,
+            description_hash: proposal.metadata.description_hash,
+            uri: proposal.metadata.uri.clone(),
+            timestamp: current_time,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Open a conviction-mode TreasurySpend proposal for continuous funding
+    /// such as keeper incentive budgets. Net support accrues as conviction
+    /// over up to CONVICTION_VOTING_PERIOD, kept current by update_conviction,
+    /// and the proposal can be resolved as soon as it crosses the threshold.
+    pub fn create_conviction_proposal(
+        ctx: Context<CreateProposal>,
+        policy_params: PolicyParams,
+        metadata: ProposalMetadata,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        policy_params.validate()?;
+        require!(
+            matches!(policy_params, PolicyParams::TreasurySpend { .. }),
+            ErrorCode::InvalidPolicyType
+        );
+
+        let global_state = &mut ctx.accounts.global_state;
+        let deposit = global_state
+            .check_proposer(PolicyType::TreasurySpend, ctx.accounts.agent_registry.as_deref())?;
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        proposal.open(
+            global_state.proposal_counter,
+            ctx.accounts.proposer.key(),
+            policy_params,
+            metadata,
+            current_time,
+            CONVICTION_VOTING_PERIOD,
+            ctx.bumps.proposal,
+        )?;
+        proposal.conviction_mode = true;
+        proposal.griefing_protection_deposit = deposit;
+        collect_deposit(
+            &ctx.accounts.token_program,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.proposer,
+            deposit,
+        )?;
+        ctx.accounts.proposal_hash.register(proposal, current_time, ctx.bumps.proposal_hash)?;
+
+        global_state.proposal_counter = global_state.proposal_counter
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        log_event!(ProposalCreated {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            policy_type: PolicyType::TreasurySpend,
+            description_hash: proposal.metadata.description_hash,
+            uri: proposal.metadata.uri.clone(),
+            timestamp: current_time,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Accrue a conviction proposal's conviction up to now. Permissionless;
+    /// keepers crank it so UIs and resolve_proposal see current conviction.
+    pub fn update_conviction(ctx: Context<UpdateConviction>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
         
-        if agent_registry.stake_amount < 100_000_000 {
-            agent_registry.is_active = false;
-        }
+        require!(proposal.conviction_mode, ErrorCode::InvalidPolicyType);
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
         
-        emit!(AgentSlashed {
-            agent: agent_registry.agent_pubkey,
-            slash_amount,
-            reason,
-            new_reputation: agent_registry.reputation_score,
+        proposal.update_conviction(current_time);
+        
+        log_event!(ConvictionUpdated {
+            proposal_id: proposal.id,
+            conviction: proposal.conviction,
+            threshold: ctx.accounts.global_state.conviction_threshold(),
+            timestamp: current_time,
         });
         
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = GlobalState::LEN,
+    /// Open an emergency proposal while the circuit breaker is active. Voting
+    /// runs for EMERGENCY_VOTING_PERIOD, only Gold+ agents may propose or
+    /// vote, and approval needs EMERGENCY_QUORUM_MULTIPLIER times the quorum.
+    pub fn create_emergency_proposal(
+        ctx: Context<CreateEmergencyProposal>,
+        action: EmergencyAction,
+        metadata: ProposalMetadata,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        ars_common::lock(global_state, ctx.program_id)?;
+        let agent_registry = &ctx.accounts.agent_registry;
+        
+        require!(
+            global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerNotActive
+        );
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        require!(
+            agent_registry.agent_tier >= AgentTier::Gold,
+            ErrorCode::InsufficientTier
+        );
+        let deposit = global_state.check_proposer(PolicyType::Emergency, Some(&**agent_registry))?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        proposal.open(
+            global_state.proposal_counter,
+            ctx.accounts.proposer.key(),
+            PolicyParams::EmergencyParams { action },
+            metadata,
+            current_time,
+            EMERGENCY_VOTING_PERIOD,
+            ctx.bumps.proposal,
+        )?;
+        proposal.griefing_protection_deposit = deposit;
+        collect_deposit(
+            &ctx.accounts.token_program,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.proposer,
+            deposit,
+        )?;
+        ctx.accounts.proposal_hash.register(proposal, current_time, ctx.bumps.proposal_hash)?;
+        
+        global_state.proposal_counter = global_state.proposal_counter
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        log_event!(ProposalCreated {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            policy_type: PolicyType::Emergency,
+            description_hash: proposal.metadata.description_hash,
+            uri: proposal.metadata.uri.clone(),
+            timestamp: current_time,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Vote with `stake_amount` of the voter's own undelegated stake. Active
+    /// Delegation accounts naming the voter as delegatee can be passed as
+    /// remaining accounts to add their amounts to the voting power. An agent's
+    /// power is scaled by their reputation and by their accuracy over the last
+    /// accuracy_window settled votes, as recorded in their AgentStats.
+    ///
+    /// An agent's vote can be signed by its operator; the vote is recorded
+    /// for the agent either way. A voter without an agent registry passes
+    /// their VoterLock instead, signs themselves and passes a `stake_amount`
+    /// of zero. Their voting power comes from the lock's veARU balance at
+    /// neutral reputation and accuracy.
+    pub fn vote_on_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteOnProposal<'info>>,
+        vote_yes: bool,
+        stake_amount: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let authority = ctx.accounts.authority.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        let mut delegated_stake: u64 = 0;
+        let voting_power = if let Some(agent_registry) = &mut ctx.accounts.agent_registry {
+            require!(agent_registry.is_authority(&authority), ErrorCode::NotAgentAuthority);
+            proposal.require_votable(agent_registry, stake_amount, current_time)?;
+            agent_registry.lock_votes_until(proposal.end_time);
+            
+            let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+            for info in ctx.remaining_accounts.iter() {
+                require!(!seen.contains(info.key), ErrorCode::InvalidDelegation);
+                seen.push(info.key());
+                
+                let delegation = Account::<Delegation>::try_from(info)?;
+                require!(
+                    delegation.delegatee == ctx.accounts.voter.key()
+                        && delegation.counts_for(proposal, current_time),
+                    ErrorCode::InvalidDelegation
+                );
+                delegated_stake = delegated_stake
+                    .checked_add(delegation.amount)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+            
+            let effective_stake = stake_amount
+                .checked_add(delegated_stake)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            let global_state = &ctx.accounts.global_state;
+            let accuracy = AgentStats::accuracy_of(&ctx.accounts.agent_stats, global_state.accuracy_window)?;
+            global_state.voting_power(effective_stake, agent_registry.reputation_score, accuracy)
+        } else {
+            let voter_lock = ctx.accounts.voter_lock
+                .as_ref()
+                .ok_or(ErrorCode::NoVotingAccount)?;
+            require_keys_eq!(authority, ctx.accounts.voter.key(), ErrorCode::Unauthorized);
+            proposal.require_lock_votable(voter_lock, stake_amount, current_time)?;
+            
+            ctx.accounts.global_state
+                .voting_power(voter_lock.balance_at(current_time), 0, None)
+        };
+        
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.vote_yes = vote_yes;
+        vote_record.stake_amount = stake_amount;
+        vote_record.delegated_stake = delegated_stake;
+        vote_record.voting_power = voting_power;
+        vote_record.voted_at = current_time;
+        vote_record.claimed = false;
+        vote_record.slashed = 0;
+        vote_record.participation_claimed = false;
+        vote_record.simulation_hash = [0; 32];
+        vote_record.bump = ctx.bumps.vote_record;
+        
+        proposal.update_conviction(current_time);
+        proposal.add_vote(vote_yes, stake_amount, voting_power)?;
+        
+        log_event!(VoteCast {
+            proposal_id: proposal.id,
+            agent: vote_record.voter,
+            vote_yes,
+            stake_amount,
+            delegated_stake,
+            voting_power,
+        });
+        
+        Ok(())
+    }
+
+    /// Vote on several proposals in one transaction. Remaining accounts are
+    /// (proposal, vote_record) pairs in the order of `votes`; each vote is
+    /// checked and recorded as in vote_on_proposal, without delegated power,
+    /// and may be signed by the agent's operator. Any failing vote reverts
+    /// the whole batch.
+    pub fn vote_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteBatch<'info>>,
+        votes: Vec<BatchVote>,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        require!(
+            !votes.is_empty()
+                && votes.len() <= MAX_BATCH_VOTES
+                && ctx.remaining_accounts.len() == votes.len() * 2,
+            ErrorCode::InvalidVoteBatch
+        );
+        
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let voter = ctx.accounts.voter.key();
+        let authority = ctx.accounts.authority.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let current_time = Clock::get()?.unix_timestamp;
+        let accuracy = AgentStats::accuracy_of(
+            &ctx.accounts.agent_stats,
+            ctx.accounts.global_state.accuracy_window,
+        )?;
+        
+        for (vote, pair) in votes.iter().zip(ctx.remaining_accounts.chunks_exact(2)) {
+            let mut proposal = Account::<PolicyProposal>::try_from(&pair[0])?;
+            proposal.require_votable(agent_registry, vote.stake_amount, current_time)?;
+            agent_registry.lock_votes_until(proposal.end_time);
+            
+            // A proposal listed twice fails here on its second record
+            let bump = create_vote_record(&pair[1], &proposal.key(), &voter, &authority, &system_program)?;
+            let mut vote_record = Account::<VoteRecord>::try_from_unchecked(&pair[1])?;
+            
+            let voting_power = ctx.accounts.global_state
+                .voting_power(vote.stake_amount, agent_registry.reputation_score, accuracy);
+            vote_record.proposal = proposal.key();
+            vote_record.voter = voter;
+            vote_record.vote_yes = vote.vote_yes;
+            vote_record.stake_amount = vote.stake_amount;
+            vote_record.delegated_stake = 0;
+            vote_record.voting_power = voting_power;
+            vote_record.voted_at = current_time;
+            vote_record.claimed = false;
+            vote_record.slashed = 0;
+            vote_record.participation_claimed = false;
+            vote_record.simulation_hash = [0; 32];
+            vote_record.bump = bump;
+            vote_record.exit(&crate::ID)?;
+            
+            proposal.update_conviction(current_time);
+            proposal.add_vote(vote.vote_yes, vote.stake_amount, voting_power)?;
+            proposal.exit(&crate::ID)?;
+            
+            log_event!(VoteCast {
+                proposal_id: proposal.id,
+                agent: agent_registry.agent_pubkey,
+                vote_yes: vote.vote_yes,
+                stake_amount: vote.stake_amount,
+                delegated_stake: 0,
+                voting_power,
+            });
+        }
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Switch an existing vote to the other side while voting is open. The
+    /// vote keeps its stake and voting power.
+    pub fn change_vote(ctx: Context<ChangeVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            current_time >= proposal.start_time && current_time < proposal.end_time,
+            ErrorCode::ProposalNotActive
+        );
+        
+        proposal.update_conviction(current_time);
+        proposal.remove_vote(vote_record.vote_yes, vote_record.stake_amount, vote_record.voting_power)?;
+        vote_record.vote_yes = !vote_record.vote_yes;
+        vote_record.voted_at = current_time;
+        proposal.add_vote(vote_record.vote_yes, vote_record.stake_amount, vote_record.voting_power)?;
+        
+        log_event!(VoteChanged {
+            proposal_id: proposal.id,
+            agent: vote_record.voter,
+            vote_yes: vote_record.vote_yes,
+            voting_power: vote_record.voting_power,
+        });
+        
+        Ok(())
+    }
+
+    /// Withdraw a vote while voting is open, removing it from the tallies and
+    /// closing the VoteRecord so the agent may vote again
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &ctx.accounts.vote_record;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            current_time >= proposal.start_time && current_time < proposal.end_time,
+            ErrorCode::ProposalNotActive
+        );
+        
+        proposal.update_conviction(current_time);
+        proposal.remove_vote(vote_record.vote_yes, vote_record.stake_amount, vote_record.voting_power)?;
+        
+        log_event!(VoteWithdrawn {
+            proposal_id: proposal.id,
+            agent: vote_record.voter,
+            stake_amount: vote_record.stake_amount,
+            voting_power: vote_record.voting_power,
+        });
+        
+        Ok(())
+    }
+
+    /// Delegate `amount` of the caller's undelegated stake to another active
+    /// agent until `expiry`. One delegation per delegator at a time.
+    pub fn delegate_votes(
+        ctx: Context<DelegateVotes>,
+        amount: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        let delegator_registry = &mut ctx.accounts.delegator_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(delegator_registry.is_active, ErrorCode::AgentNotActive);
+        require!(ctx.accounts.delegatee_registry.is_active, ErrorCode::AgentNotActive);
+        require!(
+            ctx.accounts.delegatee_registry.agent_pubkey != delegator_registry.agent_pubkey,
+            ErrorCode::InvalidDelegation
+        );
+        require!(
+            amount > 0 && amount <= delegator_registry.available_stake(),
+            ErrorCode::InvalidStakeAmount
+        );
+        let max_expiry = current_time
+            .checked_add(MAX_DELEGATION_DURATION)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            expiry > current_time && expiry <= max_expiry,
+            ErrorCode::InvalidDelegationExpiry
+        );
+        
+        delegator_registry.delegated_out = delegator_registry.delegated_out
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let delegation = &mut ctx.accounts.delegation;
+        delegation.delegator = delegator_registry.agent_pubkey;
+        delegation.delegatee = ctx.accounts.delegatee_registry.agent_pubkey;
+        delegation.amount = amount;
+        delegation.expiry = expiry;
+        delegation.created_at = current_time;
+        delegation.bump = ctx.bumps.delegation;
+        
+        log_event!(VotesDelegated {
+            delegator: delegation.delegator,
+            delegatee: delegation.delegatee,
+            amount,
+            expiry,
+        });
+        
+        Ok(())
+    }
+
+    /// Revoke the caller's delegation, returning its stake to the caller's own
+    /// voting power for proposals that start afterwards
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        let delegator_registry = &mut ctx.accounts.delegator_registry;
+        let delegation = &ctx.accounts.delegation;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        delegator_registry.delegated_out = delegator_registry.delegated_out
+            .saturating_sub(delegation.amount);
+        delegator_registry.last_revoked_at = current_time;
+        
+        log_event!(DelegationRevoked {
+            delegator: delegation.delegator,
+            delegatee: delegation.delegatee,
+            amount: delegation.amount,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Start withdrawing `amount` of the caller's undelegated stake, keeping
+    /// at least min_agent_stake bonded. The amount stops counting toward the
+    /// agent's tier and new votes at once but stays in the escrow, and
+    /// slashable, for UNSTAKE_COOLDOWN and until none of the agent's votes
+    /// can still be settled. Another request adds to the pending
+    /// amount and restarts the cooldown. Votes already cast with more than the
+    /// remaining stake can be cut down by anyone with cap_vote_stake.
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        require!(
+            amount > 0 && amount <= agent_registry.available_stake(),
+            ErrorCode::InvalidStakeAmount
+        );
+        
+        let pending_unstake = agent_registry.pending_unstake
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        // Holder stake counts as available but is not the agent's to unstake
+        let bonded = agent_registry.stake_amount
+            .checked_sub(pending_unstake)
+            .ok_or(ErrorCode::InvalidStakeAmount)?;
+        require!(
+            bonded >= ctx.accounts.global_state.min_agent_stake,
+            ErrorCode::InsufficientStake
+        );
+        
+        agent_registry.pending_unstake = pending_unstake;
+        agent_registry.unstake_available_at = current_time
+            .checked_add(UNSTAKE_COOLDOWN)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.refresh_tier();
+        
+        log_event!(UnstakeRequested {
+            agent: agent_registry.agent_pubkey,
+            amount,
+            pending_unstake,
+            new_tier: agent_registry.agent_tier,
+            available_at: agent_registry.unstake_available_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Withdraw the pending unstake from the escrow once its cooldown has
+    /// ended and the agent's votes are settled. Stake slashed in the meantime
+    /// is not paid out.
+    pub fn withdraw_unstaked(ctx: Context<WithdrawUnstaked>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(agent_registry.pending_unstake > 0, ErrorCode::NoPendingUnstake);
+        require!(
+            current_time >= agent_registry.unstake_available_at,
+            ErrorCode::UnstakeCooldownActive
+        );
+        require!(
+            current_time >= agent_registry.vote_lock_until,
+            ErrorCode::VotesUnsettled
+        );
+        
+        let amount = agent_registry.pending_unstake.min(
+            agent_registry.stake_amount
+                .saturating_sub(agent_registry.delegated_out)
+                .saturating_sub(agent_registry.pending_slash),
+        );
+        agent_registry.stake_amount = agent_registry.stake_amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.pending_unstake = 0;
+        agent_registry.unstake_available_at = 0;
+        agent_registry.refresh_tier();
+        if agent_registry.stake_amount < ctx.accounts.global_state.min_agent_stake {
+            agent_registry.is_active = false;
+        }
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.agent_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(UnstakeWithdrawn {
+            agent: agent_registry.agent_pubkey,
+            amount,
+            stake_amount: agent_registry.stake_amount,
+            new_tier: agent_registry.agent_tier,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Record that the agent is alive. An agent deactivated by
+    /// deactivate_stale_agent becomes active again if it still has the
+    /// minimum stake and is not exiting.
+    pub fn agent_heartbeat(ctx: Context<AgentHeartbeat>) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        agent_registry.last_active = current_time;
+        if !agent_registry.is_active
+            && agent_registry.exit_available_at == 0
+            && agent_registry.stake_amount >= ctx.accounts.global_state.min_agent_stake
+        {
+            agent_registry.is_active = true;
+            
+            log_event!(AgentReactivated {
+                agent: agent_registry.agent_pubkey,
+                timestamp: current_time,
+            });
+        }
+        
+        Ok(())
+    }
+
+    /// Deactivate an agent that has neither submitted nor sent a heartbeat
+    /// for agent_silence_period, so it stops counting toward consensus.
+    /// Permissionless.
+    pub fn deactivate_stale_agent(ctx: Context<DeactivateStaleAgent>) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        require!(
+            agent_registry.is_stale(current_time, ctx.accounts.global_state.agent_silence_period),
+            ErrorCode::AgentNotStale
+        );
+        
+        agent_registry.is_active = false;
+        
+        log_event!(StaleAgentDeactivated {
+            agent: agent_registry.agent_pubkey,
+            last_active: agent_registry.last_active,
+            deactivated_by: ctx.accounts.caller.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Add `amount` of the agent's ARU to its stake. An agent deactivated by
+    /// slashing becomes active again once its stake is back at the minimum.
+    pub fn increase_stake(ctx: Context<AdjustStake>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        
+        require!(amount > 0, ErrorCode::InvalidStakeAmount);
+        require!(agent_registry.exit_available_at == 0, ErrorCode::AgentNotActive);
+        
+        let old_tier = agent_registry.agent_tier;
+        agent_registry.stake_amount = agent_registry.stake_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.refresh_tier();
+        if agent_registry.stake_amount >= ctx.accounts.global_state.min_agent_stake {
+            agent_registry.is_active = true;
+        }
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.agent_token_account.to_account_info(),
+                    to: ctx.accounts.stake_escrow.to_account_info(),
+                    authority: ctx.accounts.agent.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        log_event!(StakeIncreased {
+            agent: agent_registry.agent_pubkey,
+            amount,
+            stake_amount: agent_registry.stake_amount,
+        });
+        if agent_registry.agent_tier != old_tier {
+            log_event!(AgentTierChanged {
+                agent: agent_registry.agent_pubkey,
+                old_tier,
+                new_tier: agent_registry.agent_tier,
+            });
+        }
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Withdraw `amount` of stake at once, keeping at least min_agent_stake
+    /// bonded. Unlike request_unstake there is no cooldown, so it is only
+    /// allowed once none of the agent's votes can still be settled.
+    pub fn decrease_stake(ctx: Context<AdjustStake>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        require!(
+            current_time >= agent_registry.vote_lock_until,
+            ErrorCode::VotesUnsettled
+        );
+        let unbonded = agent_registry.stake_amount
+            .saturating_sub(agent_registry.delegated_out)
+            .saturating_sub(agent_registry.pending_unstake)
+            .saturating_sub(agent_registry.pending_slash);
+        require!(
+            amount > 0 && amount <= unbonded,
+            ErrorCode::InvalidStakeAmount
+        );
+        require!(
+            agent_registry.bonded_stake() - amount >= ctx.accounts.global_state.min_agent_stake,
+            ErrorCode::InsufficientStake
+        );
+        
+        let old_tier = agent_registry.agent_tier;
+        agent_registry.stake_amount -= amount;
+        agent_registry.refresh_tier();
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.agent_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(StakeDecreased {
+            agent: agent_registry.agent_pubkey,
+            amount,
+            stake_amount: agent_registry.stake_amount,
+        });
+        if agent_registry.agent_tier != old_tier {
+            log_event!(AgentTierChanged {
+                agent: agent_registry.agent_pubkey,
+                old_tier,
+                new_tier: agent_registry.agent_tier,
+            });
+        }
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Start an agent's exit: the agent stops voting and its stake can be
+    /// withdrawn with withdraw_stake after UNSTAKE_COOLDOWN, staying slashable
+    /// until then. Not allowed while any of its votes can still be settled.
+    pub fn initiate_unstake(ctx: Context<InitiateUnstake>) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        require!(
+            current_time >= agent_registry.vote_lock_until,
+            ErrorCode::VotesUnsettled
+        );
+        
+        agent_registry.is_active = false;
+        agent_registry.exit_available_at = current_time
+            .checked_add(UNSTAKE_COOLDOWN)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        log_event!(AgentExitInitiated {
+            agent: agent_registry.agent_pubkey,
+            stake_amount: agent_registry.stake_amount,
+            available_at: agent_registry.exit_available_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Finish an agent's exit once its cooldown has ended: return the stake
+    /// left after any slashing and close the agent registry. Vote delegations
+    /// and holder stake must be unwound first.
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let agent_registry = &ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(agent_registry.exit_available_at > 0, ErrorCode::ExitNotInitiated);
+        require!(
+            current_time >= agent_registry.exit_available_at,
+            ErrorCode::UnstakeCooldownActive
+        );
+        require!(
+            agent_registry.delegated_out == 0 && agent_registry.holder_stake == 0,
+            ErrorCode::AccountNotClosable
+        );
+        require!(agent_registry.pending_slash == 0, ErrorCode::SlashPending);
+        
+        let amount = agent_registry.stake_amount;
+        if amount > 0 {
+            let global_state = &ctx.accounts.global_state;
+            let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.stake_escrow.to_account_info(),
+                        to: ctx.accounts.agent_token_account.to_account_info(),
+                        authority: global_state.to_account_info(),
+                    },
+                    &[&global_seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+        
+        log_event!(AgentExited {
+            agent: agent_registry.agent_pubkey,
+            amount,
+            timestamp: current_time,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Delegate `amount` of the holder's ARU to an agent's stake. It moves
+    /// into the stake escrow, counts toward the agent's tier and voting stake,
+    /// and earns a pro-rata share of the agent's vote rewards.
+    pub fn delegate_stake(ctx: Context<DelegateStake>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let stake_delegation = &mut ctx.accounts.stake_delegation;
+        
+        require!(agent_registry.is_active, ErrorCode::AgentNotActive);
+        require!(amount > 0, ErrorCode::InvalidStakeAmount);
+        
+        if stake_delegation.holder == Pubkey::default() {
+            stake_delegation.holder = ctx.accounts.holder.key();
+            stake_delegation.agent = agent_registry.agent_pubkey;
+            stake_delegation.bump = ctx.bumps.stake_delegation;
+        }
+        stake_delegation.settle(agent_registry.holder_reward_per_share)?;
+        stake_delegation.amount = stake_delegation.amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake_delegation.reset_debt(agent_registry.holder_reward_per_share);
+        
+        agent_registry.holder_stake = agent_registry.holder_stake
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.refresh_tier();
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    to: ctx.accounts.stake_escrow.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        log_event!(StakeDelegated {
+            holder: stake_delegation.holder,
+            agent: stake_delegation.agent,
+            amount,
+            holder_stake: agent_registry.holder_stake,
+            new_tier: agent_registry.agent_tier,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Stop counting `amount` of the holder's delegated stake toward the
+    /// agent. It can be withdrawn after UNSTAKE_COOLDOWN; another request adds
+    /// to the pending amount and restarts the cooldown.
+    pub fn undelegate_stake(ctx: Context<UndelegateStake>, amount: u64) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let stake_delegation = &mut ctx.accounts.stake_delegation;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            amount > 0 && amount <= stake_delegation.amount,
+            ErrorCode::InvalidStakeAmount
+        );
+        
+        stake_delegation.settle(agent_registry.holder_reward_per_share)?;
+        stake_delegation.amount -= amount;
+        stake_delegation.reset_debt(agent_registry.holder_reward_per_share);
+        stake_delegation.pending_withdrawal = stake_delegation.pending_withdrawal
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake_delegation.withdraw_available_at = current_time
+            .checked_add(UNSTAKE_COOLDOWN)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        agent_registry.holder_stake = agent_registry.holder_stake
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.refresh_tier();
+        
+        log_event!(StakeUndelegated {
+            holder: stake_delegation.holder,
+            agent: stake_delegation.agent,
+            amount,
+            available_at: stake_delegation.withdraw_available_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Withdraw undelegated holder stake from the escrow once its cooldown
+    /// has ended
+    pub fn withdraw_delegated_stake(ctx: Context<WithdrawDelegatedStake>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let stake_delegation = &mut ctx.accounts.stake_delegation;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(stake_delegation.pending_withdrawal > 0, ErrorCode::NoPendingUnstake);
+        require!(
+            current_time >= stake_delegation.withdraw_available_at,
+            ErrorCode::UnstakeCooldownActive
+        );
+        
+        let amount = stake_delegation.pending_withdrawal;
+        stake_delegation.pending_withdrawal = 0;
+        stake_delegation.withdraw_available_at = 0;
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(DelegatedStakeWithdrawn {
+            holder: stake_delegation.holder,
+            agent: stake_delegation.agent,
+            amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Pay a holder the vote rewards earned by their delegated stake, or with
+    /// compound_rewards set and the agent still active, add them to the
+    /// delegation instead
+    pub fn claim_holder_rewards(ctx: Context<ClaimHolderRewards>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let stake_delegation = &mut ctx.accounts.stake_delegation;
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let reward_per_share = agent_registry.holder_reward_per_share;
+        
+        stake_delegation.settle(reward_per_share)?;
+        let amount = stake_delegation.unclaimed_rewards;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        stake_delegation.unclaimed_rewards = 0;
+        
+        let compounded = stake_delegation.compound_rewards && agent_registry.is_active;
+        if compounded {
+            stake_delegation.amount = stake_delegation.amount
+                .checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            agent_registry.holder_stake = agent_registry.holder_stake
+                .checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            agent_registry.refresh_tier();
+        } else {
+            let global_state = &ctx.accounts.global_state;
+            let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.stake_escrow.to_account_info(),
+                        to: ctx.accounts.holder_token_account.to_account_info(),
+                        authority: global_state.to_account_info(),
+                    },
+                    &[&global_seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+        stake_delegation.reset_debt(reward_per_share);
+        
+        log_event!(HolderRewardsClaimed {
+            holder: stake_delegation.holder,
+            agent: stake_delegation.agent,
+            amount,
+            compounded,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Choose whether a holder's delegation rewards are compounded into the
+    /// delegation or paid out
+    pub fn set_delegation_compounding(ctx: Context<SetDelegationCompounding>, enabled: bool) -> Result<()> {
+        let stake_delegation = &mut ctx.accounts.stake_delegation;
+        stake_delegation.compound_rewards = enabled;
+        
+        log_event!(CompoundingUpdated {
+            owner: stake_delegation.holder,
+            agent: stake_delegation.agent,
+            enabled,
+        });
+        
+        Ok(())
+    }
+
+    /// Lock `amount` more ARU until `unlock_at` for veARU voting power. A lock
+    /// can be topped up or extended but never shortened; an ended lock that
+    /// has not been withdrawn can be locked again.
+    pub fn lock_aru(ctx: Context<LockAru>, amount: u64, unlock_at: i64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let voter_lock = &mut ctx.accounts.voter_lock;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        if voter_lock.owner == Pubkey::default() {
+            voter_lock.owner = ctx.accounts.owner.key();
+            voter_lock.bump = ctx.bumps.voter_lock;
+        }
+        
+        let duration = unlock_at.saturating_sub(current_time);
+        require!(
+            (MIN_LOCK_DURATION..=MAX_LOCK_DURATION).contains(&duration)
+                && unlock_at >= voter_lock.lock_end,
+            ErrorCode::InvalidLockDuration
+        );
+        require!(
+            amount > 0 || voter_lock.amount > 0,
+            ErrorCode::InvalidStakeAmount
+        );
+        
+        voter_lock.amount = voter_lock.amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        voter_lock.lock_end = unlock_at;
+        
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.owner_token_account.to_account_info(),
+                        to: ctx.accounts.stake_escrow.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+        
+        log_event!(AruLocked {
+            owner: voter_lock.owner,
+            amount,
+            total_locked: voter_lock.amount,
+            lock_end: voter_lock.lock_end,
+            ve_balance: voter_lock.balance_at(current_time),
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Withdraw the ARU of an ended lock and close it
+    pub fn withdraw_lock(ctx: Context<WithdrawLock>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let voter_lock = &ctx.accounts.voter_lock;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(current_time >= voter_lock.lock_end, ErrorCode::LockNotExpired);
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            voter_lock.amount,
+        )?;
+        
+        log_event!(LockWithdrawn {
+            owner: voter_lock.owner,
+            amount: voter_lock.amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Cut a vote on an open proposal down to the stake its agent still has
+    /// available, recomputing its voting power. Permissionless, so a vote
+    /// cannot keep the weight of stake that is being unstaked.
+    pub fn cap_vote_stake(ctx: Context<CapVoteStake>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let agent_registry = &ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            proposal.status == ProposalStatus::Active && current_time < proposal.end_time,
+            ErrorCode::ProposalNotActive
+        );
+        let new_stake = agent_registry.available_stake();
+        require!(vote_record.stake_amount > new_stake, ErrorCode::VoteWithinStake);
+        
+        let effective_stake = new_stake
+            .checked_add(vote_record.delegated_stake)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let global_state = &ctx.accounts.global_state;
+        let accuracy = AgentStats::accuracy_of(&ctx.accounts.agent_stats, global_state.accuracy_window)?;
+        let voting_power = global_state
+            .voting_power(effective_stake, agent_registry.reputation_score, accuracy)
+            .min(vote_record.voting_power);
+        
+        proposal.update_conviction(current_time);
+        proposal.remove_vote(vote_record.vote_yes, vote_record.stake_amount, vote_record.voting_power)?;
+        proposal.add_vote(vote_record.vote_yes, new_stake, voting_power)?;
+        
+        let old_stake = vote_record.stake_amount;
+        vote_record.stake_amount = new_stake;
+        vote_record.voting_power = voting_power;
+        
+        log_event!(VoteStakeCapped {
+            proposal_id: proposal.id,
+            agent: vote_record.voter,
+            old_stake,
+            new_stake,
+            voting_power,
+        });
+        
+        Ok(())
+    }
+
+    /// Close voting on a proposal: it passes if quadratic yes power beats no,
+    /// or for an optimistic proposal if NO stake stayed below
+    /// OPTIMISTIC_CHALLENGE_STAKE. SLASHING_PENALTY_BPS of the losing side's stake becomes the reward pool
+    /// that winning voters share pro rata; with no winning stake nothing is
+    /// slashed. A conviction proposal passes, and may be resolved early, once
+    /// its conviction reaches the threshold.
+    pub fn resolve_proposal(ctx: Context<ResolveProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        proposal.update_conviction(current_time);
+        let conviction_reached = proposal.conviction_mode
+            && proposal.conviction >= ctx.accounts.global_state.conviction_threshold();
+        require!(
+            current_time >= proposal.end_time || conviction_reached,
+            ErrorCode::VotingPeriodActive
+        );
+        // Close voting on a conviction proposal resolved early
+        proposal.end_time = proposal.end_time.min(current_time);
+        
+        let passed = if proposal.optimistic {
+            proposal.no_stake < OPTIMISTIC_CHALLENGE_STAKE
+        } else if proposal.conviction_mode {
+            conviction_reached
+        } else {
+            proposal.quadratic_yes > proposal.quadratic_no
+        };
+        let (winning_stake, losing_stake) = if passed {
+            (proposal.yes_stake, proposal.no_stake)
+        } else {
+            (proposal.no_stake, proposal.yes_stake)
+        };
+        
+        proposal.status = if passed {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        };
+        proposal.winning_stake = winning_stake;
+        proposal.reward_pool = if winning_stake > 0 {
+            losing_stake_penalty(losing_stake)
+        } else {
+            0
+        };
+        
+        log_event!(ProposalResolved {
+            proposal_id: proposal.id,
+            passed,
+            winning_stake,
+            reward_pool: proposal.reward_pool,
+        });
+        
+        Ok(())
+    }
+
+    /// Score an executed proposal once its outcome criterion's horizon has
+    /// passed: YES was right if the criterion's metric did not fall below its
+    /// value at execution. The correct side becomes the winning side for
+    /// claim_vote_reward, replacing the vote-based split set at resolution.
+    pub fn evaluate_outcome(ctx: Context<EvaluateOutcome>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            proposal.status == ProposalStatus::Executed,
+            ErrorCode::ProposalNotExecuted
+        );
+        require!(
+            proposal.outcome == ProposalOutcome::Pending,
+            ErrorCode::OutcomeAlreadyEvaluated
+        );
+        let global_state = &ctx.accounts.global_state;
+        let criterion = proposal.outcome_criterion;
+        let evaluation_time = criterion
+            .evaluation_time(proposal.executed_at, global_state.epoch_duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(current_time >= evaluation_time, ErrorCode::OutcomeWindowActive);
+        
+        let metric_at_evaluation = read_outcome_metric(
+            criterion.metric,
+            global_state,
+            &ctx.accounts.ili_oracle,
+            ctx.accounts.reserve_vault.as_ref(),
+            ctx.accounts.aru_price_feed.as_ref(),
+            current_time,
+        )?;
+        let improved = metric_at_evaluation >= proposal.metric_at_execution;
+        let (winning_stake, losing_stake) = if improved {
+            (proposal.yes_stake, proposal.no_stake)
+        } else {
+            (proposal.no_stake, proposal.yes_stake)
+        };
+        
+        proposal.outcome = if improved {
+            ProposalOutcome::Improved
+        } else {
+            ProposalOutcome::Worsened
+        };
+        proposal.winning_stake = winning_stake;
+        proposal.reward_pool = if winning_stake > 0 {
+            losing_stake_penalty(losing_stake)
+        } else {
+            0
+        };
+        
+        log_event!(OutcomeEvaluated {
+            proposal_id: proposal.id,
+            metric: criterion.metric,
+            metric_at_execution: proposal.metric_at_execution,
+            metric_at_evaluation,
+            improved,
+            winning_stake,
+            reward_pool: proposal.reward_pool,
+        });
+        
+        Ok(())
+    }
+
+    /// Settle one VoteRecord of a resolved proposal. A winning vote is paid
+    /// its pro-rata share of the reward pool from the stake escrow; a losing
+    /// vote has SLASHING_PENALTY_BPS of its stake slashed from the agent's
+    /// registry. Anyone may settle, since payouts only go to the voter.
+    /// Votes on a passed proposal settle once evaluate_outcome has scored it.
+    /// The result is added to the voter's prediction history in AgentStats.
+    pub fn claim_vote_reward(ctx: Context<ClaimVoteReward>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        
+        require!(
+            proposal.status != ProposalStatus::Active,
+            ErrorCode::ProposalNotResolved
+        );
+        require!(!vote_record.claimed, ErrorCode::VoteAlreadySettled);
+        
+        let yes_won = proposal.yes_won().ok_or(ErrorCode::OutcomeNotEvaluated)?;
+        let won = vote_record.vote_yes == yes_won;
+        let mut payout = 0;
+        let mut holder_rewards = 0;
+        let mut commission = 0;
+        let mut compounded = false;
+        let mut slashed = 0;
+        
+        if won {
+            if proposal.winning_stake > 0 {
+                payout = ((proposal.reward_pool as u128)
+                    .checked_mul(vote_record.stake_amount as u128)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?
+                    / proposal.winning_stake as u128) as u64;
+            }
+            
+            if payout > 0 {
+                let global_state = &ctx.accounts.global_state;
+                let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+                let signer = &[&global_seeds[..]];
+                
+                if let Some(old_bps) = agent_registry.apply_pending_commission(Clock::get()?.unix_timestamp) {
+                    log_event!(CommissionUpdated {
+                        agent: agent_registry.agent_pubkey,
+                        old_bps,
+                        new_bps: agent_registry.commission_bps,
+                    });
+                }
+                
+                // The holders' share stays in the escrow until they claim it
+                (holder_rewards, commission) = agent_registry.credit_holders(payout)?;
+                compounded = agent_registry.compound_rewards;
+                if compounded {
+                    // Already in the escrow, so restaking needs no transfer
+                    agent_registry.stake_amount = agent_registry.stake_amount
+                        .checked_add(payout - holder_rewards)
+                        .ok_or(ErrorCode::ArithmeticOverflow)?;
+                    agent_registry.refresh_tier();
+                } else {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            Transfer {
+                                from: ctx.accounts.stake_escrow.to_account_info(),
+                                to: ctx.accounts.voter_token_account.to_account_info(),
+                                authority: global_state.to_account_info(),
+                            },
+                            signer,
+                        ),
+                        payout - holder_rewards,
+                    )?;
+                }
+                
+                proposal.rewards_claimed = proposal.rewards_claimed
+                    .checked_add(payout)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+        } else if proposal.reward_pool > 0 {
+            slashed = losing_stake_penalty(vote_record.stake_amount)
+                .min(agent_registry.stake_amount);
+            
+            agent_registry.stake_amount = agent_registry.stake_amount
+                .checked_sub(slashed)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            agent_registry.slashed_amount = agent_registry.slashed_amount
+                .checked_add(slashed)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            
+            if agent_registry.stake_amount < ctx.accounts.global_state.min_agent_stake {
+                agent_registry.is_active = false;
+            }
+        }
+        
+        vote_record.claimed = true;
+        vote_record.slashed = slashed;
+        
+        let agent_stats = &mut ctx.accounts.agent_stats;
+        agent_stats.agent = vote_record.voter;
+        agent_stats.bump = ctx.bumps.agent_stats;
+        agent_stats.record_prediction(won);
+        
+        log_event!(VoteSettled {
+            proposal_id: proposal.id,
+            voter: vote_record.voter,
+            won,
+            payout,
+            holder_rewards,
+            commission,
+            compounded,
+            slashed,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Pay the flat participation reward for one vote once its proposal is
+    /// resolved, whatever the outcome. Only proposals that reached quorum
+    /// pay, so spam proposals cannot farm the pool; the reward is capped by
+    /// what is left in it.
+    pub fn claim_participation_reward(ctx: Context<ClaimParticipationReward>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &ctx.accounts.proposal;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let global_state = &mut ctx.accounts.global_state;
+        
+        require!(
+            proposal.status != ProposalStatus::Active,
+            ErrorCode::ProposalNotResolved
+        );
+        require!(
+            !vote_record.participation_claimed,
+            ErrorCode::ParticipationRewardClaimed
+        );
+        let total_power = proposal.quadratic_yes.saturating_add(proposal.quadratic_no);
+        require!(
+            total_power >= global_state.quorum_power,
+            ErrorCode::QuorumNotReached
+        );
+        
+        let amount = global_state.participation_reward.min(global_state.participation_reward_pool);
+        if amount > 0 {
+            let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+            let signer = &[&global_seeds[..]];
+            
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.stake_escrow.to_account_info(),
+                        to: ctx.accounts.voter_token_account.to_account_info(),
+                        authority: global_state.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+            )?;
+            global_state.participation_reward_pool -= amount;
+        }
+        
+        vote_record.participation_claimed = true;
+        
+        log_event!(ParticipationRewardClaimed {
+            proposal_id: proposal.id,
+            voter: vote_record.voter,
+            amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Add ARU to the participation reward pool
+    pub fn fund_participation_rewards(
+        ctx: Context<FundParticipationRewards>,
+        amount: u64,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    to: ctx.accounts.stake_escrow.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.participation_reward_pool = global_state.participation_reward_pool
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Queue a passed proposal for execution after EXECUTION_DELAY, giving
+    /// holders time to react before the policy takes effect. Emergency ILI
+    /// overrides go through override_ili instead.
+    pub fn queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(
+            proposal.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassed
+        );
+        require!(
+            proposal.policy_type != PolicyType::OverrideILI
+                && proposal.policy_type != PolicyType::Emergency
+                && !proposal.optimistic,
+            ErrorCode::InvalidPolicyType
+        );
+        ctx.accounts.global_state.check_approval(proposal)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        proposal.status = ProposalStatus::Queued;
+        proposal.execution_eta = current_time
+            .checked_add(EXECUTION_DELAY)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        log_event!(ProposalQueued {
+            proposal_id: proposal.id,
+            execution_eta: proposal.execution_eta,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Block a passed proposal before it can execute. Guardians may veto until
+    /// the execution timelock expires. Votes on a passed proposal only settle
+    /// once its outcome is evaluated, so none has been paid or slashed yet;
+    /// the reward pool is dropped and none will be.
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            ctx.accounts.global_state.is_guardian(&ctx.accounts.guardian.key()),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            !ctx.accounts.global_state.authority_is_multisig,
+            ErrorCode::SingleKeyPathDisabled
+        );
+        require!(
+            proposal.status == ProposalStatus::Passed
+                || (proposal.status == ProposalStatus::Queued
+                    && current_time < proposal.execution_eta),
+            ErrorCode::ProposalNotVetoable
+        );
+        
+        proposal.status = ProposalStatus::Vetoed;
+        proposal.reward_pool = 0;
+        
+        log_event!(ProposalVetoed {
+            proposal_id: proposal.id,
+            guardian: ctx.accounts.guardian.key(),
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Create the treasury token account for `mint`. Anyone may pay for it;
+    /// only global_state can move its funds.
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        log_event!(TreasuryInitialized {
+            mint: ctx.accounts.mint.key(),
+            treasury: ctx.accounts.treasury.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Return the griefing deposit of an executed proposal to its proposer.
+    /// Deposits of proposals that never execute stay in the treasury.
+    pub fn reclaim_proposal_deposit(ctx: Context<ReclaimProposalDeposit>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(
+            proposal.status == ProposalStatus::Executed,
+            ErrorCode::ProposalNotExecuted
+        );
+        require!(proposal.griefing_protection_deposit > 0, ErrorCode::NothingToRefund);
+        
+        let amount = proposal.griefing_protection_deposit;
+        proposal.griefing_protection_deposit = 0;
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.proposer_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(DepositReclaimed {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Open a YES/NO prediction market on an active proposal, seeding its
+    /// pool with `liquidity` ARU from the caller (see market.rs)
+    pub fn open_market(ctx: Context<OpenMarket>, liquidity: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        proposal.market.open(ctx.accounts.liquidity_provider.key(), liquidity)?;
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.provider_token_account.to_account_info(),
+                    to: ctx.accounts.market_vault.to_account_info(),
+                    authority: ctx.accounts.liquidity_provider.to_account_info(),
+                },
+            ),
+            liquidity,
+        )?;
+        
+        log_event!(MarketOpened {
+            proposal_id: proposal.id,
+            liquidity_provider: ctx.accounts.liquidity_provider.key(),
+            liquidity,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Deposit `amount` ARU into a proposal's market for `amount` YES and
+    /// `amount` NO shares
+    pub fn mint_outcome_shares(ctx: Context<TradeOutcome>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        proposal.market.require_trading(proposal.yes_won().is_some())?;
+        proposal.market.change_collateral(amount, true)?;
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.trader_token_account.to_account_info(),
+                    to: ctx.accounts.market_vault.to_account_info(),
+                    authority: ctx.accounts.trader.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        let accounts = &ctx.accounts;
+        mint_shares(
+            &accounts.token_program,
+            &accounts.yes_mint,
+            &accounts.trader_yes_account,
+            &accounts.global_state,
+            amount,
+        )?;
+        mint_shares(
+            &accounts.token_program,
+            &accounts.no_mint,
+            &accounts.trader_no_account,
+            &accounts.global_state,
+            amount,
+        )?;
+        
+        log_event!(OutcomeSharesMinted {
+            proposal_id: accounts.proposal.id,
+            owner: accounts.trader.key(),
+            amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Burn `amount` YES and `amount` NO shares for `amount` ARU from the
+    /// market vault
+    pub fn merge_outcome_shares(ctx: Context<TradeOutcome>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        proposal.market.require_trading(proposal.yes_won().is_some())?;
+        proposal.market.change_collateral(amount, false)?;
+        
+        let accounts = &ctx.accounts;
+        burn_shares(
+            &accounts.token_program,
+            &accounts.yes_mint,
+            &accounts.trader_yes_account,
+            &accounts.trader,
+            amount,
+        )?;
+        burn_shares(
+            &accounts.token_program,
+            &accounts.no_mint,
+            &accounts.trader_no_account,
+            &accounts.trader,
+            amount,
+        )?;
+        
+        let global_seeds = &[b"global_state".as_ref(), &[accounts.global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.market_vault.to_account_info(),
+                    to: accounts.trader_token_account.to_account_info(),
+                    authority: accounts.global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(OutcomeSharesMerged {
+            proposal_id: accounts.proposal.id,
+            owner: accounts.trader.key(),
+            amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Sell `amount_in` shares of one side into the market's pool for at
+    /// least `min_out` shares of the other
+    pub fn swap_outcome(
+        ctx: Context<TradeOutcome>,
+        sell_yes: bool,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(amount_in > 0, ErrorCode::InvalidAmount);
+        proposal.market.require_trading(proposal.yes_won().is_some())?;
+        let amount_out = proposal.market.swap(sell_yes, amount_in)?;
+        require!(amount_out > 0 && amount_out >= min_out, ErrorCode::SlippageExceeded);
+        let yes_price_bps = proposal.market.yes_price_bps();
+        
+        let accounts = &ctx.accounts;
+        let (mint_in, from, mint_out, to) = if sell_yes {
+            (
+                &accounts.yes_mint,
+                &accounts.trader_yes_account,
+                &accounts.no_mint,
+                &accounts.trader_no_account,
+            )
+        } else {
+            (
+                &accounts.no_mint,
+                &accounts.trader_no_account,
+                &accounts.yes_mint,
+                &accounts.trader_yes_account,
+            )
+        };
+        burn_shares(&accounts.token_program, mint_in, from, &accounts.trader, amount_in)?;
+        mint_shares(&accounts.token_program, mint_out, to, &accounts.global_state, amount_out)?;
+        
+        log_event!(OutcomeSwapped {
+            proposal_id: accounts.proposal.id,
+            trader: accounts.trader.key(),
+            sell_yes,
+            amount_in,
+            amount_out,
+            yes_price_bps,
+        });
+        
+        Ok(())
+    }
+
+    /// Close a proposal's market once its winning side is known, burning
+    /// the pool's losing reserve. Permissionless.
+    pub fn resolve_market(ctx: Context<ResolveMarket>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        
+        let yes_won = proposal.yes_won().ok_or(ErrorCode::OutcomeNotEvaluated)?;
+        let final_yes_price_bps = proposal.market.yes_price_bps();
+        let burned_reserve = proposal.market.resolve(yes_won)?;
+        
+        log_event!(MarketResolved {
+            proposal_id: proposal.id,
+            yes_won,
+            burned_reserve,
+            final_yes_price_bps,
+        });
+        
+        Ok(())
+    }
+
+    /// Burn `amount` winning shares of a resolved market for `amount` ARU
+    /// from the market vault
+    pub fn redeem_outcome(ctx: Context<TradeOutcome>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(proposal.market.resolved, ErrorCode::MarketNotResolved);
+        let yes_won = proposal.yes_won().ok_or(ErrorCode::OutcomeNotEvaluated)?;
+        proposal.market.change_collateral(amount, false)?;
+        
+        let accounts = &ctx.accounts;
+        let (mint, from) = if yes_won {
+            (&accounts.yes_mint, &accounts.trader_yes_account)
+        } else {
+            (&accounts.no_mint, &accounts.trader_no_account)
+        };
+        burn_shares(&accounts.token_program, mint, from, &accounts.trader, amount)?;
+        
+        let global_seeds = &[b"global_state".as_ref(), &[accounts.global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.market_vault.to_account_info(),
+                    to: accounts.trader_token_account.to_account_info(),
+                    authority: accounts.global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(OutcomeRedeemed {
+            proposal_id: accounts.proposal.id,
+            owner: accounts.trader.key(),
+            amount,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Buy shares of one side with `collateral_in` ARU, receiving at least
+    /// `min_shares_out`. The MarketFeeBps registry parameter of the ARU goes
+    /// to the treasury.
+    pub fn buy_outcome(
+        ctx: Context<TradeOutcomeForAru>,
+        buy_yes: bool,
+        collateral_in: u64,
+        min_shares_out: u64,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(collateral_in > 0, ErrorCode::InvalidAmount);
+        proposal.market.require_trading(proposal.yes_won().is_some())?;
+        let fee_bps = ars_common::read_param_table_or_default(&ctx.accounts.parameter_registry, ctx.program_id)?
+            .bps(ParamKey::MarketFeeBps)?;
+        let fee = market_fee(collateral_in, fee_bps);
+        let shares_out = proposal.market.buy(buy_yes, collateral_in - fee)?;
+        require!(shares_out >= min_shares_out, ErrorCode::SlippageExceeded);
+        
+        let token_program = &ctx.accounts.token_program;
+        for (to, amount) in [
+            (ctx.accounts.market_vault.to_account_info(), collateral_in - fee),
+            (ctx.accounts.treasury.to_account_info(), fee),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.trader_token_account.to_account_info(),
+                        to,
+                        authority: ctx.accounts.trader.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+        let (mint, to) = if buy_yes {
+            (&ctx.accounts.yes_mint, &ctx.accounts.trader_yes_account)
+        } else {
+            (&ctx.accounts.no_mint, &ctx.accounts.trader_no_account)
+        };
+        mint_shares(token_program, mint, to, &ctx.accounts.global_state, shares_out)?;
+        
+        let accounts = &mut ctx.accounts;
+        require_backed(
+            &accounts.proposal.market,
+            &mut accounts.yes_mint,
+            &mut accounts.no_mint,
+            &mut accounts.market_vault,
+        )?;
+        
+        let proposal = &ctx.accounts.proposal;
+        log_event!(OutcomeBought {
+            proposal_id: proposal.id,
+            trader: ctx.accounts.trader.key(),
+            buy_yes,
+            collateral_in,
+            fee,
+            shares_out,
+            yes_price_bps: proposal.market.yes_price_bps(),
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Sell `shares_in` shares of one side for at least `min_collateral_out`
+    /// ARU. The MarketFeeBps registry parameter of the ARU goes to the
+    /// treasury.
+    pub fn sell_outcome(
+        ctx: Context<TradeOutcomeForAru>,
+        sell_yes: bool,
+        shares_in: u64,
+        min_collateral_out: u64,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(shares_in > 0, ErrorCode::InvalidAmount);
+        proposal.market.require_trading(proposal.yes_won().is_some())?;
+        let released = proposal.market.sell(sell_yes, shares_in)?;
+        let fee_bps = ars_common::read_param_table_or_default(&ctx.accounts.parameter_registry, ctx.program_id)?
+            .bps(ParamKey::MarketFeeBps)?;
+        let fee = market_fee(released, fee_bps);
+        let collateral_out = released - fee;
+        require!(
+            collateral_out > 0 && collateral_out >= min_collateral_out,
+            ErrorCode::SlippageExceeded
+        );
+        
+        let token_program = &ctx.accounts.token_program;
+        let (mint, from) = if sell_yes {
+            (&ctx.accounts.yes_mint, &ctx.accounts.trader_yes_account)
+        } else {
+            (&ctx.accounts.no_mint, &ctx.accounts.trader_no_account)
+        };
+        burn_shares(token_program, mint, from, &ctx.accounts.trader, shares_in)?;
+        
+        let global_seeds = &[b"global_state".as_ref(), &[ctx.accounts.global_state.bump]];
+        for (to, amount) in [
+            (ctx.accounts.trader_token_account.to_account_info(), collateral_out),
+            (ctx.accounts.treasury.to_account_info(), fee),
+        ] {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.market_vault.to_account_info(),
+                        to,
+                        authority: ctx.accounts.global_state.to_account_info(),
+                    },
+                    &[&global_seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+        
+        let accounts = &mut ctx.accounts;
+        require_backed(
+            &accounts.proposal.market,
+            &mut accounts.yes_mint,
+            &mut accounts.no_mint,
+            &mut accounts.market_vault,
+        )?;
+        
+        let proposal = &ctx.accounts.proposal;
+        log_event!(OutcomeSold {
+            proposal_id: proposal.id,
+            trader: ctx.accounts.trader.key(),
+            sell_yes,
+            shares_in,
+            fee,
+            collateral_out,
+            yes_price_bps: proposal.market.yes_price_bps(),
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Pay a resolved market's remaining winning reserve to its liquidity
+    /// provider. What stays in the vault backs the winning shares still
+    /// outstanding. Permissionless.
+    pub fn settle_market(ctx: Context<SettleMarket>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        
+        let yes_won = proposal.yes_won().ok_or(ErrorCode::OutcomeNotEvaluated)?;
+        let amount = proposal.market.settle()?;
+        let winning_supply = if yes_won {
+            ctx.accounts.yes_mint.supply
+        } else {
+            ctx.accounts.no_mint.supply
+        };
+        require!(
+            winning_supply == proposal.market.collateral,
+            ErrorCode::MarketUnbacked
+        );
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.market_vault.to_account_info(),
+                    to: ctx.accounts.provider_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        log_event!(MarketSettled {
+            proposal_id: proposal.id,
+            liquidity_provider: proposal.market.liquidity_provider,
+            amount,
+            outstanding_collateral: proposal.market.collateral,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Apply a queued proposal once its execution_eta has passed. MintParams
+    /// mints into the destination ARU account, BurnParams burns from the
+    /// protocol treasury, RebalanceParams sets the reserve vault's target
+    /// weights and ParamUpdate writes the GlobalState field directly.
+    /// TreasurySpend pays out of the mint's treasury. AssumeAuthority replaces
+    /// the authority, and only once it has been inactive for
+    /// authority_inactivity_period. Each CPI is signed by the global_state
+    /// PDA, which must be the governance key of the target mint state or
+    /// vault.
+    pub fn execute_queued_proposal(ctx: Context<ExecuteQueuedProposal>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        require!(
+            ctx.accounts.proposal.status == ProposalStatus::Queued,
+            ErrorCode::ProposalNotQueued
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= ctx.accounts.proposal.execution_eta,
+            ErrorCode::TimelockNotExpired
+        );
+        require!(
+            !ctx.accounts.global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerActive
+        );
+        ctx.accounts.global_state.check_approval(&ctx.accounts.proposal)?;
+        
+        let params = ctx.accounts.proposal.policy_params.clone();
+        params.validate()?;
+        let in_cooldown = ctx.accounts.global_state
+            .in_breaker_cooldown(Clock::get()?.unix_timestamp);
+        
+        match params {
+            PolicyParams::MintParams { amount, destination } => {
+                let accounts = &ctx.accounts;
+                let global_state = &accounts.global_state;
+                let mint_state = accounts.mint_state.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let aru_mint = accounts.aru_mint.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let destination_account = accounts.treasury_token_account.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let token_program = accounts.token_program.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let ars_token_program = accounts.ars_token_program.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                
+                require!(
+                    aru_mint.key() == global_state.aru_mint
+                        && mint_state.aru_mint == global_state.aru_mint
+                        && mint_state.governance == global_state.key(),
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(
+                    destination_account.key() == destination
+                        && destination_account.mint == global_state.aru_mint,
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(
+                    !in_cooldown || !is_large_supply_change(amount, mint_state.total_supply),
+                    ErrorCode::BreakerCooldownActive
+                );
+                
+                let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+                ars_token::cpi::mint_aru(
+                    CpiContext::new_with_signer(
+                        ars_token_program.to_account_info(),
+                        ars_token::cpi::accounts::MintARU {
+                            mint_state: mint_state.to_account_info(),
+                            aru_mint: aru_mint.to_account_info(),
+                            destination: destination_account.to_account_info(),
+                            governance: global_state.to_account_info(),
+                            token_program: token_program.to_account_info(),
+                        },
+                        &[&global_seeds[..]],
+                    ),
+                    amount,
+                )?;
+            }
+            PolicyParams::BurnParams { amount } => {
+                let accounts = &ctx.accounts;
+                let global_state = &accounts.global_state;
+                let mint_state = accounts.mint_state.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let aru_mint = accounts.aru_mint.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let treasury = accounts.treasury_token_account.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let token_program = accounts.token_program.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let ars_token_program = accounts.ars_token_program.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                
+                require!(
+                    aru_mint.key() == global_state.aru_mint
+                        && mint_state.aru_mint == global_state.aru_mint
+                        && mint_state.governance == global_state.key(),
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(
+                    treasury.owner == global_state.key() && treasury.mint == global_state.aru_mint,
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(
+                    !in_cooldown || !is_large_supply_change(amount, mint_state.total_supply),
+                    ErrorCode::BreakerCooldownActive
+                );
+                
+                let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+                ars_token::cpi::burn_aru(
+                    CpiContext::new_with_signer(
+                        ars_token_program.to_account_info(),
+                        ars_token::cpi::accounts::BurnARU {
+                            mint_state: mint_state.to_account_info(),
+                            aru_mint: aru_mint.to_account_info(),
+                            source: treasury.to_account_info(),
+                            authority: global_state.to_account_info(),
+                            token_program: token_program.to_account_info(),
+                        },
+                        &[&global_seeds[..]],
+                    ),
+                    amount,
+                )?;
+            }
+            PolicyParams::RebalanceParams { weights } => {
+                let accounts = &ctx.accounts;
+                let global_state = &accounts.global_state;
+                let reserve_vault = accounts.reserve_vault.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let ars_reserve_program = accounts.ars_reserve_program.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                
+                require!(
+                    reserve_vault.key() == global_state.reserve_vault
+                        && reserve_vault.governance == global_state.key(),
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(!in_cooldown, ErrorCode::BreakerCooldownActive);
+                
+                let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+                ars_reserve::cpi::rebalance(
+                    CpiContext::new_with_signer(
+                        ars_reserve_program.to_account_info(),
+                        ars_reserve::cpi::accounts::Rebalance {
+                            vault: reserve_vault.to_account_info(),
+                            governance: global_state.to_account_info(),
+                        },
+                        &[&global_seeds[..]],
+                    ),
+                    weights,
+                )?;
+            }
+            PolicyParams::ParamUpdate { field, value } => {
+                ctx.accounts.global_state.set_param(field, value);
+            }
+            PolicyParams::TreasurySpend { mint, amount, destination } => {
+                let accounts = &ctx.accounts;
+                let global_state = &accounts.global_state;
+                let treasury = accounts.protocol_treasury.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let destination_account = accounts.treasury_token_account.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                let token_program = accounts.token_program.as_ref()
+                    .ok_or(ErrorCode::MissingPolicyAccount)?;
+                
+                require!(
+                    treasury.key() == treasury_address(&mint),
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(
+                    destination_account.key() == destination && destination_account.mint == mint,
+                    ErrorCode::InvalidPolicyAccount
+                );
+                require!(!in_cooldown, ErrorCode::BreakerCooldownActive);
+                
+                let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: treasury.to_account_info(),
+                            to: destination_account.to_account_info(),
+                            authority: global_state.to_account_info(),
+                        },
+                        &[&global_seeds[..]],
+                    ),
+                    amount,
+                )?;
+            }
+            PolicyParams::AssumeAuthority { new_authority } => {
+                let current_time = Clock::get()?.unix_timestamp;
+                let global_state = &mut ctx.accounts.global_state;
+                require!(
+                    global_state.authority_inactive(current_time),
+                    ErrorCode::AuthorityStillActive
+                );
+                
+                log_event!(AuthorityAssumed {
+                    old_authority: global_state.authority,
+                    new_authority,
+                    proposal_id: ctx.accounts.proposal.id,
+                    authority_last_active: global_state.authority_last_active,
+                    timestamp: current_time,
+                });
+                
+                global_state.authority = new_authority;
+                global_state.pending_authority = None;
+                global_state.transfer_timelock = 0;
+                global_state.authority_last_active = current_time;
+            }
+            // Execution only authorizes the slash; slash_agent applies it
+            PolicyParams::SlashParams { .. } => {}
+            PolicyParams::OverrideParams { .. } | PolicyParams::EmergencyParams { .. } => {
+                return err!(ErrorCode::InvalidPolicyType);
+            }
+        }
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let action = match params {
+            PolicyParams::MintParams { .. }
+            | PolicyParams::BurnParams { .. }
+            | PolicyParams::RebalanceParams { .. }
+            | PolicyParams::TreasurySpend { .. } => AuditAction::TreasurySpend,
+            PolicyParams::AssumeAuthority { .. } => AuditAction::AuthorityTransfer,
+            _ => AuditAction::ProposalExecuted,
+        };
+        ctx.accounts.audit_log.record(
+            ctx.accounts.executor.key(),
+            action,
+            &(ctx.accounts.proposal.id, &params).try_to_vec()?,
+            current_time,
+        );
+        
+        let metric = read_outcome_metric(
+            ctx.accounts.proposal.outcome_criterion.metric,
+            &ctx.accounts.global_state,
+            &ctx.accounts.ili_oracle,
+            ctx.accounts.reserve_vault.as_ref(),
+            ctx.accounts.aru_price_feed.as_ref(),
+            current_time,
+        )?;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.mark_executed(metric, current_time, ctx.accounts.global_state.epoch_duration)?;
+        
+        log_event!(ProposalExecuted {
+            proposal_id: proposal.id,
+            policy_type: proposal.policy_type,
+            policy_params: params,
+            timestamp: current_time,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Apply an approved emergency proposal as soon as its voting window
+    /// closes. There is no execution timelock; the circuit breaker must still
+    /// be active.
+    pub fn execute_emergency_proposal(ctx: Context<ExecuteEmergencyProposal>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            proposal.policy_type == PolicyType::Emergency,
+            ErrorCode::InvalidPolicyType
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        require!(current_time >= proposal.end_time, ErrorCode::VotingPeriodActive);
+        require!(
+            global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerNotActive
+        );
+        global_state.check_approval(proposal)?;
+        
+        let params = proposal.policy_params.clone();
+        match params {
+            PolicyParams::EmergencyParams { action: EmergencyAction::Pause } => {
+                let paused_until = current_time
+                    .checked_add(EMERGENCY_PAUSE_EXTENSION)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                global_state.circuit_breaker_timelock =
+                    global_state.circuit_breaker_timelock.max(paused_until);
+            }
+            PolicyParams::EmergencyParams { action: EmergencyAction::RollbackParam { field } } => {
+                global_state.rollback_param(field);
+            }
+            _ => return err!(ErrorCode::InvalidPolicyParams),
+        }
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.executor.key(),
+            AuditAction::ProposalExecuted,
+            &(proposal.id, &params).try_to_vec()?,
+            current_time,
+        );
+        
+        let metric = read_outcome_metric(
+            proposal.outcome_criterion.metric,
+            global_state,
+            &ctx.accounts.ili_oracle,
+            ctx.accounts.reserve_vault.as_ref(),
+            ctx.accounts.aru_price_feed.as_ref(),
+            current_time,
+        )?;
+        proposal.mark_executed(metric, current_time, global_state.epoch_duration)?;
+        
+        log_event!(ProposalExecuted {
+            proposal_id: proposal.id,
+            policy_type: proposal.policy_type,
+            policy_params: params,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Apply an unchallenged optimistic proposal. The challenge period stands
+    /// in for the execution timelock, so there is no queue step. The band is
+    /// checked again since the parameter may have moved in the meantime.
+    pub fn execute_optimistic_proposal(ctx: Context<ExecuteOptimisticProposal>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(proposal.optimistic, ErrorCode::InvalidPolicyType);
+        require!(
+            proposal.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassed
+        );
+        
+        let params = proposal.policy_params.clone();
+        let PolicyParams::ParamUpdate { field, value } = params else {
+            return err!(ErrorCode::InvalidPolicyParams);
+        };
+        require!(
+            global_state.within_optimistic_band(field, value),
+            ErrorCode::OutsideOptimisticBand
+        );
+        global_state.set_param(field, value);
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.executor.key(),
+            AuditAction::ProposalExecuted,
+            &(proposal.id, &params).try_to_vec()?,
+            current_time,
+        );
+        
+        let metric = read_outcome_metric(
+            proposal.outcome_criterion.metric,
+            global_state,
+            &ctx.accounts.ili_oracle,
+            ctx.accounts.reserve_vault.as_ref(),
+            ctx.accounts.aru_price_feed.as_ref(),
+            current_time,
+        )?;
+        proposal.mark_executed(metric, current_time, global_state.epoch_duration)?;
+        
+        log_event!(ProposalExecuted {
+            proposal_id: proposal.id,
+            policy_type: proposal.policy_type,
+            policy_params: params,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Apply an emergency ILI value approved by a supermajority OverrideILI
+    /// proposal. The override expires ILI_OVERRIDE_DURATION after voting ends,
+    /// so a stale proposal cannot be executed late.
+    pub fn override_ili(ctx: Context<OverrideILI>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let ili_oracle = &mut ctx.accounts.ili_oracle;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            proposal.policy_type == PolicyType::OverrideILI,
+            ErrorCode::InvalidPolicyType
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        require!(current_time >= proposal.end_time, ErrorCode::VotingPeriodActive);
+        
+        let expires_at = proposal.end_time
+            .checked_add(ILI_OVERRIDE_DURATION)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(current_time < expires_at, ErrorCode::OverrideExpired);
+        
+        ctx.accounts.global_state.check_approval(proposal)?;
+        
+        let ili_value = match proposal.policy_params {
+            PolicyParams::OverrideParams { ili_value } if ili_value > 0 => ili_value,
+            _ => return err!(ErrorCode::InvalidPolicyParams),
+        };
+        
+        // Scored against the ILI the override replaces
+        let metric = read_outcome_metric(
+            proposal.outcome_criterion.metric,
+            &ctx.accounts.global_state,
+            ili_oracle,
+            ctx.accounts.reserve_vault.as_ref(),
+            ctx.accounts.aru_price_feed.as_ref(),
+            current_time,
+        )?;
+        proposal.mark_executed(metric, current_time, ctx.accounts.global_state.epoch_duration)?;
+        ili_oracle.override_ili = ili_value;
+        ili_oracle.override_expires = expires_at;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.executor.key(),
+            AuditAction::IliOverride,
+            &(proposal.id, ili_value).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(ILIOverridden {
+            proposal_id: proposal.id,
+            ili_value,
+            expires_at,
+        });
+        
+        Ok(())
+    }
+
+    pub fn trigger_circuit_breaker(
+        ctx: Context<TriggerCircuitBreaker>,
+        reason: CircuitBreakerReason,
+        details_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let agent_registry = &ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // High-tier agents may trigger outright; others need the reputation
+        let registry = ars_common::read_param_table_or_default(&ctx.accounts.parameter_registry, ctx.program_id)?;
+        require!(
+            agent_registry.agent_tier as u8 >= registry.tier(ParamKey::BreakerTriggerMinTier)?
+                || agent_registry.reputation_score
+                    >= registry.reputation(ParamKey::BreakerTriggerMinReputation)?,
+            ErrorCode::InsufficientReputation
+        );
+        
+        global_state.circuit_breaker_active = true;
+        global_state.circuit_breaker_timelock = current_time
+            .checked_add(CIRCUIT_BREAKER_TIMELOCK)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.agent.key(),
+            AuditAction::BreakerTriggered,
+            &(reason, details_hash).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(CircuitBreakerTriggered {
+            agent: agent_registry.agent_pubkey,
+            reason,
+            details_hash,
+            timelock_expires: global_state.circuit_breaker_timelock,
+        });
+        
+        Ok(())
+    }
+
+    /// Permissionless crank run after each ILI consensus round. Compares the
+    /// round's raw median against the smoothed ILI; once the deviation has
+    /// exceeded anomaly_deviation_bps for anomaly_rounds checked rounds in a
+    /// row, the circuit breaker trips with reason OracleAnomaly, as if a
+    /// reputable agent had triggered it.
+    pub fn check_price_anomaly(ctx: Context<CheckPriceAnomaly>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let ili_oracle = &ctx.accounts.ili_oracle;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            ili_oracle.last_update > global_state.anomaly_checked_round,
+            ErrorCode::AnomalyRoundChecked
+        );
+        global_state.anomaly_checked_round = ili_oracle.last_update;
+        
+        let deviation_bps = ili_oracle.deviation_bps().unwrap_or(0);
+        if global_state.anomaly_deviation_bps == 0
+            || deviation_bps <= global_state.anomaly_deviation_bps as u64
+        {
+            global_state.anomaly_streak = 0;
+            return Ok(());
+        }
+        
+        global_state.anomaly_streak = global_state.anomaly_streak.saturating_add(1);
+        log_event!(PriceAnomalyDetected {
+            current_ili: ili_oracle.current_ili,
+            smoothed_ili: ili_oracle.smoothed_ili,
+            deviation_bps,
+            streak: global_state.anomaly_streak,
+            round: ili_oracle.last_update,
+        });
+        
+        if global_state.anomaly_streak >= global_state.anomaly_rounds
+            && !global_state.circuit_breaker_active
+        {
+            global_state.circuit_breaker_active = true;
+            global_state.circuit_breaker_timelock = current_time
+                .checked_add(CIRCUIT_BREAKER_TIMELOCK)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            global_state.anomaly_streak = 0;
+            
+            ctx.accounts.audit_log.record(
+                ctx.accounts.caller.key(),
+                AuditAction::BreakerTriggered,
+                &(CircuitBreakerReason::OracleAnomaly, ili_oracle.last_update).try_to_vec()?,
+                current_time,
+            );
+            
+            log_event!(CircuitBreakerTriggered {
+                agent: ctx.accounts.caller.key(),
+                reason: CircuitBreakerReason::OracleAnomaly,
+                details_hash: None,
+                timelock_expires: global_state.circuit_breaker_timelock,
+            });
+        }
+        
+        Ok(())
+    }
+
+    pub fn set_anomaly_detector(
+        ctx: Context<SetAnomalyDetector>,
+        anomaly_deviation_bps: u16,
+        anomaly_rounds: u8,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            anomaly_deviation_bps <= 10_000 && (1..=MAX_ANOMALY_ROUNDS).contains(&anomaly_rounds),
+            ErrorCode::InvalidAnomalyConfig
+        );
+        
+        global_state.anomaly_deviation_bps = anomaly_deviation_bps;
+        global_state.anomaly_rounds = anomaly_rounds;
+        global_state.anomaly_streak = 0;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(anomaly_deviation_bps, anomaly_rounds).try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        Ok(())
+    }
+
+    /// Clear the circuit breaker once its timelock has expired and start the
+    /// post-breaker cooldown: ars-token mint/burn caps and ars-reserve
+    /// withdrawal limits are halved, and large mint, burn and rebalance
+    /// proposals cannot execute, until breaker_cooldown_ends.
+    pub fn deactivate_circuit_breaker(ctx: Context<DeactivateCircuitBreaker>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        ars_common::lock(global_state, ctx.program_id)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerNotActive
+        );
+        require!(
+            current_time >= global_state.circuit_breaker_timelock,
+            ErrorCode::TimelockNotExpired
+        );
+        
+        let cooldown_ends = current_time
+            .checked_add(global_state.breaker_cooldown_period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        global_state.circuit_breaker_active = false;
+        global_state.breaker_cooldown_ends = cooldown_ends;
+        
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        ars_token::cpi::set_breaker_cooldown(
+            CpiContext::new_with_signer(
+                ctx.accounts.ars_token_program.to_account_info(),
+                ars_token::cpi::accounts::SetBreakerCooldown {
+                    mint_state: ctx.accounts.mint_state.to_account_info(),
+                    governance: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            cooldown_ends,
+        )?;
+        ars_reserve::cpi::set_breaker_cooldown(
+            CpiContext::new_with_signer(
+                ctx.accounts.ars_reserve_program.to_account_info(),
+                ars_reserve::cpi::accounts::SetBreakerCooldown {
+                    vault: ctx.accounts.reserve_vault.to_account_info(),
+                    governance: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            cooldown_ends,
+        )?;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::BreakerDeactivated,
+            &cooldown_ends.try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(CircuitBreakerDeactivated {
+            authority: ctx.accounts.authority.key(),
+            cooldown_ends,
+            timestamp: current_time,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    pub fn set_breaker_cooldown(
+        ctx: Context<SetBreakerCooldown>,
+        breaker_cooldown_period: i64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            (0..=MAX_BREAKER_COOLDOWN).contains(&breaker_cooldown_period),
+            ErrorCode::InvalidBreakerCooldown
+        );
+        
+        global_state.breaker_cooldown_period = breaker_cooldown_period;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &breaker_cooldown_period.try_to_vec()?,
+            Clock::get()?.unix_timestamp,
+        );
+        
+        Ok(())
+    }
+
+    /// Register (or, with None, remove) the Realms governance allowed to
+    /// drive parameter updates and reserve rebalances through the adapter
+    pub fn set_realms_governance(
+        ctx: Context<SetRealmsGovernance>,
+        realms_program: Pubkey,
+        governance: Option<Pubkey>,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.authorize(ctx.accounts.authority.key())?;
+        
+        global_state.realms_program = realms_program;
+        global_state.realms_governance = governance;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(realms_program, governance).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(RealmsGovernanceUpdated {
+            realms_program,
+            governance,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Update a protocol parameter from an executed Realms proposal, with the
+    /// same range checks and rollback tracking as a ParamUpdate proposal
+    pub fn realms_update_parameter(
+        ctx: Context<RealmsUpdateParameter>,
+        field: ProtocolParam,
+        value: u64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let governance = ctx.accounts.governance.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.verify_realms_governance(&ctx.accounts.governance)?;
+        require!(
+            !global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerActive
+        );
+        PolicyParams::ParamUpdate { field, value }.validate()?;
+        
+        global_state.set_param(field, value);
+        
+        ctx.accounts.audit_log.record(
+            governance,
+            AuditAction::ParamChange,
+            &(field, value).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(RealmsParamUpdated {
+            governance,
+            field,
+            value,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Rebalance the reserve vault from an executed Realms proposal. Blocked
+    /// while the circuit breaker or its cooldown is active, like a
+    /// RebalanceVault proposal.
+    pub fn realms_rebalance(
+        ctx: Context<RealmsRebalance>,
+        weights: [u16; RESERVE_ASSET_COUNT],
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let global_state = &ctx.accounts.global_state;
+        let governance = ctx.accounts.governance.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.verify_realms_governance(&ctx.accounts.governance)?;
+        require!(
+            !global_state.circuit_breaker_active,
+            ErrorCode::CircuitBreakerActive
+        );
+        require!(
+            !global_state.in_breaker_cooldown(current_time),
+            ErrorCode::BreakerCooldownActive
+        );
+        PolicyParams::RebalanceParams { weights }.validate()?;
+        
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        ars_reserve::cpi::rebalance(
+            CpiContext::new_with_signer(
+                ctx.accounts.ars_reserve_program.to_account_info(),
+                ars_reserve::cpi::accounts::Rebalance {
+                    vault: ctx.accounts.reserve_vault.to_account_info(),
+                    governance: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            weights,
+        )?;
+        
+        ctx.accounts.audit_log.record(
+            governance,
+            AuditAction::TreasurySpend,
+            &weights.try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(RealmsRebalanced {
+            governance,
+            weights,
+            timestamp: current_time,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Publish or replace the bug bounty terms
+    pub fn set_bug_bounty(
+        ctx: Context<SetBugBounty>,
+        scope_hash: [u8; 32],
+        reward_tiers: [u64; BOUNTY_TIER_COUNT],
+        contact: [u8; BOUNTY_CONTACT_LEN],
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        ctx.accounts.global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            reward_tiers.windows(2).all(|pair| pair[0] >= pair[1]),
+            ErrorCode::InvalidBountyTiers
+        );
+        
+        let bug_bounty = &mut ctx.accounts.bug_bounty;
+        bug_bounty.scope_hash = scope_hash;
+        bug_bounty.reward_tiers = reward_tiers;
+        bug_bounty.contact = contact;
+        bug_bounty.updated_at = current_time;
+        bug_bounty.bump = ctx.bumps.bug_bounty;
+        
+        ctx.accounts.audit_log.record(
+            ctx.accounts.authority.key(),
+            AuditAction::ParamChange,
+            &(scope_hash, reward_tiers, contact).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(BugBountyUpdated {
+            scope_hash,
+            reward_tiers,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Timestamp the hash of an undisclosed finding. Anyone may commit; the
+    /// PDA is keyed by the commitment, so the earliest reporter keeps it.
+    pub fn report_commitment(
+        ctx: Context<CommitReport>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let bug_bounty = &mut ctx.accounts.bug_bounty;
+        
+        let report = &mut ctx.accounts.report;
+        report.reporter = ctx.accounts.reporter.key();
+        report.commitment = commitment;
+        report.scope_hash = bug_bounty.scope_hash;
+        report.committed_at = clock.unix_timestamp;
+        report.slot = clock.slot;
+        report.bump = ctx.bumps.report;
+        
+        bug_bounty.report_count = bug_bounty.report_count.saturating_add(1);
+        
+        log_event!(BugReportCommitted {
+            reporter: report.reporter,
+            commitment,
+            scope_hash: report.scope_hash,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Propose a slash of an agent's stake. Above the SlashConfirmationThreshold
+    /// registry parameter the authority also needs a guardian co-signer or an
+    /// executed SlashAgent proposal for exactly this agent and amount. The
+    /// amount is held back from withdrawal and anyone can execute the slash
+    /// with execute_slash once the SlashAppealWindow has passed, unless the
+    /// agent appeals first.
+    /// Every slash is recorded in a SlashRecord.
+    pub fn slash_agent(
+        ctx: Context<SlashAgent>,
+        slash_amount: u64,
+        reason: SlashReason,
+        details_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let authority = ctx.accounts.authority.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.authorize(authority)?;
+        require!(
+            slash_amount <= agent_registry.stake_amount.saturating_sub(agent_registry.pending_slash),
+            ErrorCode::SlashAmountTooHigh
+        );
+        
+        let registry = ars_common::read_param_table_or_default(&ctx.accounts.parameter_registry, ctx.program_id)?;
+        let confirmation = if slash_amount <= registry.amount(ParamKey::SlashConfirmationThreshold)? {
+            SlashConfirmation::None
+        } else if let Some(guardian) = ctx.accounts.guardian.as_ref() {
+            require!(
+                !global_state.authority_is_multisig,
+                ErrorCode::SingleKeyPathDisabled
+            );
+            require!(
+                guardian.key() != authority && global_state.is_guardian(&guardian.key()),
+                ErrorCode::SlashNotConfirmed
+            );
+            SlashConfirmation::Guardian { guardian: guardian.key() }
+        } else if let Some(proposal) = ctx.accounts.proposal.as_mut() {
+            let approved = PolicyParams::SlashParams {
+                agent: agent_registry.agent_pubkey,
+                amount: slash_amount,
+            };
+            require!(
+                proposal.status == ProposalStatus::Executed
+                    && !proposal.slash_applied
+                    && proposal.policy_params == approved,
+                ErrorCode::SlashNotConfirmed
+            );
+            proposal.slash_applied = true;
+            SlashConfirmation::Proposal { proposal_id: proposal.id }
+        } else {
+            return err!(ErrorCode::SlashNotConfirmed);
+        };
+        
+        let slash_index = agent_registry.slash_count;
+        agent_registry.pending_slash = agent_registry.pending_slash
+            .checked_add(slash_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.slash_count = agent_registry.slash_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let executable_at = current_time
+            .checked_add(registry.seconds(ParamKey::SlashAppealWindow)?)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let slash_record = &mut ctx.accounts.slash_record;
+        slash_record.agent = agent_registry.agent_pubkey;
+        slash_record.index = slash_index;
+        slash_record.amount = slash_amount;
+        slash_record.reason = reason;
+        slash_record.details_hash = details_hash;
+        slash_record.authority = authority;
+        slash_record.confirmation = confirmation;
+        slash_record.status = SlashStatus::Proposed;
+        slash_record.proposed_at = current_time;
+        slash_record.executable_at = executable_at;
+        slash_record.appealed_at = 0;
+        slash_record.slashed_at = 0;
+        slash_record.bump = ctx.bumps.slash_record;
+        
+        ctx.accounts.audit_log.record(
+            authority,
+            AuditAction::Slash,
+            &(agent_registry.agent_pubkey, slash_amount, reason, details_hash, confirmation).try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(SlashProposed {
+            agent: agent_registry.agent_pubkey,
+            slash_index,
+            slash_amount,
+            reason,
+            details_hash,
+            executable_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Appeal a proposed slash within its appeal window. The slash is frozen
+    /// until governance votes on a SlashAgent proposal for the same agent
+    /// and amount opened after the appeal: execute_slash applies it if that
+    /// proposal executes, dismiss_slash drops it if the proposal is rejected.
+    pub fn appeal_slash(ctx: Context<AppealSlash>) -> Result<()> {
+        let slash_record = &mut ctx.accounts.slash_record;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(slash_record.status == SlashStatus::Proposed, ErrorCode::SlashNotPending);
+        require!(
+            current_time < slash_record.executable_at,
+            ErrorCode::SlashAppealWindowClosed
+        );
+        
+        slash_record.status = SlashStatus::Appealed;
+        slash_record.appealed_at = current_time;
+        
+        log_event!(SlashAppealed {
+            agent: slash_record.agent,
+            slash_index: slash_record.index,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Move a proposed slash's stake to the treasury. Permissionless once
+    /// the appeal window has passed; an appealed slash also needs the
+    /// executed SlashAgent proposal that upheld it.
+    pub fn execute_slash(ctx: Context<ExecuteSlash>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let global_state = &ctx.accounts.global_state;
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let slash_record = &mut ctx.accounts.slash_record;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        match slash_record.status {
+            SlashStatus::Proposed => require!(
+                current_time >= slash_record.executable_at,
+                ErrorCode::SlashAppealWindowActive
+            ),
+            SlashStatus::Appealed => {
+                let proposal = ctx.accounts.proposal
+                    .as_mut()
+                    .ok_or(ErrorCode::SlashAppealUnresolved)?;
+                require!(
+                    slash_record.is_appeal_vote(proposal)
+                        && proposal.status == ProposalStatus::Executed
+                        && !proposal.slash_applied,
+                    ErrorCode::SlashAppealUnresolved
+                );
+                proposal.slash_applied = true;
+            }
+            _ => return err!(ErrorCode::SlashNotPending),
+        }
+        
+        // Vote settlement may have taken stake since the slash was proposed
+        let slash_amount = slash_record.amount.min(agent_registry.stake_amount);
+        agent_registry.pending_slash = agent_registry.pending_slash
+            .saturating_sub(slash_record.amount);
+        agent_registry.stake_amount -= slash_amount;
+        agent_registry.slashed_amount = agent_registry.slashed_amount
+            .checked_add(slash_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        agent_registry.reputation_score = agent_registry.reputation_score
+            .checked_sub(50)
+            .unwrap_or(-1000);
+        agent_registry.refresh_tier();
+        if agent_registry.stake_amount < global_state.min_agent_stake {
+            agent_registry.is_active = false;
+        }
+        
+        slash_record.status = SlashStatus::Executed;
+        slash_record.slashed_at = current_time;
+        
+        // The slashed ARU leaves the stake escrow for the insurance fund
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.insurance_fund.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            slash_amount,
+        )?;
+        
+        log_event!(AgentSlashed {
+            agent: agent_registry.agent_pubkey,
+            slash_index: slash_record.index,
+            slash_amount,
+            reason: slash_record.reason,
+            details_hash: slash_record.details_hash,
+            new_reputation: agent_registry.reputation_score,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    /// Drop an appealed slash that governance rejected, releasing the held
+    /// stake. Permissionless.
+    pub fn dismiss_slash(ctx: Context<DismissSlash>) -> Result<()> {
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let slash_record = &mut ctx.accounts.slash_record;
+        let proposal = &ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            slash_record.is_appeal_vote(proposal)
+                && matches!(proposal.status, ProposalStatus::Rejected | ProposalStatus::Vetoed),
+            ErrorCode::SlashAppealUnresolved
+        );
+        
+        agent_registry.pending_slash = agent_registry.pending_slash
+            .saturating_sub(slash_record.amount);
+        slash_record.status = SlashStatus::Dismissed;
+        
+        log_event!(SlashDismissed {
+            agent: slash_record.agent,
+            slash_index: slash_record.index,
+            proposal_id: proposal.id,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Create the insurance fund that executed slashes pay into. Anyone may
+    /// pay for it; only global_state can move its funds.
+    pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+        log_event!(InsuranceFundInitialized {
+            mint: ctx.accounts.aru_mint.key(),
+            insurance_fund: ctx.accounts.insurance_fund.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Move `amount` of slashed ARU from the insurance fund into the reserve
+    /// vault from an executed Realms proposal. Only allowed while the vault's
+    /// VHR is below vhr_threshold.
+    pub fn cover_shortfall(ctx: Context<CoverShortfall>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+        
+        let global_state = &ctx.accounts.global_state;
+        let governance = ctx.accounts.governance.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        global_state.verify_realms_governance(&ctx.accounts.governance)?;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            ctx.accounts.reserve_vault.vhr < global_state.vhr_threshold,
+            ErrorCode::NoReserveShortfall
+        );
+        require!(
+            amount <= ctx.accounts.insurance_fund.amount,
+            ErrorCode::InsufficientInsuranceFund
+        );
+        
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        ars_reserve::cpi::cover_shortfall(
+            CpiContext::new_with_signer(
+                ctx.accounts.ars_reserve_program.to_account_info(),
+                ars_reserve::cpi::accounts::CoverShortfall {
+                    vault: ctx.accounts.reserve_vault.to_account_info(),
+                    vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                    asset_config: ctx.accounts.asset_config.to_account_info(),
+                    source_token_account: ctx.accounts.insurance_fund.to_account_info(),
+                    governance: global_state.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+                &[&global_seeds[..]],
+            ),
+            amount,
+        )?;
+        
+        ctx.accounts.reserve_vault.reload()?;
+        
+        ctx.accounts.audit_log.record(
+            governance,
+            AuditAction::TreasurySpend,
+            &amount.try_to_vec()?,
+            current_time,
+        );
+        
+        log_event!(ShortfallCovered {
+            governance,
+            amount,
+            vhr: ctx.accounts.reserve_vault.vhr,
+            timestamp: current_time,
+        });
+        
+        ars_common::unlock(&mut ctx.accounts.global_state);
+        
+        Ok(())
+    }
+
+    pub fn initialize_randomness(ctx: Context<InitializeRandomness>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.authorize(ctx.accounts.authority.key())?;
+
+        let randomness_state = &mut ctx.accounts.randomness_state;
+        randomness_state.authority = global_state.authority;
+        randomness_state.randomness_account = Pubkey::default();
+        randomness_state.request_slot = 0;
+        randomness_state.request_nonce = 0;
+        randomness_state.seed = [0u8; 32];
+        randomness_state.seeded_at_slot = 0;
+        randomness_state.is_fallback = false;
+        randomness_state.pending = false;
+        randomness_state.bump = ctx.bumps.randomness_state;
+        randomness_state.fallback_slot = 0;
+        randomness_state.candidates_hash = [0u8; 32];
+        randomness_state.committee_size = 0;
+        randomness_state.committee_drawn = false;
+
+        Ok(())
+    }
+
+    /// Commit a freshly seeded Switchboard randomness account, together with
+    /// the committee candidates (candidates_hash of their agent keys in
+    /// ascending order) and how many of them draw_committee should select.
+    /// A pending request can only be replaced once its fallback slot has aged
+    /// out of SlotHashes.
+    pub fn request_randomness(
+        ctx: Context<RequestRandomness>,
+        candidates_hash: [u8; 32],
+        committee_size: u8,
+    ) -> Result<()> {
+        let randomness_state = &mut ctx.accounts.randomness_state;
+        let clock = Clock::get()?;
+
+        require!(
+            ctx.accounts.authority.key() == randomness_state.authority,
+            ErrorCode::Unauthorized
+        );
+        require!(
+            !randomness_state.pending
+                || clock.slot > randomness_state.fallback_slot.saturating_add(SLOT_HASHES_MAX_ENTRIES),
+            ErrorCode::RandomnessAlreadyPending
+        );
+        require!(
+            committee_size > 0 && committee_size <= MAX_COMMITTEE_SIZE,
+            ErrorCode::InvalidCommitteeSize
+        );
+
+        // An account seeded earlier may already have revealed its value
+        require!(
+            switchboard_seed_slot(&ctx.accounts.randomness_account)? == clock.slot.saturating_sub(1),
+            ErrorCode::RandomnessNotFresh
+        );
+
+        randomness_state.randomness_account = ctx.accounts.randomness_account.key();
+        randomness_state.request_slot = clock.slot;
+        randomness_state.fallback_slot = clock.slot
+            .checked_add(RANDOMNESS_FALLBACK_SLOTS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        randomness_state.request_nonce = randomness_state.request_nonce
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        randomness_state.candidates_hash = candidates_hash;
+        randomness_state.committee_size = committee_size;
+        randomness_state.committee_drawn = false;
+        randomness_state.pending = true;
+
+        log_event!(RandomnessRequested {
+            randomness_account: randomness_state.randomness_account,
+            request_nonce: randomness_state.request_nonce,
+            request_slot: clock.slot,
+            fallback_slot: randomness_state.fallback_slot,
+            candidates_hash,
+            committee_size,
+        });
+
+        Ok(())
+    }
+
+    pub fn consume_randomness(ctx: Context<ConsumeRandomness>) -> Result<()> {
+        let randomness_state = &mut ctx.accounts.randomness_state;
+        let clock = Clock::get()?;
+
+        require!(randomness_state.pending, ErrorCode::RandomnessNotRequested);
+        require!(
+            ctx.accounts.randomness_account.key() == randomness_state.randomness_account,
+            ErrorCode::InvalidRandomnessAccount
+        );
+
+        let (seed, is_fallback) = match read_switchboard_value(
+            &ctx.accounts.randomness_account,
+            &clock,
+        ) {
+            Some(value) => (value, false),
+            None => {
+                require!(
+                    clock.slot > randomness_state.fallback_slot,
+                    ErrorCode::RandomnessUnavailable
+                );
+
+                (
+                    slot_hashes_seed(
+                        &ctx.accounts.slot_hashes,
+                        randomness_state.fallback_slot,
+                        randomness_state.request_nonce,
+                    )?,
+                    true,
+                )
+            }
+        };
+
+        randomness_state.seed = seed;
+        randomness_state.seeded_at_slot = clock.slot;
+        randomness_state.is_fallback = is_fallback;
+        randomness_state.pending = false;
+
+        log_event!(RandomnessConsumed {
+            request_nonce: randomness_state.request_nonce,
+            seed,
+            is_fallback,
+            slot: clock.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Select the committee from the consumed seed. Anyone can call this once
+    /// per seed, passing the AgentRegistry of every committed candidate in
+    /// ascending agent key order as remaining accounts. The lead is the
+    /// member with the highest reputation, ties broken by the seed.
+    pub fn draw_committee<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DrawCommittee<'info>>,
+    ) -> Result<()> {
+        let randomness_state = &mut ctx.accounts.randomness_state;
+
+        require!(
+            !randomness_state.pending && randomness_state.seeded_at_slot != 0,
+            ErrorCode::RandomnessNotRequested
+        );
+        require!(!randomness_state.committee_drawn, ErrorCode::CommitteeAlreadyDrawn);
+
+        let mut candidates: Vec<(Pubkey, i32)> = Vec::with_capacity(ctx.remaining_accounts.len());
+        for account in ctx.remaining_accounts.iter() {
+            let agent_registry = Account::<AgentRegistry>::try_from(account)?;
+            if let Some((previous, _)) = candidates.last() {
+                require!(
+                    *previous < agent_registry.agent_pubkey,
+                    ErrorCode::CommitteeCandidatesMismatch
+                );
+            }
+            candidates.push((agent_registry.agent_pubkey, agent_registry.reputation_score));
+        }
+
+        let keys: Vec<Pubkey> = candidates.iter().map(|(key, _)| *key).collect();
+        require!(
+            candidates_hash(&keys) == randomness_state.candidates_hash,
+            ErrorCode::CommitteeCandidatesMismatch
+        );
+
+        let seed = randomness_state.seed;
+        let selected = select_committee(
+            &seed,
+            candidates.len(),
+            randomness_state.committee_size as usize,
+        );
+        let mut members = Vec::with_capacity(selected.len());
+        let mut lead: Option<(Pubkey, i32)> = None;
+        for index in selected {
+            let (key, reputation) = candidates[index];
+            members.push(key);
+            lead = match lead {
+                Some((lead_key, lead_reputation))
+                    if lead_reputation > reputation
+                        || (lead_reputation == reputation && tie_break(&seed, &lead_key, &key)) =>
+                {
+                    Some((lead_key, lead_reputation))
+                }
+                _ => Some((key, reputation)),
+            };
+        }
+        let (lead, _) = lead.ok_or(ErrorCode::CommitteeCandidatesMismatch)?;
+
+        randomness_state.committee_drawn = true;
+
+        log_event!(CommitteeSelected {
+            request_nonce: randomness_state.request_nonce,
+            members,
+            lead,
+            is_fallback: randomness_state.is_fallback,
+        });
+
+        Ok(())
+    }
+
+    /// Point the ARU price feed at a Raydium CP-Swap pool pairing ARU with
+    /// `quote_mint`. Re-registering replaces the pool and clears the price.
+    pub fn register_aru_price_feed(
+        ctx: Context<RegisterAruPriceFeed>,
+        twap_window: i64,
+    ) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.authorize(ctx.accounts.authority.key())?;
+        require!(
+            (MIN_TWAP_WINDOW..=MAX_TWAP_WINDOW).contains(&twap_window),
+            ErrorCode::InvalidTwapWindow
+        );
+
+        let pool = read_cpmm_pool(&ctx.accounts.pool)?;
+        let aru_mint = ctx.accounts.aru_mint.key();
+        let quote_mint = ctx.accounts.quote_mint.key();
+        let aru_is_token_0 = match (pool.token_0_mint, pool.token_1_mint) {
+            (token_0, token_1) if token_0 == aru_mint && token_1 == quote_mint => true,
+            (token_0, token_1) if token_0 == quote_mint && token_1 == aru_mint => false,
+            _ => return err!(ErrorCode::InvalidPriceSource),
+        };
+        require_keys_eq!(
+            pool.observation_key,
+            ctx.accounts.observation_state.key(),
+            ErrorCode::InvalidPriceSource
+        );
+        read_observations(&ctx.accounts.observation_state, &ctx.accounts.pool.key())?;
+
+        let aru_price_feed = &mut ctx.accounts.aru_price_feed;
+        aru_price_feed.pool = ctx.accounts.pool.key();
+        aru_price_feed.observation_state = pool.observation_key;
+        aru_price_feed.quote_mint = quote_mint;
+        aru_price_feed.aru_is_token_0 = aru_is_token_0;
+        aru_price_feed.aru_decimals = ctx.accounts.aru_mint.decimals;
+        aru_price_feed.quote_decimals = ctx.accounts.quote_mint.decimals;
+        aru_price_feed.twap_window = twap_window;
+        aru_price_feed.price = 0;
+        aru_price_feed.price_span = 0;
+        aru_price_feed.updated_at = 0;
+        aru_price_feed.bump = ctx.bumps.aru_price_feed;
+
+        log_event!(AruPriceFeedRegistered {
+            pool: aru_price_feed.pool,
+            quote_mint,
+            twap_window,
+        });
+
+        Ok(())
+    }
+
+    /// Publish the ARU TWAP over the feed's window from the pool's
+    /// observation ring. Permissionless.
+    pub fn update_aru_price(ctx: Context<UpdateAruPrice>) -> Result<()> {
+        let aru_price_feed = &mut ctx.accounts.aru_price_feed;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let (observations, newest) =
+            read_observations(&ctx.accounts.observation_state, &aru_price_feed.pool)?;
+        let (price_x32, span) = twap_x32(
+            &observations,
+            newest,
+            aru_price_feed.twap_window,
+            aru_price_feed.aru_is_token_0,
+        )?;
+        let price = scale_price_x32(
+            price_x32,
+            aru_price_feed.aru_decimals,
+            aru_price_feed.quote_decimals,
+        )
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        aru_price_feed.price = price;
+        aru_price_feed.price_span = span;
+        aru_price_feed.updated_at = current_time;
+
+        log_event!(AruPriceUpdated {
+            price,
+            span,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn enable_compressed_votes(
+        ctx: Context<EnableCompressedVotes>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(
+            ctx.accounts.proposer.key() == proposal.proposer,
+            ErrorCode::Unauthorized
+        );
+        require!(proposal.vote_tree == Pubkey::default(), ErrorCode::VoteTreeAlreadySet);
+        require!(
+            proposal.yes_stake == 0 && proposal.no_stake == 0,
+            ErrorCode::VoteTreeAlreadySet
+        );
+
+        let id_bytes = proposal.id.to_le_bytes();
+        let proposal_seeds = &[b"proposal".as_ref(), id_bytes.as_ref(), &[proposal.bump]];
+        let signer = &[&proposal_seeds[..]];
+
+        spl_account_compression::cpi::init_empty_merkle_tree(
+            CpiContext::new_with_signer(
+                ctx.accounts.compression_program.to_account_info(),
+                spl_account_compression::cpi::accounts::Initialize {
+                    merkle_tree: ctx.accounts.vote_tree.to_account_info(),
+                    authority: proposal.to_account_info(),
+                    noop: ctx.accounts.noop_program.to_account_info(),
+                },
+                signer,
+            ),
+            max_depth,
+            max_buffer_size,
+        )?;
+
+        proposal.vote_tree = ctx.accounts.vote_tree.key();
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Vote as in vote_on_proposal, without delegated power, and also append
+    /// the vote as a leaf of the proposal's vote tree. The vote still gets
+    /// its VoteRecord, so an agent votes once per proposal across both paths
+    /// and the vote settles like any other.
+    pub fn vote_on_proposal_compressed(
+        ctx: Context<VoteOnProposalCompressed>,
+        vote_yes: bool,
+        stake_amount: u64,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        let agent_registry = &mut ctx.accounts.agent_registry;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        proposal.require_votable(agent_registry, stake_amount, current_time)?;
+        agent_registry.lock_votes_until(proposal.end_time);
+        require!(proposal.vote_tree != Pubkey::default(), ErrorCode::VoteTreeNotSet);
+        require!(
+            ctx.accounts.vote_tree.key() == proposal.vote_tree,
+            ErrorCode::InvalidVoteTree
+        );
+
+        let global_state = &ctx.accounts.global_state;
+        let accuracy = AgentStats::accuracy_of(&ctx.accounts.agent_stats, global_state.accuracy_window)?;
+        let voting_power = global_state
+            .voting_power(stake_amount, agent_registry.reputation_score, accuracy);
+        let leaf = compressed_vote_leaf(
+            proposal.id,
+            &agent_registry.agent_pubkey,
+            vote_yes,
+            stake_amount,
+            voting_power,
+        );
+
+        let id_bytes = proposal.id.to_le_bytes();
+        let proposal_seeds = &[b"proposal".as_ref(), id_bytes.as_ref(), &[proposal.bump]];
+        let signer = &[&proposal_seeds[..]];
+
+        spl_account_compression::cpi::append(
+            CpiContext::new_with_signer(
+                ctx.accounts.compression_program.to_account_info(),
+                spl_account_compression::cpi::accounts::Modify {
+                    merkle_tree: ctx.accounts.vote_tree.to_account_info(),
+                    authority: proposal.to_account_info(),
+                    noop: ctx.accounts.noop_program.to_account_info(),
+                },
+                signer,
+            ),
+            leaf,
+        )?;
+
+        let leaf_index = proposal.compressed_votes;
+        proposal.compressed_votes = leaf_index
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.vote_yes = vote_yes;
+        vote_record.stake_amount = stake_amount;
+        vote_record.delegated_stake = 0;
+        vote_record.voting_power = voting_power;
+        vote_record.voted_at = current_time;
+        vote_record.claimed = false;
+        vote_record.slashed = 0;
+        vote_record.participation_claimed = false;
+        vote_record.simulation_hash = [0; 32];
+        vote_record.bump = ctx.bumps.vote_record;
+
+        proposal.update_conviction(current_time);
+        proposal.add_vote(vote_yes, stake_amount, voting_power)?;
+
+        log_event!(CompressedVoteCast {
+            proposal_id: proposal.id,
+            agent: agent_registry.agent_pubkey,
+            vote_yes,
+            stake_amount,
+            voting_power,
+            leaf,
+            leaf_index,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Prove a compressed vote exists; proof nodes are passed as remaining accounts
+    pub fn verify_compressed_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyCompressedVote<'info>>,
+        root: [u8; 32],
+        vote_yes: bool,
+        stake_amount: u64,
+        voting_power: u64,
+        leaf_index: u32,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+
+        require!(
+            ctx.accounts.vote_tree.key() == proposal.vote_tree,
+            ErrorCode::InvalidVoteTree
+        );
+
+        let leaf = compressed_vote_leaf(
+            proposal.id,
+            &ctx.accounts.voter.key(),
+            vote_yes,
+            stake_amount,
+            voting_power,
+        );
+
+        spl_account_compression::cpi::verify_leaf(
+            CpiContext::new(
+                ctx.accounts.compression_program.to_account_info(),
+                spl_account_compression::cpi::accounts::VerifyLeaf {
+                    merkle_tree: ctx.accounts.vote_tree.to_account_info(),
+                },
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            root,
+            leaf,
+            leaf_index,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn post_reward_root(
+        ctx: Context<PostRewardRoot>,
+        epoch: u64,
+        root: [u8; 32],
+        total_amount: u64,
+        reward_per_update: u64,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(total_amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            epoch < ctx.accounts.global_state.epoch_at(current_time),
+            ErrorCode::EpochNotComplete
+        );
+
+        // Any keeper may post, backed by a bond that a challenger can claim
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.poster.to_account_info(),
+                    to: ctx.accounts.reward_root.to_account_info(),
+                },
+            ),
+            REWARD_ROOT_BOND_LAMPORTS,
+        )?;
+
+        let reward_root = &mut ctx.accounts.reward_root;
+        reward_root.epoch = epoch;
+        reward_root.root = root;
+        reward_root.total_amount = total_amount;
+        reward_root.claimed_amount = 0;
+        reward_root.poster = ctx.accounts.poster.key();
+        reward_root.posted_at = current_time;
+        reward_root.challenge_ends = current_time
+            .checked_add(REWARD_CHALLENGE_WINDOW)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        reward_root.reward_per_update = reward_per_update;
+        reward_root.bond = REWARD_ROOT_BOND_LAMPORTS;
+        reward_root.voided = false;
+        reward_root.bond_released = false;
+        reward_root.bump = ctx.bumps.reward_root;
+
+        log_event!(RewardRootPosted {
+            epoch,
+            root,
+            total_amount,
+            reward_per_update,
+            poster: reward_root.poster,
+            challenge_ends: reward_root.challenge_ends,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    pub fn challenge_reward_root(
+        ctx: Context<ChallengeRewardRoot>,
+        agent: Pubkey,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let reward_root = &mut ctx.accounts.reward_root;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!reward_root.voided, ErrorCode::RewardRootVoided);
+        require!(
+            current_time < reward_root.challenge_ends,
+            ErrorCode::ChallengeWindowClosed
+        );
+        require!(proof.len() <= MAX_PROOF_LEN, ErrorCode::InvalidMerkleProof);
+        require!(
+            verify_proof(&proof, reward_root.root, reward_leaf(reward_root.epoch, &agent, amount)),
+            ErrorCode::InvalidMerkleProof
+        );
+
+        // An agent with no stats account earned nothing
+        let stats_info = &ctx.accounts.agent_stats;
+        let submissions = if stats_info.data_is_empty() {
+            0
+        } else {
+            require!(stats_info.owner == &crate::ID, ErrorCode::StatsUnavailable);
+            let stats = AgentStats::try_deserialize(&mut &stats_info.try_borrow_data()?[..])?;
+            let (submissions, _) = stats.get(reward_root.epoch)
+                .ok_or(ErrorCode::StatsUnavailable)?;
+            submissions
+        };
+
+        let expected_amount = (submissions as u64)
+            .checked_mul(reward_root.reward_per_update)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(amount != expected_amount, ErrorCode::ChallengeFailed);
+
+        let bond = reward_root.bond;
+        **reward_root.to_account_info().try_borrow_mut_lamports()? -= bond;
+        **ctx.accounts.challenger.to_account_info().try_borrow_mut_lamports()? += bond;
+
+        reward_root.voided = true;
+        reward_root.bond = 0;
+
+        log_event!(RewardRootChallenged {
+            epoch: reward_root.epoch,
+            challenger: ctx.accounts.challenger.key(),
+            agent,
+            claimed_amount: amount,
+            expected_amount,
+            bond_slashed: bond,
+        });
+
+        Ok(())
+    }
+
+    pub fn release_reward_bond(ctx: Context<ReleaseRewardBond>) -> Result<()> {
+        let reward_root = &mut ctx.accounts.reward_root;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!reward_root.voided, ErrorCode::RewardRootVoided);
+        require!(!reward_root.bond_released, ErrorCode::BondAlreadyReleased);
+        require!(
+            current_time >= reward_root.challenge_ends,
+            ErrorCode::ChallengeWindowActive
+        );
+
+        let bond = reward_root.bond;
+        **reward_root.to_account_info().try_borrow_mut_lamports()? -= bond;
+        **ctx.accounts.poster.to_account_info().try_borrow_mut_lamports()? += bond;
+
+        reward_root.bond = 0;
+        reward_root.bond_released = true;
+
+        Ok(())
+    }
+
+    pub fn claim_agent_reward(
+        ctx: Context<ClaimAgentReward>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let reward_root = &mut ctx.accounts.reward_root;
+        let agent = ctx.accounts.agent.key();
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!reward_root.voided, ErrorCode::RewardRootVoided);
+        require!(
+            current_time >= reward_root.challenge_ends,
+            ErrorCode::ChallengeWindowActive
+        );
+        require!(proof.len() <= MAX_PROOF_LEN, ErrorCode::InvalidMerkleProof);
+        require!(
+            verify_proof(&proof, reward_root.root, reward_leaf(reward_root.epoch, &agent, amount)),
+            ErrorCode::InvalidMerkleProof
+        );
+
+        let new_claimed = reward_root.claimed_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            new_claimed <= reward_root.total_amount,
+            ErrorCode::RewardPoolExhausted
+        );
+
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer = &[&global_seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.agent_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        reward_root.claimed_amount = new_claimed;
+
+        let reward_claim = &mut ctx.accounts.reward_claim;
+        reward_claim.reward_root = reward_root.key();
+        reward_claim.agent = agent;
+        reward_claim.amount = amount;
+        reward_claim.bump = ctx.bumps.reward_claim;
+
+        log_event!(AgentRewardClaimed {
+            epoch: reward_root.epoch,
+            agent,
+            amount,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Register a keeper operator, bonding at least KEEPER_MIN_BOND into the
+    /// stake escrow
+    pub fn register_keeper(ctx: Context<RegisterKeeper>, bond: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        require!(bond >= KEEPER_MIN_BOND, ErrorCode::KeeperBondTooLow);
+
+        let keeper_registry = &mut ctx.accounts.keeper_registry;
+        keeper_registry.operator = ctx.accounts.operator.key();
+        keeper_registry.bond = bond;
+        keeper_registry.open_claims = 0;
+        keeper_registry.windows_serviced = 0;
+        keeper_registry.windows_missed = 0;
+        keeper_registry.bump = ctx.bumps.keeper_registry;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.operator_token_account.to_account_info(),
+                    to: ctx.accounts.stake_escrow.to_account_info(),
+                    authority: ctx.accounts.operator.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+
+        log_event!(KeeperRegistered {
+            operator: ctx.accounts.operator.key(),
+            bond,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Claim the exclusive right to run `job` during a future `window`.
+    /// The first claim on a window wins; the window PDA can only exist once.
+    pub fn claim_keeper_window(
+        ctx: Context<ClaimKeeperWindow>,
+        job: KeeperJob,
+        window: u64,
+    ) -> Result<()> {
+        let current_window = KeeperWindow::index_at(Clock::get()?.unix_timestamp);
+        require!(
+            window > current_window
+                && window <= current_window.saturating_add(KEEPER_MAX_CLAIM_AHEAD),
+            ErrorCode::InvalidKeeperWindow
+        );
+
+        // A slashed keeper must top back up before claiming again
+        let keeper_registry = &mut ctx.accounts.keeper_registry;
+        require!(keeper_registry.bond >= KEEPER_MIN_BOND, ErrorCode::KeeperBondTooLow);
+        keeper_registry.open_claims = keeper_registry.open_claims
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let keeper_window = &mut ctx.accounts.keeper_window;
+        keeper_window.job = job;
+        keeper_window.window = window;
+        keeper_window.keeper = keeper_registry.operator;
+        keeper_window.bump = ctx.bumps.keeper_window;
+
+        log_event!(KeeperWindowClaimed {
+            keeper: keeper_registry.operator,
+            job,
+            window,
+        });
+
+        Ok(())
+    }
+
+    /// Mark a claimed window serviced. Must run during the window, in the
+    /// same transaction as (and after) the crank itself.
+    pub fn complete_keeper_job(ctx: Context<CompleteKeeperJob>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let keeper_window = &ctx.accounts.keeper_window;
+        require!(
+            KeeperWindow::index_at(Clock::get()?.unix_timestamp) == keeper_window.window,
+            ErrorCode::KeeperWindowNotOpen
+        );
+        require_job_in_transaction(&ctx.accounts.instructions_sysvar, keeper_window.job)?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        let reward = KEEPER_REWARD.min(global_state.keeper_reward_pool);
+        if reward > 0 {
+            let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+            let signer = &[&global_seeds[..]];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.stake_escrow.to_account_info(),
+                        to: ctx.accounts.keeper_token_account.to_account_info(),
+                        authority: global_state.to_account_info(),
+                    },
+                    signer,
+                ),
+                reward,
+            )?;
+            global_state.keeper_reward_pool -= reward;
+        }
+
+        let keeper_registry = &mut ctx.accounts.keeper_registry;
+        keeper_registry.windows_serviced = keeper_registry.windows_serviced
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        keeper_registry.open_claims = keeper_registry.open_claims.saturating_sub(1);
+
+        log_event!(KeeperJobCompleted {
+            keeper: keeper_registry.operator,
+            job: keeper_window.job,
+            window: keeper_window.window,
+            reward,
+        });
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Slash a keeper whose claimed window ended without being serviced.
+    /// Permissionless; the slashed share of the bond funds future rewards.
+    pub fn slash_missed_window(ctx: Context<SlashMissedWindow>) -> Result<()> {
+        let keeper_window = &ctx.accounts.keeper_window;
+        require!(
+            Clock::get()?.unix_timestamp >= keeper_window.end_time(),
+            ErrorCode::KeeperWindowNotEnded
+        );
+
+        let keeper_registry = &mut ctx.accounts.keeper_registry;
+        let slash_amount = keeper_registry.bond * KEEPER_SLASH_BPS / 10_000;
+        keeper_registry.bond -= slash_amount;
+        keeper_registry.windows_missed = keeper_registry.windows_missed
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        keeper_registry.open_claims = keeper_registry.open_claims.saturating_sub(1);
+
+        // The slashed ARU never leaves the escrow, it only changes owner
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.keeper_reward_pool = global_state.keeper_reward_pool
+            .checked_add(slash_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        log_event!(KeeperSlashed {
+            keeper: keeper_registry.operator,
+            job: keeper_window.job,
+            window: keeper_window.window,
+            slash_amount,
+        });
+
+        Ok(())
+    }
+
+    /// Add ARU to the keeper reward pool
+    pub fn fund_keeper_rewards(ctx: Context<FundKeeperRewards>, amount: u64) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    to: ctx.accounts.stake_escrow.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.keeper_reward_pool = global_state.keeper_reward_pool
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Return the remaining bond and close the registry. Every claimed window
+    /// must be serviced or slashed first.
+    pub fn deregister_keeper(ctx: Context<DeregisterKeeper>) -> Result<()> {
+        ars_common::lock(&mut ctx.accounts.global_state, ctx.program_id)?;
+
+        let keeper_registry = &ctx.accounts.keeper_registry;
+        require!(keeper_registry.open_claims == 0, ErrorCode::KeeperHasOpenClaims);
+
+        let global_state = &ctx.accounts.global_state;
+        let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer = &[&global_seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.operator_token_account.to_account_info(),
+                    authority: global_state.to_account_info(),
+                },
+                signer,
+            ),
+            keeper_registry.bond,
+        )?;
+
+        ars_common::unlock(&mut ctx.accounts.global_state);
+
+        Ok(())
+    }
+
+    /// Close an agent registry once the agent is inactive with no stake left,
+    /// refunding its rent to the agent
+    pub fn close_agent_registry(ctx: Context<CloseAgentRegistry>) -> Result<()> {
+        let agent_registry = &ctx.accounts.agent_registry;
+        
+        require!(
+            !agent_registry.is_active
+                && agent_registry.stake_amount == 0
+                && agent_registry.delegated_out == 0
+                && agent_registry.holder_stake == 0,
+            ErrorCode::AccountNotClosable
+        );
+        
+        log_event!(AccountClosed {
+            account: agent_registry.key(),
+            kind: ClosedAccountKind::AgentRegistry,
+            rent_recipient: ctx.accounts.agent.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Close a proposal that is no longer active, or whose voting ended more
+    /// than PROPOSAL_RETENTION ago, refunding its rent to the proposer. A
+    /// proposal with a reward pool stays open for the full retention period so
+    /// its votes can be settled.
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        let retention_end = proposal.end_time
+            .checked_add(PROPOSAL_RETENTION)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            current_time >= retention_end
+                || (proposal.status != ProposalStatus::Active
+                    && proposal.status != ProposalStatus::Queued
+                    && proposal.status != ProposalStatus::Vetoed
+                    && proposal.reward_pool == 0
+                    && current_time >= proposal.end_time),
+            ErrorCode::AccountNotClosable
+        );
+        
+        log_event!(AccountClosed {
+            account: proposal.key(),
+            kind: ClosedAccountKind::Proposal,
+            rent_recipient: ctx.accounts.proposer.key(),
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalState::LEN,
+        seeds = [b"global_state"],
+        bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = ILIOracle::LEN,
+        seeds = [b"ili_oracle"],
+        bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = AuditLog::LEN,
+        seeds = [b"audit_log"],
+        bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Reserve vault address
+    pub reserve_vault: AccountInfo<'info>,
+    
+    /// CHECK: ARU mint address
+    pub aru_mint: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitiateAdminTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AuthorityCheckIn<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeParamUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyParamUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeParameterRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = ParameterRegistry::LEN,
+        seeds = [b"parameter_registry"],
+        bump
+    )]
+    pub parameter_registry: Box<Account<'info, ParameterRegistry>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeRegistryParam<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"parameter_registry"],
+        bump = parameter_registry.bump
+    )]
+    pub parameter_registry: Box<Account<'info, ParameterRegistry>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyRegistryParam<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"parameter_registry"],
+        bump = parameter_registry.bump
+    )]
+    pub parameter_registry: Box<Account<'info, ParameterRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct SetLegacyMessageCutoff<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetApprovalThresholds<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAuthorityMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetParticipationReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetIliSmoothing<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ViewILI<'info> {
+    #[account(
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+}
+
+#[derive(Accounts)]
+pub struct AssertBuildProfile {}
+
+#[cfg(feature = "devnet")]
+#[derive(Accounts)]
+pub struct SimulateProposal<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        constraint = mint_state.aru_mint == global_state.aru_mint @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub reserve_vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = simulator,
+        space = ProposalSimulation::LEN,
+        seeds = [b"simulation", proposal.key().as_ref(), simulator.key().as_ref()],
+        bump
+    )]
+    pub simulation: Account<'info, ProposalSimulation>,
+    
+    #[account(mut)]
+    pub simulator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(feature = "devnet")]
+#[derive(Accounts)]
+pub struct AttachSimulation<'info> {
+    #[account(
+        mut,
+        seeds = [b"vote_record", vote_record.proposal.as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        seeds = [b"simulation", vote_record.proposal.as_ref(), voter.key().as_ref()],
+        bump = simulation.bump
+    )]
+    pub simulation: Account<'info, ProposalSimulation>,
+    
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterAgent<'info> {
+    #[account(
+        init,
+        payer = agent,
+        space = AgentRegistry::LEN,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(mut)]
+    pub agent: Signer<'info>,
+    
+    #[account(mut)]
+    pub agent_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// Program-owned so vote rewards can be paid out of it
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    /// Published beforehand to include the name hash in AgentRegistered
+    #[account(
+        seeds = [b"agent_metadata", agent.key().as_ref()],
+        bump = agent_metadata.bump
+    )]
+    pub agent_metadata: Option<Account<'info, AgentMetadata>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitILIUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: address checked by seeds; read with read_param_table_or_default
+    #[account(
+        seeds = [b"parameter_registry"],
+        bump
+    )]
+    pub parameter_registry: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump,
+        constraint = agent_registry.is_authority(&authority.key()) @ ErrorCode::NotAgentAuthority
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AgentStats::LEN,
+        seeds = [b"agent_stats", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_stats: Account<'info, AgentStats>,
+    
+    /// CHECK: Registry key only; authority must be the agent or its operator
+    pub agent: UncheckedAccount<'info>,
+    
+    /// The agent's own key or its operator
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetAgentKey<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAgentMetadata<'info> {
+    #[account(
+        init,
+        payer = agent,
+        space = AgentMetadata::LEN,
+        seeds = [b"agent_metadata", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_metadata: Account<'info, AgentMetadata>,
+    
+    #[account(mut)]
+    pub agent: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAgentMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent_metadata", agent.key().as_ref()],
+        bump = agent_metadata.bump,
+        has_one = agent
+    )]
+    pub agent_metadata: Account<'info, AgentMetadata>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCommission<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitILIUpdateSecp256k1<'info> {
+    #[account(
+        mut,
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: address checked by seeds; read with read_param_table_or_default
+    #[account(
+        seeds = [b"parameter_registry"],
+        bump
+    )]
+    pub parameter_registry: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AgentStats::LEN,
+        seeds = [b"agent_stats", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_stats: Account<'info, AgentStats>,
+    
+    /// CHECK: Registry key only; the submission is authenticated by the
+    /// secp256k1 signature of the agent's registered EVM address
+    pub agent: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, verified by address
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(policy_params: PolicyParams)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = PolicyProposal::LEN,
+        seeds = [b"proposal", global_state.proposal_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = ProposalHash::LEN,
+        seeds = [b"proposal_hash", policy_params.hash().as_ref()],
+        bump
+    )]
+    pub proposal_hash: Account<'info, ProposalHash>,
+    
+    /// Proposer's agent registration, required by policy types with a
+    /// minimum tier
+    #[account(
+        seeds = [b"agent", proposer.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Option<Account<'info, AgentRegistry>>,
+    
+    /// Proposer's ARU account paying the griefing deposit
+    #[account(
+        mut,
+        constraint = proposer_token_account.owner == proposer.key(),
+        constraint = proposer_token_account.mint == global_state.aru_mint
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(policy_params: PolicyParams)]
+pub struct CreateFastTrackProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = PolicyProposal::LEN,
+        seeds = [b"proposal", global_state.proposal_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = ProposalHash::LEN,
+        seeds = [b"proposal_hash", policy_params.hash().as_ref()],
+        bump
+    )]
+    pub proposal_hash: Account<'info, ProposalHash>,
+    
+    #[account(
+        seeds = [b"agent", proposer.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    /// Proposer's ARU account paying the griefing deposit
+    #[account(
+        mut,
+        constraint = proposer_token_account.owner == proposer.key(),
+        constraint = proposer_token_account.mint == global_state.aru_mint
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(field: ProtocolParam, value: u64)]
+pub struct CreateOptimisticProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = PolicyProposal::LEN,
+        seeds = [b"proposal", global_state.proposal_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = ProposalHash::LEN,
+        seeds = [b"proposal_hash", PolicyParams::ParamUpdate { field, value }.hash().as_ref()],
+        bump
+    )]
+    pub proposal_hash: Account<'info, ProposalHash>,
+    
+    /// Proposer's agent registration, required by policy types with a
+    /// minimum tier
+    #[account(
+        seeds = [b"agent", proposer.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Option<Account<'info, AgentRegistry>>,
+    
+    /// Proposer's ARU account paying the griefing deposit
+    #[account(
+        mut,
+        constraint = proposer_token_account.owner == proposer.key(),
+        constraint = proposer_token_account.mint == global_state.aru_mint
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: EmergencyAction)]
+pub struct CreateEmergencyProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = PolicyProposal::LEN,
+        seeds = [b"proposal", global_state.proposal_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        seeds = [b"agent", proposer.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = ProposalHash::LEN,
+        seeds = [b"proposal_hash", PolicyParams::EmergencyParams { action }.hash().as_ref()],
+        bump
+    )]
+    pub proposal_hash: Account<'info, ProposalHash>,
+    
+    /// Proposer's ARU account paying the griefing deposit
+    #[account(
+        mut,
+        constraint = proposer_token_account.owner == proposer.key(),
+        constraint = proposer_token_account.mint == global_state.aru_mint
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct VoteOnProposal<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    /// Voter's agent registration; absent for holders voting with a lock
+    #[account(
+        mut,
+        seeds = [b"agent", voter.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Option<Account<'info, AgentRegistry>>,
+    
+    /// Voter's ARU lock, used when there is no agent registration
+    #[account(
+        seeds = [b"voter_lock", voter.key().as_ref()],
+        bump = voter_lock.bump
+    )]
+    pub voter_lock: Option<Account<'info, VoterLock>>,
+    
+    /// CHECK: AgentStats PDA for the voter; may be uninitialized, in which
+    /// case accuracy does not scale the vote
+    #[account(
+        seeds = [b"agent_stats", voter.key().as_ref()],
+        bump
+    )]
+    pub agent_stats: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = VoteRecord::LEN,
+        seeds = [b"vote_record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    /// CHECK: Key the vote is recorded for; checked against authority in
+    /// the handler
+    pub voter: UncheckedAccount<'info>,
+    
+    /// The voter, or the operator of the voter's agent registration
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VoteBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", voter.key().as_ref()],
+        bump = agent_registry.bump,
+        constraint = agent_registry.is_authority(&authority.key()) @ ErrorCode::NotAgentAuthority
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    /// CHECK: AgentStats PDA for the voter; may be uninitialized, in which
+    /// case accuracy does not scale the vote
+    #[account(
+        seeds = [b"agent_stats", voter.key().as_ref()],
+        bump
+    )]
+    pub agent_stats: UncheckedAccount<'info>,
+    
+    /// CHECK: Registry key only; authority must be the agent or its operator
+    pub voter: UncheckedAccount<'info>,
+    
+    /// The agent's own key or its operator
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote_record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", delegator.key().as_ref()],
+        bump = delegator_registry.bump
+    )]
+    pub delegator_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        seeds = [b"agent", delegatee_registry.agent_pubkey.as_ref()],
+        bump = delegatee_registry.bump
+    )]
+    pub delegatee_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init,
+        payer = delegator,
+        space = Delegation::LEN,
+        seeds = [b"delegation", delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+    
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", delegator.key().as_ref()],
+        bump = delegator_registry.bump
+    )]
+    pub delegator_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [b"delegation", delegator.key().as_ref()],
+        bump = delegation.bump,
+        has_one = delegator
+    )]
+    pub delegation: Account<'info, Delegation>,
+    
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnstaked<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.mint == global_state.aru_mint
+    )]
+    pub agent_token_account: Account<'info, TokenAccount>,
+    
+    pub agent: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct AgentHeartbeat<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeactivateStaleAgent<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent_registry.agent_pubkey.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdjustStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.mint == global_state.aru_mint
+    )]
+    pub agent_token_account: Account<'info, TokenAccount>,
+    
+    pub agent: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitiateUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.mint == global_state.aru_mint
+    )]
+    pub agent_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub agent: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DelegateStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent_registry.agent_pubkey.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = StakeDelegation::LEN,
+        seeds = [b"stake_delegation", agent_registry.agent_pubkey.as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub stake_delegation: Account<'info, StakeDelegation>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == global_state.aru_mint
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UndelegateStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", stake_delegation.agent.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_delegation", stake_delegation.agent.as_ref(), holder.key().as_ref()],
+        bump = stake_delegation.bump
+    )]
+    pub stake_delegation: Account<'info, StakeDelegation>,
+    
+    pub holder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawDelegatedStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_delegation", stake_delegation.agent.as_ref(), holder.key().as_ref()],
+        bump = stake_delegation.bump
+    )]
+    pub stake_delegation: Account<'info, StakeDelegation>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == global_state.aru_mint
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    pub holder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimHolderRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", stake_delegation.agent.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_delegation", stake_delegation.agent.as_ref(), holder.key().as_ref()],
+        bump = stake_delegation.bump
+    )]
+    pub stake_delegation: Account<'info, StakeDelegation>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == global_state.aru_mint
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    pub holder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetDelegationCompounding<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_delegation", stake_delegation.agent.as_ref(), holder.key().as_ref()],
+        bump = stake_delegation.bump
+    )]
+    pub stake_delegation: Account<'info, StakeDelegation>,
+    
+    pub holder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LockAru<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = VoterLock::LEN,
+        seeds = [b"voter_lock", owner.key().as_ref()],
+        bump
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key(),
+        constraint = owner_token_account.mint == global_state.aru_mint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLock<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"voter_lock", owner.key().as_ref()],
+        bump = voter_lock.bump
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key(),
+        constraint = owner_token_account.mint == global_state.aru_mint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CapVoteStake<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_record", proposal.key().as_ref(), vote_record.voter.as_ref()],
+        bump = vote_record.bump,
+        has_one = proposal
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        seeds = [b"agent", vote_record.voter.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    /// CHECK: AgentStats PDA for the voter; may be uninitialized, in which
+    /// case accuracy does not scale the vote
+    #[account(
+        seeds = [b"agent_stats", vote_record.voter.as_ref()],
+        bump
+    )]
+    pub agent_stats: UncheckedAccount<'info>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConviction<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveProposal<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    pub resolver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EvaluateOutcome<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    /// ars-reserve vault, required when the proposal's outcome metric is VHR
+    #[account(address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount)]
+    pub reserve_vault: Option<Account<'info, ReserveVault>>,
+    
+    /// ARU price feed, required when the proposal's outcome metric is the
+    /// ARU price
+    #[account(seeds = [b"aru_price_feed"], bump = aru_price_feed.bump)]
+    pub aru_price_feed: Option<Account<'info, AruPriceFeed>>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVoteReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_record", proposal.key().as_ref(), vote_record.voter.as_ref()],
+        bump = vote_record.bump,
+        has_one = proposal
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", vote_record.voter.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = AgentStats::LEN,
+        seeds = [b"agent_stats", vote_record.voter.as_ref()],
+        bump
+    )]
+    pub agent_stats: Account<'info, AgentStats>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == vote_record.voter,
+        constraint = voter_token_account.mint == global_state.aru_mint
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"treasury", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = global_state
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimProposalDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = proposer_token_account.owner == proposal.proposer,
+        constraint = proposer_token_account.mint == global_state.aru_mint
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    
+    pub caller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OpenMarket<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        init,
+        payer = liquidity_provider,
+        seeds = [b"yes_mint", proposal.key().as_ref()],
+        bump,
+        mint::decimals = 6,
+        mint::authority = global_state
+    )]
+    pub yes_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = liquidity_provider,
+        seeds = [b"no_mint", proposal.key().as_ref()],
+        bump,
+        mint::decimals = 6,
+        mint::authority = global_state
+    )]
+    pub no_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = liquidity_provider,
+        seeds = [b"market_vault", proposal.key().as_ref()],
+        bump,
+        token::mint = aru_mint,
+        token::authority = global_state
+    )]
+    pub market_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = global_state.aru_mint)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == liquidity_provider.key(),
+        constraint = provider_token_account.mint == global_state.aru_mint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub liquidity_provider: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TradeOutcome<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"yes_mint", proposal.key().as_ref()],
+        bump
+    )]
+    pub yes_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"no_mint", proposal.key().as_ref()],
+        bump
+    )]
+    pub no_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"market_vault", proposal.key().as_ref()],
+        bump
+    )]
+    pub market_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = trader_token_account.owner == trader.key(),
+        constraint = trader_token_account.mint == global_state.aru_mint
+    )]
+    pub trader_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = trader_yes_account.owner == trader.key(),
+        constraint = trader_yes_account.mint == yes_mint.key()
+    )]
+    pub trader_yes_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = trader_no_account.owner == trader.key(),
+        constraint = trader_no_account.mint == no_mint.key()
+    )]
+    pub trader_no_account: Account<'info, TokenAccount>,
+    
+    pub trader: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveMarket<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TradeOutcomeForAru<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: address checked by seeds; read with read_param_table_or_default
+    #[account(
+        seeds = [b"parameter_registry"],
+        bump
+    )]
+    pub parameter_registry: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"yes_mint", proposal.key().as_ref()],
+        bump
+    )]
+    pub yes_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"no_mint", proposal.key().as_ref()],
+        bump
+    )]
+    pub no_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"market_vault", proposal.key().as_ref()],
+        bump
+    )]
+    pub market_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = trader_token_account.owner == trader.key(),
+        constraint = trader_token_account.mint == global_state.aru_mint
+    )]
+    pub trader_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = trader_yes_account.owner == trader.key(),
+        constraint = trader_yes_account.mint == yes_mint.key()
+    )]
+    pub trader_yes_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = trader_no_account.owner == trader.key(),
+        constraint = trader_no_account.mint == no_mint.key()
+    )]
+    pub trader_no_account: Account<'info, TokenAccount>,
+    
+    pub trader: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SettleMarket<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        seeds = [b"yes_mint", proposal.key().as_ref()],
+        bump
+    )]
+    pub yes_mint: Account<'info, Mint>,
+    
+    #[account(
+        seeds = [b"no_mint", proposal.key().as_ref()],
+        bump
+    )]
+    pub no_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"market_vault", proposal.key().as_ref()],
+        bump
+    )]
+    pub market_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == proposal.market.liquidity_provider,
+        constraint = provider_token_account.mint == global_state.aru_mint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    pub caller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimParticipationReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_record", proposal.key().as_ref(), vote_record.voter.as_ref()],
+        bump = vote_record.bump,
+        has_one = proposal
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == vote_record.voter,
+        constraint = voter_token_account.mint == global_state.aru_mint
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    
+    pub caller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FundParticipationRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    
+    pub funder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct QueueProposal<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteQueuedProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    /// MintARU / BurnARU: ars-token mint state
+    #[account(mut)]
+    pub mint_state: Option<Account<'info, MintState>>,
+    
+    /// MintARU / BurnARU: ARU mint
+    #[account(mut)]
+    pub aru_mint: Option<Account<'info, Mint>>,
+    
+    /// MintARU: the proposal's destination account
+    /// BurnARU: protocol-owned ARU account burned from
+    /// TreasurySpend: the proposal's destination account
+    #[account(mut)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// TreasurySpend: treasury of the spent mint
+    #[account(mut)]
+    pub protocol_treasury: Option<Account<'info, TokenAccount>>,
+    
+    /// RebalanceVault: ars-reserve vault
+    #[account(mut)]
+    pub reserve_vault: Option<Account<'info, ReserveVault>>,
+    
+    /// ARU price feed, required when the proposal's outcome metric is the
+    /// ARU price
+    #[account(seeds = [b"aru_price_feed"], bump = aru_price_feed.bump)]
+    pub aru_price_feed: Option<Account<'info, AruPriceFeed>>,
+    
+    #[account(
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    pub executor: Signer<'info>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    pub ars_token_program: Option<Program<'info, ArsToken>>,
+    pub ars_reserve_program: Option<Program<'info, ArsReserve>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEmergencyProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    /// ars-reserve vault, required when the proposal's outcome metric is VHR
+    #[account(address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount)]
+    pub reserve_vault: Option<Account<'info, ReserveVault>>,
+    
+    /// ARU price feed, required when the proposal's outcome metric is the
+    /// ARU price
+    #[account(seeds = [b"aru_price_feed"], bump = aru_price_feed.bump)]
+    pub aru_price_feed: Option<Account<'info, AruPriceFeed>>,
+    
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOptimisticProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    /// ars-reserve vault, required when the proposal's outcome metric is VHR
+    #[account(address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount)]
+    pub reserve_vault: Option<Account<'info, ReserveVault>>,
+    
+    /// ARU price feed, required when the proposal's outcome metric is the
+    /// ARU price
+    #[account(seeds = [b"aru_price_feed"], bump = aru_price_feed.bump)]
+    pub aru_price_feed: Option<Account<'info, AruPriceFeed>>,
+    
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OverrideILI<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    /// ars-reserve vault, required when the proposal's outcome metric is VHR
+    #[account(address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount)]
+    pub reserve_vault: Option<Account<'info, ReserveVault>>,
+    
+    /// ARU price feed, required when the proposal's outcome metric is the
+    /// ARU price
+    #[account(seeds = [b"aru_price_feed"], bump = aru_price_feed.bump)]
+    pub aru_price_feed: Option<Account<'info, AruPriceFeed>>,
+    
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TriggerCircuitBreaker<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: address checked by seeds; read with read_param_table_or_default
+    #[account(
+        seeds = [b"parameter_registry"],
+        bump
+    )]
+    pub parameter_registry: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CheckPriceAnomaly<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        seeds = [b"ili_oracle"],
+        bump = ili_oracle.bump
+    )]
+    pub ili_oracle: Account<'info, ILIOracle>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAnomalyDetector<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeactivateCircuitBreaker<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        constraint = mint_state.aru_mint == global_state.aru_mint
+            && mint_state.governance == global_state.key() @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        mut,
+        address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount,
+        constraint = reserve_vault.governance == global_state.key() @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub reserve_vault: Account<'info, ReserveVault>,
+    
+    pub authority: Signer<'info>,
+    
+    pub ars_token_program: Program<'info, ArsToken>,
+    pub ars_reserve_program: Program<'info, ArsReserve>,
+}
+
+#[derive(Accounts)]
+pub struct SetBreakerCooldown<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRealmsGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RealmsUpdateParameter<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    /// Realms governance PDA, signed by spl-governance when it executes the
+    /// DAO's proposal; checked by verify_realms_governance
+    pub governance: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RealmsRebalance<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount,
+        constraint = reserve_vault.governance == global_state.key() @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub reserve_vault: Account<'info, ReserveVault>,
+    
+    /// Realms governance PDA, signed by spl-governance when it executes the
+    /// DAO's proposal; checked by verify_realms_governance
+    pub governance: Signer<'info>,
+    
+    pub ars_reserve_program: Program<'info, ArsReserve>,
+}
+
+#[derive(Accounts)]
+pub struct SetBugBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BugBounty::LEN,
+        seeds = [b"bug_bounty"],
+        bump
+    )]
+    pub bug_bounty: Account<'info, BugBounty>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct CommitReport<'info> {
+    #[account(
+        mut,
+        seeds = [b"bug_bounty"],
+        bump = bug_bounty.bump
+    )]
+    pub bug_bounty: Account<'info, BugBounty>,
+    
+    #[account(
+        init,
+        payer = reporter,
+        space = ReportCommitment::LEN,
+        seeds = [b"report_commitment", commitment.as_ref()],
+        bump
+    )]
+    pub report: Account<'info, ReportCommitment>,
+    
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SlashAgent<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", agent_registry.agent_pubkey.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = SlashRecord::LEN,
+        seeds = [
+            b"slash_record",
+            agent_registry.agent_pubkey.as_ref(),
+            agent_registry.slash_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    
+    /// CHECK: address checked by seeds; read with read_param_table_or_default
+    #[account(
+        seeds = [b"parameter_registry"],
+        bump
+    )]
+    pub parameter_registry: UncheckedAccount<'info>,
+    
+    /// Guardian co-signing a slash above the confirmation threshold
+    pub guardian: Option<Signer<'info>>,
+    
+    /// Executed SlashAgent proposal approving a slash above the confirmation
+    /// threshold
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, PolicyProposal>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"slash_record", agent.key().as_ref(), slash_record.index.to_le_bytes().as_ref()],
+        bump = slash_record.bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    
+    pub agent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"agent", slash_record.agent.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"slash_record", slash_record.agent.as_ref(), slash_record.index.to_le_bytes().as_ref()],
+        bump = slash_record.bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, TokenAccount>,
+    
+    /// Executed SlashAgent proposal upholding an appealed slash
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, PolicyProposal>>,
+    
+    pub caller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DismissSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", slash_record.agent.as_ref()],
+        bump = agent_registry.bump
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"slash_record", slash_record.agent.as_ref(), slash_record.index.to_le_bytes().as_ref()],
+        bump = slash_record.bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    
+    /// Rejected SlashAgent proposal deciding the appeal
+    #[account(
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"insurance_fund", aru_mint.key().as_ref()],
+        bump,
+        token::mint = aru_mint,
+        token::authority = global_state
+    )]
+    pub insurance_fund: Account<'info, TokenAccount>,
+    
+    #[account(address = global_state.aru_mint)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CoverShortfall<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", global_state.aru_mint.as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = global_state.reserve_vault @ ErrorCode::InvalidPolicyAccount,
+        constraint = reserve_vault.governance == global_state.key() @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub reserve_vault: Account<'info, ReserveVault>,
+    
+    /// Reserve vault token account for ARU
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == global_state.aru_mint @ ErrorCode::InvalidPolicyAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: ARU asset config of the reserve vault, checked by ars-reserve
+    pub asset_config: UncheckedAccount<'info>,
+    
+    /// Realms governance PDA, signed by spl-governance when it executes the
+    /// DAO's proposal; checked by verify_realms_governance
+    pub governance: Signer<'info>,
+    
+    pub ars_reserve_program: Program<'info, ArsReserve>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RandomnessState::LEN,
+        seeds = [b"randomness"],
+        bump
+    )]
+    pub randomness_state: Account<'info, RandomnessState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"randomness"],
+        bump = randomness_state.bump
+    )]
+    pub randomness_state: Account<'info, RandomnessState>,
+
+    /// CHECK: Owner and seed slot validated in switchboard_seed_slot
+    pub randomness_account: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"randomness"],
+        bump = randomness_state.bump
+    )]
+    pub randomness_state: Account<'info, RandomnessState>,
+
+    /// CHECK: Must match the committed randomness account
+    pub randomness_account: AccountInfo<'info>,
+
+    /// CHECK: Validated against the SlotHashes sysvar ID
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DrawCommittee<'info> {
+    #[account(
+        mut,
+        seeds = [b"randomness"],
+        bump = randomness_state.bump
+    )]
+    pub randomness_state: Account<'info, RandomnessState>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterAruPriceFeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AruPriceFeed::LEN,
+        seeds = [b"aru_price_feed"],
+        bump
+    )]
+    pub aru_price_feed: Account<'info, AruPriceFeed>,
+
+    /// CHECK: Raydium CP-Swap pool, parsed and owner-checked in the handler
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: The pool's observation account, checked against the pool
+    pub observation_state: UncheckedAccount<'info>,
+
+    #[account(address = global_state.aru_mint @ ErrorCode::InvalidPriceSource)]
+    pub aru_mint: Account<'info, Mint>,
+
+    pub quote_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAruPrice<'info> {
+    #[account(
+        mut,
+        seeds = [b"aru_price_feed"],
+        bump = aru_price_feed.bump
+    )]
+    pub aru_price_feed: Account<'info, AruPriceFeed>,
+
+    /// CHECK: Must match the feed's observation account
+    #[account(address = aru_price_feed.observation_state @ ErrorCode::InvalidPriceSource)]
+    pub observation_state: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EnableCompressedVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+
+    /// CHECK: Pre-allocated tree account, initialized by spl-account-compression
+    #[account(mut)]
+    pub vote_tree: UncheckedAccount<'info>,
+
+    pub proposer: Signer<'info>,
+
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub noop_program: Program<'info, Noop>,
+}
+
+#[derive(Accounts)]
+pub struct VoteOnProposalCompressed<'info> {
+    #[account(
+        mut,
         seeds = [b"global_state"],
-        bump
+        bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
     
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"agent", voter.key().as_ref()],
+        bump = agent_registry.bump,
+        constraint = agent_registry.is_authority(&authority.key()) @ ErrorCode::NotAgentAuthority
+    )]
+    pub agent_registry: Account<'info, AgentRegistry>,
+
+    /// CHECK: AgentStats PDA for the voter; may be uninitialized, in which
+    /// case accuracy does not scale the vote
+    #[account(
+        seeds = [b"agent_stats", voter.key().as_ref()],
+        bump
+    )]
+    pub agent_stats: UncheckedAccount<'info>,
+
+    /// Same record as vote_on_proposal, so either path can only vote once
     #[account(
         init,
         payer = authority,
-        space = ILIOracle::LEN,
-        seeds = [b"ili_oracle"],
+        space = VoteRecord::LEN,
+        seeds = [b"vote_record", proposal.key().as_ref(), voter.key().as_ref()],
         bump
     )]
-    pub ili_oracle: Account<'info, ILIOracle>,
-    
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// CHECK: Must match proposal.vote_tree
+    #[account(mut)]
+    pub vote_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Registry key only; authority must be the agent or its operator
+    pub voter: UncheckedAccount<'info>,
+
+    /// The agent's own key or its operator
     #[account(mut)]
     pub authority: Signer<'info>,
-    
-    /// CHECK: Reserve vault address
-    pub reserve_vault: AccountInfo<'info>,
-    
-    /// CHECK: ARU mint address
-    pub aru_mint: AccountInfo<'info>,
-    
+
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub noop_program: Program<'info, Noop>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitiateAdminTransfer<'info> {
+pub struct VerifyCompressedVote<'info> {
+    #[account(
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PolicyProposal>,
+
+    /// CHECK: Must match proposal.vote_tree
+    pub vote_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Voter whose leaf is being proven
+    pub voter: UncheckedAccount<'info>,
+
+    pub compression_program: Program<'info, SplAccountCompression>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct PostRewardRoot<'info> {
     #[account(
         mut,
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    
-    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = poster,
+        space = RewardRoot::LEN,
+        seeds = [b"reward_root", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_root: Account<'info, RewardRoot>,
+
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ExecuteAdminTransfer<'info> {
+pub struct ClaimAgentReward<'info> {
     #[account(
         mut,
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-}
 
-#[derive(Accounts)]
-pub struct RegisterAgent<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_root", reward_root.epoch.to_le_bytes().as_ref()],
+        bump = reward_root.bump
+    )]
+    pub reward_root: Account<'info, RewardRoot>,
+
     #[account(
         init,
         payer = agent,
-        space = AgentRegistry::LEN,
-        seeds = [b"agent", agent.key().as_ref()],
+        space = RewardClaim::LEN,
+        seeds = [b"reward_claim", reward_root.key().as_ref(), agent.key().as_ref()],
         bump
     )]
-    pub agent_registry: Account<'info, AgentRegistry>,
-    
+    pub reward_claim: Account<'info, RewardClaim>,
+
+    #[account(
+        mut,
+        constraint = reward_vault.owner == global_state.key(),
+        constraint = reward_vault.mint == global_state.aru_mint
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(address = global_state.aru_mint)]
+    pub aru_mint: Account<'info, Mint>,
+
+    /// Created on demand (paid by the agent) if the agent has no ARU ATA yet
+    #[account(
+        init_if_needed,
+        payer = agent,
+        associated_token::mint = aru_mint,
+        associated_token::authority = agent
+    )]
+    pub agent_token_account: Account<'info, TokenAccount>,
+
     #[account(mut)]
     pub agent: Signer<'info>,
-    
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(agent: Pubkey)]
+pub struct ChallengeRewardRoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_root", reward_root.epoch.to_le_bytes().as_ref()],
+        bump = reward_root.bump
+    )]
+    pub reward_root: Account<'info, RewardRoot>,
+
+    /// CHECK: AgentStats PDA for the challenged agent; may be uninitialized
+    #[account(
+        seeds = [b"agent_stats", agent.as_ref()],
+        bump
+    )]
+    pub agent_stats: UncheckedAccount<'info>,
+
     #[account(mut)]
-    pub agent_token_account: Account<'info, TokenAccount>,
-    
+    pub challenger: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseRewardBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_root", reward_root.epoch.to_le_bytes().as_ref()],
+        bump = reward_root.bump,
+        has_one = poster
+    )]
+    pub reward_root: Account<'info, RewardRoot>,
+
+    /// CHECK: Receives the bond; must match reward_root.poster
+    #[account(mut)]
+    pub poster: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterKeeper<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = KeeperRegistry::LEN,
+        seeds = [b"keeper", operator.key().as_ref()],
+        bump
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
     #[account(mut)]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
     pub stake_escrow: Account<'info, TokenAccount>,
-    
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitILIUpdate<'info> {
+#[instruction(job: KeeperJob, window: u64)]
+pub struct ClaimKeeperWindow<'info> {
     #[account(
         mut,
-        seeds = [b"ili_oracle"],
-        bump = ili_oracle.bump
+        seeds = [b"keeper", operator.key().as_ref()],
+        bump = keeper_registry.bump
     )]
-    pub ili_oracle: Account<'info, ILIOracle>,
-    
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = KeeperWindow::LEN,
+        seeds = [b"keeper_window", &[job as u8], window.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub keeper_window: Account<'info, KeeperWindow>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteKeeperJob<'info> {
     #[account(
+        mut,
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    
+
     #[account(
-        seeds = [b"agent", agent.key().as_ref()],
-        bump = agent_registry.bump
+        mut,
+        seeds = [b"keeper", keeper.key().as_ref()],
+        bump = keeper_registry.bump
     )]
-    pub agent_registry: Account<'info, AgentRegistry>,
-    
-    pub agent: Signer<'info>,
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+
+    #[account(
+        mut,
+        close = keeper,
+        seeds = [
+            b"keeper_window",
+            &[keeper_window.job as u8],
+            keeper_window.window.to_le_bytes().as_ref()
+        ],
+        bump = keeper_window.bump,
+        has_one = keeper
+    )]
+    pub keeper_window: Account<'info, KeeperWindow>,
+
+    #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = keeper_token_account.owner == keeper.key(),
+        constraint = keeper_token_account.mint == global_state.aru_mint
+    )]
+    pub keeper_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, verified by address
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct CreateProposal<'info> {
+pub struct SlashMissedWindow<'info> {
     #[account(
         mut,
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    
+
     #[account(
-        init,
-        payer = proposer,
-        space = PolicyProposal::LEN,
-        seeds = [b"proposal", global_state.proposal_counter.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [b"keeper", keeper_window.keeper.as_ref()],
+        bump = keeper_registry.bump
     )]
-    pub proposal: Account<'info, PolicyProposal>,
-    
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+
+    #[account(
+        mut,
+        close = keeper,
+        seeds = [
+            b"keeper_window",
+            &[keeper_window.job as u8],
+            keeper_window.window.to_le_bytes().as_ref()
+        ],
+        bump = keeper_window.bump,
+        has_one = keeper
+    )]
+    pub keeper_window: Account<'info, KeeperWindow>,
+
+    /// CHECK: Receives the window rent; must match keeper_window.keeper
     #[account(mut)]
-    pub proposer: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
+    pub keeper: UncheckedAccount<'info>,
+
+    pub caller: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct VoteOnProposal<'info> {
+pub struct FundKeeperRewards<'info> {
     #[account(
         mut,
-        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
-        bump = proposal.bump
+        seeds = [b"global_state"],
+        bump = global_state.bump
     )]
-    pub proposal: Account<'info, PolicyProposal>,
-    
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
-        seeds = [b"agent", voter.key().as_ref()],
-        bump = agent_registry.bump
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
     )]
-    pub agent_registry: Account<'info, AgentRegistry>,
-    
-    pub voter: Signer<'info>,
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct TriggerCircuitBreaker<'info> {
+pub struct DeregisterKeeper<'info> {
     #[account(
         mut,
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    
+
+    #[account(
+        mut,
+        close = operator,
+        seeds = [b"keeper", operator.key().as_ref()],
+        bump = keeper_registry.bump
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+
     #[account(
+        mut,
+        constraint = stake_escrow.owner == global_state.key(),
+        constraint = stake_escrow.mint == global_state.aru_mint
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = operator_token_account.owner == operator.key(),
+        constraint = operator_token_account.mint == global_state.aru_mint
+    )]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAgentRegistry<'info> {
+    #[account(
+        mut,
+        close = agent,
         seeds = [b"agent", agent.key().as_ref()],
         bump = agent_registry.bump
     )]
     pub agent_registry: Account<'info, AgentRegistry>,
     
+    #[account(mut)]
     pub agent: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct SlashAgent<'info> {
-    #[account(
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Account<'info, GlobalState>,
-    
+pub struct CloseProposal<'info> {
     #[account(
         mut,
-        seeds = [b"agent", agent_registry.agent_pubkey.as_ref()],
-        bump = agent_registry.bump
+        close = proposer,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer
     )]
-    pub agent_registry: Account<'info, AgentRegistry>,
+    pub proposal: Account<'info, PolicyProposal>,
     
-    pub authority: Signer<'info>,
+    /// CHECK: Rent recipient, must be the proposal's proposer
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    pub closer: Signer<'info>,
 }
 
     /// Allocate collateral from ARS to Percolator market
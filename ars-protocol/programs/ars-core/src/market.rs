@@ -0,0 +1,291 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::GlobalState;
+
+/// Prediction market on a proposal's outcome
+///
+/// open_market creates a YES and a NO share mint per proposal, at
+/// ["yes_mint", proposal] and ["no_mint", proposal], and an ARU vault at
+/// ["market_vault", proposal], all controlled by global_state. Every ARU in
+/// the vault backs one YES and one NO share: anyone can mint a complete set
+/// for 1 ARU or merge one back. The liquidity provider's ARU seeds a
+/// constant-product pool of unminted shares held in the proposal's
+/// OutcomeMarket, so each side's minted supply plus its pool reserve always
+/// equals the collateral. Swapping one side for the other moves the pool
+/// price, which is the market's running estimate that YES wins. The
+/// provider's loss is bounded by the liquidity they seeded.
+///
+/// buy_outcome and sell_outcome trade one side directly against ARU: a buy
+/// mints complete sets and sells the unwanted side into the pool, a sell
+/// swaps part of the shares for the other side and merges the resulting
+/// sets. Both charge the MarketFeeBps registry parameter on the ARU leg,
+/// paid to the ARU treasury.
+///
+/// Once PolicyProposal::yes_won is known, resolve_market burns the pool's
+/// losing reserve and holders redeem winning shares 1:1 for ARU; losing
+/// shares are worthless. settle_market pays the pool's winning reserve out
+/// to the liquidity provider.
+
+/// Smallest liquidity a market can be opened with (10 ARU)
+pub const MIN_MARKET_LIQUIDITY: u64 = 10_000_000;
+
+/// Treasury fee on `amount` ARU traded at `fee_bps`
+pub fn market_fee(amount: u64, fee_bps: u16) -> u64 {
+    (amount as u128 * fee_bps as u128 / 10_000) as u64
+}
+
+/// Market state kept inside the proposal account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct OutcomeMarket {
+    /// Whether open_market has run for this proposal
+    pub enabled: bool,
+    /// Account that seeded the pool
+    pub liquidity_provider: Pubkey,
+    /// Unminted YES shares held by the pool
+    pub yes_reserve: u64,
+    /// Unminted NO shares held by the pool
+    pub no_reserve: u64,
+    /// ARU in the market vault
+    pub collateral: u64,
+    /// Whether resolve_market has burned the losing reserve
+    pub resolved: bool,
+}
+
+impl OutcomeMarket {
+    pub const LEN: usize = 1 + // enabled
+        32 + // liquidity_provider
+        8 + // yes_reserve
+        8 + // no_reserve
+        8 + // collateral
+        1; // resolved
+
+    /// Seed the pool with `liquidity` complete sets
+    pub fn open(&mut self, liquidity_provider: Pubkey, liquidity: u64) -> Result<()> {
+        require!(!self.enabled, ErrorCode::MarketAlreadyOpen);
+        require!(liquidity >= MIN_MARKET_LIQUIDITY, ErrorCode::InsufficientLiquidity);
+        self.enabled = true;
+        self.liquidity_provider = liquidity_provider;
+        self.yes_reserve = liquidity;
+        self.no_reserve = liquidity;
+        self.collateral = liquidity;
+        self.resolved = false;
+        Ok(())
+    }
+
+    /// Require the market to be open for trading. Trading stops once the
+    /// winning side is known, so nobody can trade against a stale price.
+    pub fn require_trading(&self, outcome_known: bool) -> Result<()> {
+        require!(self.enabled, ErrorCode::MarketNotOpen);
+        require!(!self.resolved && !outcome_known, ErrorCode::MarketClosed);
+        Ok(())
+    }
+
+    /// Account for `amount` complete sets minted (or merged if `mint` is
+    /// false) against the vault
+    pub fn change_collateral(&mut self, amount: u64, mint: bool) -> Result<()> {
+        self.collateral = if mint {
+            self.collateral.checked_add(amount)
+        } else {
+            self.collateral.checked_sub(amount)
+        }
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Sell `amount_in` shares of one side into the pool for shares of the
+    /// other, returning how many the trader receives
+    pub fn swap(&mut self, sell_yes: bool, amount_in: u64) -> Result<u64> {
+        let (reserve_in, reserve_out) = if sell_yes {
+            (&mut self.yes_reserve, &mut self.no_reserve)
+        } else {
+            (&mut self.no_reserve, &mut self.yes_reserve)
+        };
+        let amount_out = swap_out(*reserve_in, *reserve_out, amount_in)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        *reserve_in = reserve_in
+            .checked_add(amount_in)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        *reserve_out -= amount_out;
+        Ok(amount_out)
+    }
+
+    /// Mint `collateral_in` complete sets and sell the unwanted side into the
+    /// pool, returning how many shares of the bought side the trader receives
+    pub fn buy(&mut self, buy_yes: bool, collateral_in: u64) -> Result<u64> {
+        self.change_collateral(collateral_in, true)?;
+        let swapped = self.swap(!buy_yes, collateral_in)?;
+        let shares_out = collateral_in
+            .checked_add(swapped)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(shares_out)
+    }
+
+    /// Sell `shares_in` shares of one side for ARU: part is swapped for the
+    /// other side and the rest merged with it. Returns the ARU released from
+    /// the vault, the most the pool can pay without its product shrinking.
+    pub fn sell(&mut self, sell_yes: bool, shares_in: u64) -> Result<u64> {
+        let (reserve_in, reserve_out) = if sell_yes {
+            (self.yes_reserve, self.no_reserve)
+        } else {
+            (self.no_reserve, self.yes_reserve)
+        };
+        // Largest merge size whose other side the pool can pay for
+        let (mut low, mut high) = (0u64, shares_in.min(reserve_out));
+        while low < high {
+            let mid = high - (high - low) / 2;
+            let paid = swap_out(reserve_in, reserve_out, shares_in - mid)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            if paid >= mid {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        let collateral_out = low;
+
+        let (reserve_in, reserve_out) = if sell_yes {
+            (&mut self.yes_reserve, &mut self.no_reserve)
+        } else {
+            (&mut self.no_reserve, &mut self.yes_reserve)
+        };
+        *reserve_in = reserve_in
+            .checked_add(shares_in - collateral_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        *reserve_out -= collateral_out;
+        self.change_collateral(collateral_out, false)?;
+        Ok(collateral_out)
+    }
+
+    /// Pay out the pool's remaining winning reserve after resolution,
+    /// returning the ARU released from the vault
+    pub fn settle(&mut self) -> Result<u64> {
+        require!(self.resolved, ErrorCode::MarketNotResolved);
+        // The losing reserve was zeroed by resolve
+        let amount = self.yes_reserve + self.no_reserve;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        self.yes_reserve = 0;
+        self.no_reserve = 0;
+        self.change_collateral(amount, false)?;
+        Ok(amount)
+    }
+
+    /// Whether every share is backed: each side's minted supply plus its
+    /// pool reserve equals the collateral. After resolution only the winning
+    /// side is checked.
+    pub fn is_backed(&self, yes_supply: u64, no_supply: u64, yes_won: Option<bool>) -> bool {
+        let backs = |supply: u64, reserve: u64| {
+            supply as u128 + reserve as u128 == self.collateral as u128
+        };
+        match (self.resolved, yes_won) {
+            (true, Some(true)) => backs(yes_supply, self.yes_reserve),
+            (true, Some(false)) => backs(no_supply, self.no_reserve),
+            _ => backs(yes_supply, self.yes_reserve) && backs(no_supply, self.no_reserve),
+        }
+    }
+
+    /// Price of a YES share in basis points of 1 ARU
+    pub fn yes_price_bps(&self) -> u16 {
+        yes_price_bps(self.yes_reserve, self.no_reserve)
+    }
+
+    /// Burn the pool's losing reserve, returning how many shares were burned
+    pub fn resolve(&mut self, yes_won: bool) -> Result<u64> {
+        require!(self.enabled, ErrorCode::MarketNotOpen);
+        require!(!self.resolved, ErrorCode::MarketClosed);
+        let losing_reserve = if yes_won {
+            &mut self.no_reserve
+        } else {
+            &mut self.yes_reserve
+        };
+        let burned = *losing_reserve;
+        *losing_reserve = 0;
+        self.resolved = true;
+        Ok(burned)
+    }
+}
+
+/// Shares of the other side a constant-product pool pays for `amount_in`,
+/// rounded down so the product never shrinks
+pub fn swap_out(reserve_in: u64, reserve_out: u64, amount_in: u64) -> Option<u64> {
+    let k = reserve_in as u128 * reserve_out as u128;
+    let new_in = (reserve_in as u128).checked_add(amount_in as u128)?;
+    if new_in == 0 {
+        return Some(0);
+    }
+    let new_out = k.div_ceil(new_in);
+    Some((reserve_out as u128 - new_out) as u64)
+}
+
+/// Price of a YES share in basis points of 1 ARU for the given reserves
+pub fn yes_price_bps(yes_reserve: u64, no_reserve: u64) -> u16 {
+    let total = yes_reserve as u128 + no_reserve as u128;
+    if total == 0 {
+        return 5_000;
+    }
+    (no_reserve as u128 * 10_000 / total) as u16
+}
+
+/// Mint `amount` outcome shares of `mint` into `to`, signed by global_state
+pub fn mint_shares<'info>(
+    token_program: &Program<'info, Token>,
+    mint: &Account<'info, Mint>,
+    to: &Account<'info, TokenAccount>,
+    global_state: &Account<'info, GlobalState>,
+    amount: u64,
+) -> Result<()> {
+    let global_seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: global_state.to_account_info(),
+            },
+            &[&global_seeds[..]],
+        ),
+        amount,
+    )
+}
+
+/// Burn `amount` outcome shares of `mint` from `from`, signed by `owner`
+pub fn burn_shares<'info>(
+    token_program: &Program<'info, Token>,
+    mint: &Account<'info, Mint>,
+    from: &Account<'info, TokenAccount>,
+    owner: &Signer<'info>,
+    amount: u64,
+) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: from.to_account_info(),
+                authority: owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Check after a trade that every minted share is still backed by the
+/// market's collateral and that the vault holds that collateral
+pub fn require_backed<'info>(
+    market: &OutcomeMarket,
+    yes_mint: &mut Account<'info, Mint>,
+    no_mint: &mut Account<'info, Mint>,
+    market_vault: &mut Account<'info, TokenAccount>,
+) -> Result<()> {
+    yes_mint.reload()?;
+    no_mint.reload()?;
+    market_vault.reload()?;
+    require!(
+        market.is_backed(yes_mint.supply, no_mint.supply, None)
+            && market_vault.amount >= market.collateral,
+        ErrorCode::MarketUnbacked
+    );
+    Ok(())
+}
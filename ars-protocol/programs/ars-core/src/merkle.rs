@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Challenge window before a posted reward root becomes claimable (24 hours)
+pub const REWARD_CHALLENGE_WINDOW: i64 = 24 * 60 * 60;
+
+/// Bond posted with a reward root, paid to a successful challenger (5 SOL)
+pub const REWARD_ROOT_BOND_LAMPORTS: u64 = 5_000_000_000;
+
+/// Maximum proof length accepted by claim instructions (supports 2^20 leaves)
+pub const MAX_PROOF_LEN: usize = 20;
+
+/// Leaf of the per-epoch agent reward distribution
+pub fn reward_leaf(epoch: u64, agent: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        b"ars_reward",
+        &epoch.to_le_bytes(),
+        agent.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Verify a Merkle proof using sorted-pair keccak hashing
+/// (same convention as OpenZeppelin's MerkleProof)
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use ars_reserve::ReserveVault;
+
+use crate::errors::ErrorCode;
+use crate::simulation::vhr;
+use crate::state::{AruPriceFeed, GlobalState, ILIOracle, IliSource, OutcomeMetric};
+
+/// Outcome metrics
+///
+/// A proposal declares at creation which published feed scores it (its
+/// OutcomeCriterion). The metric is read once when the proposal executes and
+/// again by evaluate_outcome after the criterion's horizon; YES was correct
+/// if it did not fall. Instructions that read a metric take the ars-reserve
+/// vault and the ARU price feed as optional accounts, required only for VHR
+/// and the ARU price respectively.
+
+/// Current value of `metric`
+pub fn read_outcome_metric(
+    metric: OutcomeMetric,
+    global_state: &GlobalState,
+    ili_oracle: &ILIOracle,
+    reserve_vault: Option<&Account<ReserveVault>>,
+    aru_price_feed: Option<&Account<AruPriceFeed>>,
+    current_time: i64,
+) -> Result<u64> {
+    match metric {
+        OutcomeMetric::Ili => ili_oracle.read(IliSource::Smoothed, current_time),
+        OutcomeMetric::Vhr => {
+            let reserve_vault = reserve_vault.ok_or(ErrorCode::MissingPolicyAccount)?;
+            require!(
+                reserve_vault.key() == global_state.reserve_vault,
+                ErrorCode::InvalidPolicyAccount
+            );
+            Ok(vhr(reserve_vault.total_value, reserve_vault.total_liabilities()?) as u64)
+        }
+        OutcomeMetric::AruPrice => aru_price_feed
+            .ok_or(ErrorCode::MissingPolicyAccount)?
+            .read(current_time),
+    }
+}
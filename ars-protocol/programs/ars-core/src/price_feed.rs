@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use solana_program::{pubkey, pubkey::Pubkey as SolanaPubkey};
+
+use crate::errors::ErrorCode;
+
+/// Raydium CP-Swap program ID (owner of CPMM pool and observation accounts)
+pub const RAYDIUM_CPMM_PROGRAM_ID: SolanaPubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+/// Fixed-point scale of AruPriceFeed::price (quote units per ARU, 6 decimals)
+pub const ARU_PRICE_SCALE: u64 = 1_000_000;
+
+/// Shortest TWAP window a feed can be registered with
+pub const MIN_TWAP_WINDOW: i64 = 5 * 60;
+
+/// Longest TWAP window a feed can be registered with
+pub const MAX_TWAP_WINDOW: i64 = 24 * 60 * 60;
+
+/// How long a published ARU price stays usable
+pub const MAX_ARU_PRICE_AGE: i64 = 60 * 60;
+
+/// Observations kept in a CP-Swap ObservationState ring
+pub const OBSERVATION_NUM: usize = 100;
+
+/// ARU market price feed for ARS
+///
+/// register_aru_price_feed points the feed at a Raydium CP-Swap ARU pool.
+/// The pool's observation account keeps a ring of cumulative prices that
+/// CP-Swap updates on swaps; update_aru_price reads the newest observation
+/// and the newest one at least twap_window older, and publishes the time
+/// weighted average price of ARU in the pool's other token. Manipulating it
+/// means holding the pool price off for the whole window.
+///
+/// Pool layout: [discriminator: 8][amm_config, pool_creator, token_0_vault,
+/// token_1_vault, lp_mint, token_0_mint, token_1_mint, token_0_program,
+/// token_1_program, observation_key: Pubkey]...
+/// Observation layout: [discriminator: 8][initialized: u8]
+/// [observation_index: u16][pool_id: Pubkey][(block_timestamp: u64,
+/// cumulative_token_0_price_x32: u128, cumulative_token_1_price_x32: u128);
+/// OBSERVATION_NUM], all little-endian and packed.
+
+const POOL_TOKEN_0_MINT_OFFSET: usize = 8 + 32 * 5;
+const POOL_TOKEN_1_MINT_OFFSET: usize = POOL_TOKEN_0_MINT_OFFSET + 32;
+const POOL_OBSERVATION_KEY_OFFSET: usize = 8 + 32 * 9;
+
+const OBSERVATION_INDEX_OFFSET: usize = 8 + 1;
+const OBSERVATION_POOL_OFFSET: usize = OBSERVATION_INDEX_OFFSET + 2;
+const OBSERVATIONS_OFFSET: usize = OBSERVATION_POOL_OFFSET + 32;
+const OBSERVATION_SIZE: usize = 8 + 16 + 16;
+
+/// CP-Swap pool accounts a feed needs, read from the pool state
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CpmmPool {
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub observation_key: Pubkey,
+}
+
+/// One entry of a CP-Swap observation ring
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PoolObservation {
+    pub block_timestamp: u64,
+    /// Sum of token_0's price in token_1 (Q32.32) times elapsed seconds
+    pub cumulative_token_0_price_x32: u128,
+    /// Sum of token_1's price in token_0 (Q32.32) times elapsed seconds
+    pub cumulative_token_1_price_x32: u128,
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey> {
+    let bytes: [u8; 32] = data
+        .get(offset..offset + 32)
+        .and_then(|b| b.try_into().ok())
+        .ok_or(ErrorCode::InvalidPriceSource)?;
+    Ok(Pubkey::new_from_array(bytes))
+}
+
+/// Parse a CP-Swap pool state account
+pub fn read_cpmm_pool(pool: &AccountInfo) -> Result<CpmmPool> {
+    let raydium_id: Pubkey = RAYDIUM_CPMM_PROGRAM_ID.into();
+    require_keys_eq!(*pool.owner, raydium_id, ErrorCode::InvalidPriceSource);
+
+    let data = pool.try_borrow_data()?;
+    Ok(CpmmPool {
+        token_0_mint: read_pubkey(&data, POOL_TOKEN_0_MINT_OFFSET)?,
+        token_1_mint: read_pubkey(&data, POOL_TOKEN_1_MINT_OFFSET)?,
+        observation_key: read_pubkey(&data, POOL_OBSERVATION_KEY_OFFSET)?,
+    })
+}
+
+/// Parse a CP-Swap observation account of `pool`, returning its ring and
+/// the index of the newest entry
+pub fn read_observations(
+    observation_state: &AccountInfo,
+    pool: &Pubkey,
+) -> Result<(Vec<PoolObservation>, usize)> {
+    let raydium_id: Pubkey = RAYDIUM_CPMM_PROGRAM_ID.into();
+    require_keys_eq!(*observation_state.owner, raydium_id, ErrorCode::InvalidPriceSource);
+
+    let data = observation_state.try_borrow_data()?;
+    require!(
+        data.len() >= OBSERVATIONS_OFFSET + OBSERVATION_SIZE * OBSERVATION_NUM,
+        ErrorCode::InvalidPriceSource
+    );
+    require_keys_eq!(
+        read_pubkey(&data, OBSERVATION_POOL_OFFSET)?,
+        *pool,
+        ErrorCode::InvalidPriceSource
+    );
+
+    let index = u16::from_le_bytes([
+        data[OBSERVATION_INDEX_OFFSET],
+        data[OBSERVATION_INDEX_OFFSET + 1],
+    ]) as usize;
+    require!(index < OBSERVATION_NUM, ErrorCode::InvalidPriceSource);
+
+    let observations = data[OBSERVATIONS_OFFSET..]
+        .chunks_exact(OBSERVATION_SIZE)
+        .take(OBSERVATION_NUM)
+        .map(|entry| PoolObservation {
+            block_timestamp: u64::from_le_bytes(entry[0..8].try_into().unwrap()),
+            cumulative_token_0_price_x32: u128::from_le_bytes(entry[8..24].try_into().unwrap()),
+            cumulative_token_1_price_x32: u128::from_le_bytes(entry[24..40].try_into().unwrap()),
+        })
+        .collect();
+    Ok((observations, index))
+}
+
+/// Time weighted price of token_0 in token_1 (or of token_1 in token_0 if
+/// `token_0` is false) between the newest observation and the newest one
+/// at least `window` seconds older, as a Q32.32 raw-unit ratio. Returns the
+/// price and the span it covers.
+pub fn twap_x32(
+    observations: &[PoolObservation],
+    newest_index: usize,
+    window: i64,
+    token_0: bool,
+) -> Result<(u128, u64)> {
+    let newest = observations
+        .get(newest_index)
+        .filter(|o| o.block_timestamp > 0)
+        .ok_or(ErrorCode::TwapWindowUnavailable)?;
+    let cutoff = newest.block_timestamp.saturating_sub(window as u64);
+
+    // Walk back through the ring until an entry old enough is found
+    let oldest = (1..observations.len())
+        .map(|back| &observations[(newest_index + observations.len() - back) % observations.len()])
+        .take_while(|o| o.block_timestamp > 0 && o.block_timestamp < newest.block_timestamp)
+        .find(|o| o.block_timestamp <= cutoff)
+        .ok_or(ErrorCode::TwapWindowUnavailable)?;
+
+    let span = newest.block_timestamp - oldest.block_timestamp;
+    // Cumulative prices wrap on overflow
+    let delta = if token_0 {
+        newest.cumulative_token_0_price_x32.wrapping_sub(oldest.cumulative_token_0_price_x32)
+    } else {
+        newest.cumulative_token_1_price_x32.wrapping_sub(oldest.cumulative_token_1_price_x32)
+    };
+    Ok((delta / span as u128, span))
+}
+
+/// Convert a Q32.32 raw-unit ratio into quote units per ARU at
+/// ARU_PRICE_SCALE, given both mints' decimals
+pub fn scale_price_x32(price_x32: u128, aru_decimals: u8, quote_decimals: u8) -> Option<u64> {
+    let scaled = price_x32
+        .checked_mul(ARU_PRICE_SCALE as u128)?
+        .checked_mul(10u128.checked_pow(aru_decimals as u32)?)?
+        / 10u128.checked_pow(quote_decimals as u32)?;
+    u64::try_from(scaled >> 32).ok()
+}
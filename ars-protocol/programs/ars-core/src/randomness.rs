@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use solana_program::{pubkey, pubkey::Pubkey as SolanaPubkey};
+
+/// Switchboard On-Demand program ID (owner of randomness accounts)
+pub const SWITCHBOARD_PROGRAM_ID: SolanaPubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Slots to wait for a Switchboard reveal before falling back to slot hashes
+pub const RANDOMNESS_FALLBACK_SLOTS: u64 = 150;
+
+/// Entries kept by the SlotHashes sysvar
+pub const SLOT_HASHES_MAX_ENTRIES: u64 = 512;
+
+/// Largest committee draw_committee will select
+pub const MAX_COMMITTEE_SIZE: u8 = 16;
+
+/// Randomness module for ARS
+///
+/// Seeds committee selection and auction tie-breaks from a Switchboard
+/// randomness account. When the oracle fails to reveal within
+/// RANDOMNESS_FALLBACK_SLOTS, the seed is derived from the SlotHashes sysvar
+/// instead and flagged as a fallback so consumers can treat it as weaker.
+/// The fallback slot is fixed when the request is made, so the seed does not
+/// depend on when consume_randomness is called.
+///
+/// The committee candidates are committed with the request (candidates_hash)
+/// before anyone can know the seed; draw_committee then selects from exactly
+/// that set.
+
+/// Read the revealed value from a Switchboard randomness account.
+/// Returns None if the account has not been revealed for the committed slot.
+pub fn read_switchboard_value(
+    randomness_account: &AccountInfo,
+    clock: &Clock,
+) -> Option<[u8; 32]> {
+    let sb_id: Pubkey = SWITCHBOARD_PROGRAM_ID.into();
+    if *randomness_account.owner != sb_id {
+        return None;
+    }
+
+    let data = randomness_account.try_borrow_data().ok()?;
+    let randomness = switchboard_on_demand::RandomnessAccountData::parse(data).ok()?;
+    randomness.get_value(clock).ok()
+}
+
+/// Slot in which a Switchboard randomness account was committed
+pub fn switchboard_seed_slot(randomness_account: &AccountInfo) -> Result<u64> {
+    let sb_id: Pubkey = SWITCHBOARD_PROGRAM_ID.into();
+    require!(
+        *randomness_account.owner == sb_id,
+        crate::errors::ErrorCode::InvalidRandomnessAccount
+    );
+
+    let data = randomness_account.try_borrow_data()?;
+    let randomness = switchboard_on_demand::RandomnessAccountData::parse(data)
+        .map_err(|_| error!(crate::errors::ErrorCode::InvalidRandomnessAccount))?;
+    Ok(randomness.seed_slot)
+}
+
+/// Hash of the first slot at or after `target_slot` in raw SlotHashes data.
+/// SlotHashes layout: [len: u64][(slot: u64, hash: [u8; 32]); len], newest
+/// first. Returns None until that slot is known, or once it has aged out of
+/// the sysvar (no older entry left to prove nothing was skipped before it).
+pub fn slot_hash_at(data: &[u8], target_slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(data.get(..8)?.try_into().ok()?) as usize;
+    let mut candidate = None;
+
+    for entry in data.get(8..)?.chunks_exact(40).take(len) {
+        let slot = u64::from_le_bytes(entry[..8].try_into().ok()?);
+        let hash: [u8; 32] = entry[8..].try_into().ok()?;
+        if slot == target_slot {
+            return Some(hash);
+        }
+        if slot < target_slot {
+            return candidate;
+        }
+        candidate = Some(hash);
+    }
+
+    None
+}
+
+/// Derive a fallback seed from the SlotHashes entry of the slot fixed at
+/// request time
+pub fn slot_hashes_seed(slot_hashes: &AccountInfo, target_slot: u64, nonce: u64) -> Result<[u8; 32]> {
+    require!(
+        slot_hashes.key() == anchor_lang::solana_program::sysvar::slot_hashes::ID,
+        crate::errors::ErrorCode::InvalidRandomnessAccount
+    );
+
+    let data = slot_hashes.try_borrow_data()?;
+    let hash = slot_hash_at(&data, target_slot)
+        .ok_or(crate::errors::ErrorCode::RandomnessUnavailable)?;
+
+    Ok(hashv(&[&hash, &nonce.to_le_bytes()]).to_bytes())
+}
+
+/// Commitment to an ordered committee candidate list
+pub fn candidates_hash(candidates: &[Pubkey]) -> [u8; 32] {
+    let keys: Vec<&[u8]> = candidates.iter().map(|key| key.as_ref()).collect();
+    hashv(&keys).to_bytes()
+}
+
+/// Deterministically pick `k` distinct indices out of `n` candidates
+/// (partial Fisher-Yates driven by the seed)
+pub fn select_committee(seed: &[u8; 32], n: usize, k: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..n).collect();
+    let k = k.min(n);
+
+    for i in 0..k {
+        let draw = hashv(&[seed, &(i as u64).to_le_bytes()]).to_bytes();
+        let r = u64::from_le_bytes(draw[..8].try_into().unwrap());
+        let j = i + (r % (n - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+
+    indices.truncate(k);
+    indices
+}
+
+/// Break a tie between two candidates: returns true if `a` wins
+pub fn tie_break(seed: &[u8; 32], a: &Pubkey, b: &Pubkey) -> bool {
+    let score_a = hashv(&[seed, a.as_ref()]).to_bytes();
+    let score_b = hashv(&[seed, b.as_ref()]).to_bytes();
+    score_a < score_b
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+use crate::errors::ErrorCode;
+use crate::state::GlobalState;
+
+/// SPL Governance (Realms) adapter for ARS
+///
+/// A DAO that already governs itself on Realms can be registered as a second
+/// authority for parameter updates and reserve rebalances. Its proposals
+/// execute instructions signed by the DAO's governance PDA; ARS accepts that
+/// signer only if it is the registered governance account and is owned by the
+/// registered governance program, so a keypair or another program's PDA
+/// cannot pose as the DAO.
+
+/// Canonical SPL Governance program used by the Realms UI. DAOs on their own
+/// governance deployment register that program id instead.
+pub const SPL_GOVERNANCE_PROGRAM_ID: Pubkey = pubkey!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+
+impl GlobalState {
+    /// Check that `governance` is the registered Realms governance account,
+    /// owned by the registered governance program, and signed
+    pub fn verify_realms_governance(&self, governance: &AccountInfo) -> Result<()> {
+        let registered = self.realms_governance.ok_or(ErrorCode::InvalidRealmsGovernance)?;
+        require!(
+            governance.is_signer
+                && governance.key() == registered
+                && *governance.owner == self.realms_program,
+            ErrorCode::InvalidRealmsGovernance
+        );
+        Ok(())
+    }
+}
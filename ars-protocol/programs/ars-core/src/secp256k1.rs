@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    secp256k1_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::errors::ErrorCode;
+use crate::signing::check_agent_message_format;
+
+/// Secp256k1 verification for EVM-keyed agents
+///
+/// The secp256k1 precompile recovers the signer of keccak256(message) and
+/// compares it to a 20-byte Ethereum address. Agents sign the same bytes as
+/// Ed25519 agents (see signing.rs) with their EVM key, without the EIP-191
+/// personal_sign prefix.
+
+/// Size of the secp256k1 instruction header (count)
+const HEADER_LEN: usize = 1;
+
+/// Size of one SecpSignatureOffsets entry
+const OFFSETS_LEN: usize = 11;
+
+/// One signature checked by a secp256k1 precompile instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Signature {
+    pub eth_address: [u8; 20],
+    pub message: Vec<u8>,
+}
+
+/// Resolve `len` bytes at `offset` in instruction `instruction_index`
+/// (`own_index` is the precompile instruction, whose data is already loaded)
+fn referenced_bytes(
+    instructions_sysvar: &AccountInfo,
+    own_index: usize,
+    own_data: &[u8],
+    instruction_index: u8,
+    offset: usize,
+    len: usize,
+) -> Result<Vec<u8>> {
+    let end = offset.checked_add(len).ok_or(ErrorCode::InvalidSignature)?;
+
+    if instruction_index as usize == own_index {
+        return Ok(own_data
+            .get(offset..end)
+            .ok_or(ErrorCode::InvalidSignature)?
+            .to_vec());
+    }
+
+    let ix = load_instruction_at_checked(instruction_index as usize, instructions_sysvar)
+        .map_err(|_| error!(ErrorCode::InvalidSignature))?;
+    Ok(ix
+        .data
+        .get(offset..end)
+        .ok_or(ErrorCode::InvalidSignature)?
+        .to_vec())
+}
+
+/// Parse every address/message pair checked by the secp256k1 precompile
+/// instruction at `own_index`
+pub fn parse_secp256k1_ix(
+    instructions_sysvar: &AccountInfo,
+    own_index: usize,
+    data: &[u8],
+) -> Result<Vec<Secp256k1Signature>> {
+    require!(data.len() >= HEADER_LEN, ErrorCode::InvalidSignature);
+
+    let count = data[0] as usize;
+    require!(count > 0, ErrorCode::InvalidSignature);
+    require!(
+        data.len() >= HEADER_LEN + count * OFFSETS_LEN,
+        ErrorCode::InvalidSignature
+    );
+
+    let mut signatures = Vec::with_capacity(count);
+    for i in 0..count {
+        let entry = &data[HEADER_LEN + i * OFFSETS_LEN..HEADER_LEN + (i + 1) * OFFSETS_LEN];
+        let u16_at = |j: usize| u16::from_le_bytes([entry[j], entry[j + 1]]) as usize;
+
+        // signature_offset u16, signature_instruction_index u8,
+        // eth_address_offset u16, eth_address_instruction_index u8,
+        // message_data_offset u16, message_data_size u16, message_instruction_index u8
+        let eth_address = referenced_bytes(
+            instructions_sysvar,
+            own_index,
+            data,
+            entry[5],
+            u16_at(3),
+            20,
+        )?;
+        let message = referenced_bytes(
+            instructions_sysvar,
+            own_index,
+            data,
+            entry[10],
+            u16_at(6),
+            u16_at(8),
+        )?;
+
+        signatures.push(Secp256k1Signature {
+            eth_address: eth_address.try_into().unwrap(),
+            message,
+        });
+    }
+
+    Ok(signatures)
+}
+
+/// Every address/message pair checked by secp256k1 precompile instructions
+/// earlier in the transaction
+fn preceding_secp256k1_signatures(instructions_sysvar: &AccountInfo) -> Result<Vec<Secp256k1Signature>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::MissingSignatureInstruction);
+
+    let mut found_precompile = false;
+    let mut signatures = Vec::new();
+    for index in 0..current_index as usize {
+        let ix = load_instruction_at_checked(index, instructions_sysvar)?;
+        if ix.program_id != secp256k1_program::ID {
+            continue;
+        }
+        found_precompile = true;
+        signatures.extend(parse_secp256k1_ix(instructions_sysvar, index, &ix.data)?);
+    }
+
+    require!(found_precompile, ErrorCode::MissingSignatureInstruction);
+    Ok(signatures)
+}
+
+/// Verify that a secp256k1 precompile instruction earlier in the transaction
+/// checked `expected_message` signed by `expected_address`.
+///
+/// The precompile performs the signature recovery (the transaction fails if
+/// any signature is invalid); this helper only confirms the verified
+/// address/message pair is the one the program expects.
+pub fn verify_secp256k1_ix(
+    instructions_sysvar: &AccountInfo,
+    expected_address: &[u8; 20],
+    expected_message: &[u8],
+) -> Result<()> {
+    let matched = preceding_secp256k1_signatures(instructions_sysvar)?
+        .iter()
+        .any(|sig| sig.eth_address == *expected_address && sig.message == expected_message);
+    require!(matched, ErrorCode::InvalidSignature);
+    Ok(())
+}
+
+/// Check that `signatures` include an agent message signed by `address`:
+/// either the current-format `message`, or `legacy_message` while
+/// check_agent_message_format still accepts legacy messages at `epoch`
+pub fn check_signed_agent_message(
+    signatures: &[Secp256k1Signature],
+    address: &[u8; 20],
+    message: &[u8],
+    legacy_message: &[u8],
+    epoch: u64,
+    legacy_cutoff_epoch: u64,
+) -> Result<()> {
+    let signed = [message, legacy_message]
+        .into_iter()
+        .find(|expected| {
+            signatures
+                .iter()
+                .any(|sig| sig.eth_address == *address && sig.message == *expected)
+        })
+        .ok_or(ErrorCode::InvalidSignature)?;
+    check_agent_message_format(signed, epoch, legacy_cutoff_epoch)
+}
+
+/// Verify a secp256k1-signed agent message in the transaction, accepting
+/// the legacy format until `legacy_cutoff_epoch` (see check_signed_agent_message)
+pub fn verify_secp256k1_agent_message(
+    instructions_sysvar: &AccountInfo,
+    address: &[u8; 20],
+    message: &[u8],
+    legacy_message: &[u8],
+    epoch: u64,
+    legacy_cutoff_epoch: u64,
+) -> Result<()> {
+    let signatures = preceding_secp256k1_signatures(instructions_sysvar)?;
+    check_signed_agent_message(
+        &signatures,
+        address,
+        message,
+        legacy_message,
+        epoch,
+        legacy_cutoff_epoch,
+    )
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Agent message formats
+///
+/// Agents sign these messages off-chain; the program rebuilds the same bytes
+/// and checks them against the Ed25519 instruction in the transaction.
+/// Canonical test vectors live in test-vectors/agent_messages.json and must
+/// be regenerated whenever a format changes.
+
+/// Domain separator prefixed to every agent message
+pub const AGENT_MESSAGE_DOMAIN: &[u8] = b"ARS_AGENT_MSG";
+
+/// Current agent message format version
+pub const AGENT_MESSAGE_VERSION: u8 = 1;
+
+/// Epochs after initialization during which legacy messages are still accepted
+pub const LEGACY_MESSAGE_GRACE_EPOCHS: u64 = 4;
+
+/// Oldest signed ILI submission timestamp accepted, relative to the clock
+pub const MAX_SIGNED_SUBMISSION_AGE: i64 = 5 * 60;
+
+/// Build an agent message
+///
+/// Layout: domain || version || field count (u16 LE) || action tag and each
+/// field, every one prefixed with its length (u16 LE). The length prefixes
+/// make the encoding injective, so no two (action, fields) inputs share bytes.
+pub fn create_agent_message(action: &[u8], fields: &[&[u8]]) -> Vec<u8> {
+    let len = AGENT_MESSAGE_DOMAIN.len()
+        + 1
+        + 2
+        + 2 * (fields.len() + 1)
+        + action.len()
+        + fields.iter().map(|f| f.len()).sum::<usize>();
+    let mut message = Vec::with_capacity(len);
+    message.extend_from_slice(AGENT_MESSAGE_DOMAIN);
+    message.push(AGENT_MESSAGE_VERSION);
+    message.extend_from_slice(&(fields.len() as u16).to_le_bytes());
+    for part in std::iter::once(action).chain(fields.iter().copied()) {
+        message.extend_from_slice(&(part.len() as u16).to_le_bytes());
+        message.extend_from_slice(part);
+    }
+    message
+}
+
+/// Build a pre-versioning agent message: action tag followed by the raw field bytes
+pub fn create_legacy_agent_message(action: &[u8], fields: &[&[u8]]) -> Vec<u8> {
+    let len = action.len() + fields.iter().map(|f| f.len()).sum::<usize>();
+    let mut message = Vec::with_capacity(len);
+    message.extend_from_slice(action);
+    for field in fields {
+        message.extend_from_slice(field);
+    }
+    message
+}
+
+/// Reject messages the program should no longer accept at `epoch`.
+/// Versioned messages must carry the current version; legacy messages are
+/// only accepted before `legacy_cutoff_epoch`.
+pub fn check_agent_message_format(
+    message: &[u8],
+    epoch: u64,
+    legacy_cutoff_epoch: u64,
+) -> Result<()> {
+    match message.strip_prefix(AGENT_MESSAGE_DOMAIN) {
+        Some(rest) => require!(
+            rest.first() == Some(&AGENT_MESSAGE_VERSION),
+            ErrorCode::UnsupportedMessageVersion
+        ),
+        None => require!(
+            epoch < legacy_cutoff_epoch,
+            ErrorCode::LegacyMessageRejected
+        ),
+    }
+    Ok(())
+}
+
+/// Message signed for an ILI submission
+pub fn ili_update_message(agent: &Pubkey, ili_value: u64, timestamp: i64) -> Vec<u8> {
+    create_agent_message(
+        b"ili_update",
+        &[agent.as_ref(), &ili_value.to_le_bytes(), &timestamp.to_le_bytes()],
+    )
+}
+
+/// Message signed to authorize an ARU mint
+pub fn mint_message(agent: &Pubkey, amount: u64, epoch: u64) -> Vec<u8> {
+    create_agent_message(
+        b"mint",
+        &[agent.as_ref(), &amount.to_le_bytes(), &epoch.to_le_bytes()],
+    )
+}
+
+/// Message signed to authorize an ARU burn
+pub fn burn_message(agent: &Pubkey, amount: u64, epoch: u64) -> Vec<u8> {
+    create_agent_message(
+        b"burn",
+        &[agent.as_ref(), &amount.to_le_bytes(), &epoch.to_le_bytes()],
+    )
+}
+
+/// Message signed for a proposal vote
+pub fn vote_message(agent: &Pubkey, proposal_id: u64, vote_yes: bool, stake_amount: u64) -> Vec<u8> {
+    create_agent_message(
+        b"vote",
+        &[
+            agent.as_ref(),
+            &proposal_id.to_le_bytes(),
+            &[vote_yes as u8],
+            &stake_amount.to_le_bytes(),
+        ],
+    )
+}
+
+/// Pre-versioning message builders, accepted until the legacy cutoff epoch
+pub mod legacy {
+    use super::*;
+
+    pub fn ili_update_message(agent: &Pubkey, ili_value: u64, timestamp: i64) -> Vec<u8> {
+        create_legacy_agent_message(
+            b"ili_update",
+            &[agent.as_ref(), &ili_value.to_le_bytes(), &timestamp.to_le_bytes()],
+        )
+    }
+
+    pub fn mint_message(agent: &Pubkey, amount: u64, epoch: u64) -> Vec<u8> {
+        create_legacy_agent_message(
+            b"mint",
+            &[agent.as_ref(), &amount.to_le_bytes(), &epoch.to_le_bytes()],
+        )
+    }
+
+    pub fn burn_message(agent: &Pubkey, amount: u64, epoch: u64) -> Vec<u8> {
+        create_legacy_agent_message(
+            b"burn",
+            &[agent.as_ref(), &amount.to_le_bytes(), &epoch.to_le_bytes()],
+        )
+    }
+
+    pub fn vote_message(agent: &Pubkey, proposal_id: u64, vote_yes: bool, stake_amount: u64) -> Vec<u8> {
+        create_legacy_agent_message(
+            b"vote",
+            &[
+                agent.as_ref(),
+                &proposal_id.to_le_bytes(),
+                &[vote_yes as u8],
+                &stake_amount.to_le_bytes(),
+            ],
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use ars_common::ACCOUNT_RESERVED_LEN;
+
+use crate::state::PolicyParams;
+
+/// Proposal simulation (devnet)
+///
+/// simulate_proposal applies a proposal's policy to copies of the ars-token
+/// supply and the ars-reserve vault figures and stores the projected supply
+/// and VHR in a scratch ProposalSimulation account per (proposal, simulator),
+/// without touching the live accounts. The projection is a model: minting or
+/// burning scales reserve liabilities with supply, and other policy types
+/// leave both figures unchanged. The stored result_hash commits to every
+/// input and output, so anyone can recompute it from the account, and a voter
+/// can attach it to their vote with attach_simulation.
+
+/// Scratch account holding one simulation of a proposal
+#[account]
+pub struct ProposalSimulation {
+    /// Proposal simulated
+    pub proposal: Pubkey,
+    /// Account that ran the simulation
+    pub simulator: Pubkey,
+    /// PolicyParams::hash of the simulated policy
+    pub params_hash: [u8; 32],
+    /// ARU supply before and after the policy
+    pub supply_before: u64,
+    pub supply_after: u64,
+    /// Reserve VHR (basis points) before and after the policy
+    pub vhr_before: u16,
+    pub vhr_after: u16,
+    /// Slot the snapshot was taken at
+    pub slot: u64,
+    /// simulation_hash of the fields above
+    pub result_hash: [u8; 32],
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl ProposalSimulation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // proposal
+        32 + // simulator
+        32 + // params_hash
+        8 + // supply_before
+        8 + // supply_after
+        2 + // vhr_before
+        2 + // vhr_after
+        8 + // slot
+        32 + // result_hash
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Projected (supply, VHR) after applying `policy_params` to the given
+/// supply, reserve value and liabilities
+pub fn project_policy(
+    policy_params: &PolicyParams,
+    supply: u64,
+    total_value: u64,
+    liabilities: u64,
+) -> (u64, u16) {
+    let supply_after = match policy_params {
+        PolicyParams::MintParams { amount, .. } => supply.saturating_add(*amount),
+        PolicyParams::BurnParams { amount } => supply.saturating_sub(*amount),
+        _ => supply,
+    };
+    let liabilities_after = if supply == 0 {
+        liabilities
+    } else {
+        (liabilities as u128 * supply_after as u128 / supply as u128) as u64
+    };
+    (supply_after, vhr(total_value, liabilities_after))
+}
+
+/// Reserve VHR in basis points, computed as ars-reserve does
+pub fn vhr(total_value: u64, liabilities: u64) -> u16 {
+    if liabilities == 0 {
+        return u16::MAX;
+    }
+    (total_value as u128 * 10_000 / liabilities as u128).min(u16::MAX as u128) as u16
+}
+
+/// Hash committing to a simulation's inputs and outputs
+pub fn simulation_hash(
+    proposal: &Pubkey,
+    params_hash: &[u8; 32],
+    supply_before: u64,
+    supply_after: u64,
+    vhr_before: u16,
+    vhr_after: u16,
+    slot: u64,
+) -> [u8; 32] {
+    hashv(&[
+        b"ars_simulation",
+        proposal.as_ref(),
+        params_hash,
+        &supply_before.to_le_bytes(),
+        &supply_after.to_le_bytes(),
+        &vhr_before.to_le_bytes(),
+        &vhr_after.to_le_bytes(),
+        &slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use ars_common::{ParamTable, ACCOUNT_RESERVED_LEN};
+
+use crate::market::OutcomeMarket;
+use crate::price_feed::MAX_ARU_PRICE_AGE;
 
 /// Global state for the ARS protocol with admin transfer and circuit breaker
 #[account]
@@ -33,11 +37,147 @@ pub struct GlobalState {
     pub proposal_counter: u64,
     /// Last update slot
     pub last_update_slot: u64,
+    /// First epoch in which legacy (unversioned) agent messages are rejected
+    pub legacy_message_cutoff_epoch: u64,
+    /// Minimum total quadratic voting power (yes + no) for a proposal to execute
+    pub quorum_power: u64,
+    /// Share of quadratic voting power that must vote yes, per PolicyType
+    /// (basis points, indexed by the PolicyType discriminant)
+    pub approval_threshold_bps: [u16; POLICY_TYPE_COUNT],
+    /// Keys allowed to veto passed proposals during the execution timelock
+    /// (first guardian_count entries are in use)
+    pub guardians: [Pubkey; MAX_GUARDIANS],
+    /// Number of appointed guardians
+    pub guardian_count: u8,
+    /// Value each ProtocolParam held before its last change, restored by an
+    /// emergency rollback (indexed by the ProtocolParam discriminant)
+    pub previous_params: [u64; PROTOCOL_PARAM_COUNT],
+    /// ARU in the stake escrow set aside for keeper rewards (funded by
+    /// fund_keeper_rewards and slashed keeper bonds)
+    pub keeper_reward_pool: u64,
+    /// Flat reward per vote on a resolved proposal that reached quorum
+    pub participation_reward: u64,
+    /// ARU in the stake escrow set aside for participation rewards
+    pub participation_reward_pool: u64,
+    /// Voting power multiplier at or below -reputation_span (basis points)
+    pub reputation_floor_bps: u16,
+    /// Voting power multiplier at or above reputation_span (basis points)
+    pub reputation_ceiling_bps: u16,
+    /// Reputation score at which the multiplier reaches the floor or ceiling
+    pub reputation_span: u32,
+    /// Voting power multiplier at 0% prediction accuracy (basis points)
+    pub accuracy_floor_bps: u16,
+    /// Voting power multiplier at 100% prediction accuracy (basis points)
+    pub accuracy_ceiling_bps: u16,
+    /// Number of an agent's most recent settled votes accuracy is measured
+    /// over
+    pub accuracy_window: u8,
+    /// How long limits stay tightened after the circuit breaker clears
+    pub breaker_cooldown_period: i64,
+    /// End of the current post-breaker cooldown (0 if none has run)
+    pub breaker_cooldown_ends: i64,
+    /// Deviation of the raw ILI from the smoothed ILI that counts as an
+    /// anomalous round (basis points, 0 disables the detector)
+    pub anomaly_deviation_bps: u16,
+    /// Consecutive anomalous rounds that trip the circuit breaker
+    pub anomaly_rounds: u8,
+    /// Anomalous rounds seen in a row so far
+    pub anomaly_streak: u8,
+    /// Oracle round (ILIOracle.last_update) last checked for an anomaly
+    pub anomaly_checked_round: i64,
+    /// Who may create each kind of proposal (indexed by the PolicyType
+    /// discriminant)
+    pub proposal_requirements: [ProposalRequirement; POLICY_TYPE_COUNT],
+    /// SPL Governance program owning realms_governance
+    pub realms_program: Pubkey,
+    /// Realms governance PDA allowed to update parameters and rebalance the
+    /// reserve (None disables the adapter)
+    pub realms_governance: Option<Pubkey>,
+    /// Whether `authority` is a multisig vault PDA (e.g. Squads). Disables the
+    /// paths where one guardian key acts alone: proposal vetoes and
+    /// guardian-confirmed slashes.
+    pub authority_is_multisig: bool,
+    /// Values queued by propose_param_update (indexed by the ProtocolParam
+    /// discriminant)
+    pub pending_params: [u64; PROTOCOL_PARAM_COUNT],
+    /// When each queued value may be applied (0 if none is queued)
+    pub pending_param_etas: [i64; PROTOCOL_PARAM_COUNT],
+    /// Smallest stake an agent may register with and stay active at
+    pub min_agent_stake: u64,
+    /// Smallest griefing deposit any proposal records, on top of the
+    /// per-type proposal_requirements deposit
+    pub min_griefing_deposit: u64,
+    /// Last time the authority signed an instruction
+    pub authority_last_active: i64,
+    /// Authority inactivity after which an executed AssumeAuthority
+    /// proposal may replace the authority
+    pub authority_inactivity_period: i64,
+    /// Time without a submission or heartbeat after which anyone may
+    /// deactivate an agent
+    pub agent_silence_period: i64,
     /// PDA bump
     pub bump: u8,
+    /// Reentrancy guard held by handlers while they make CPIs
+    pub locked: bool,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN - 1],
+}
+
+impl ars_common::Lockable for GlobalState {
+    fn locked(&self) -> bool {
+        self.locked
+    }
+
+    fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
 }
 
 impl GlobalState {
+    /// Epoch index containing `timestamp` (rolling epochs of epoch_duration)
+    pub fn epoch_at(&self, timestamp: i64) -> u64 {
+        if self.epoch_duration <= 0 || timestamp <= 0 {
+            return 0;
+        }
+        (timestamp / self.epoch_duration) as u64
+    }
+
+    /// Check a proposal's vote tally against the quorum and the approval
+    /// threshold for its policy type, or a conviction-mode proposal's
+    /// conviction against the conviction threshold
+    pub fn check_approval(&self, proposal: &PolicyProposal) -> Result<()> {
+        if proposal.conviction_mode {
+            require!(
+                proposal.conviction >= self.conviction_threshold(),
+                crate::errors::ErrorCode::ConvictionNotReached
+            );
+            return Ok(());
+        }
+        let total_power = proposal.quadratic_yes
+            .checked_add(proposal.quadratic_no)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        let quorum_power = if proposal.policy_type == PolicyType::Emergency {
+            self.quorum_power.saturating_mul(EMERGENCY_QUORUM_MULTIPLIER)
+        } else {
+            self.quorum_power
+        };
+        require!(
+            total_power > 0 && total_power >= quorum_power,
+            crate::errors::ErrorCode::QuorumNotReached
+        );
+        let threshold_bps = self.approval_threshold_bps[proposal.policy_type as usize];
+        require!(
+            (proposal.quadratic_yes as u128) * 10_000 >= (total_power as u128) * threshold_bps as u128,
+            crate::errors::ErrorCode::SupermajorityNotReached
+        );
+        Ok(())
+    }
+
+    /// Conviction a conviction-mode proposal needs to pass
+    pub fn conviction_threshold(&self) -> u64 {
+        self.quorum_power.saturating_mul(CONVICTION_THRESHOLD_QUORUMS)
+    }
+
     /// Calculate space needed for GlobalState account
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
@@ -55,11 +195,293 @@ impl GlobalState {
         1 + // min_agent_consensus
         8 + // proposal_counter
         8 + // last_update_slot
-        1; // bump
+        8 + // legacy_message_cutoff_epoch
+        8 + // quorum_power
+        2 * POLICY_TYPE_COUNT + // approval_threshold_bps
+        32 * MAX_GUARDIANS + // guardians
+        1 + // guardian_count
+        8 * PROTOCOL_PARAM_COUNT + // previous_params
+        8 + // keeper_reward_pool
+        8 + // participation_reward
+        8 + // participation_reward_pool
+        2 + // reputation_floor_bps
+        2 + // reputation_ceiling_bps
+        4 + // reputation_span
+        2 + // accuracy_floor_bps
+        2 + // accuracy_ceiling_bps
+        1 + // accuracy_window
+        8 + // breaker_cooldown_period
+        8 + // breaker_cooldown_ends
+        2 + // anomaly_deviation_bps
+        1 + // anomaly_rounds
+        1 + // anomaly_streak
+        8 + // anomaly_checked_round
+        ProposalRequirement::LEN * POLICY_TYPE_COUNT + // proposal_requirements
+        32 + // realms_program
+        (1 + 32) + // realms_governance (Option<Pubkey>)
+        1 + // authority_is_multisig
+        8 * PROTOCOL_PARAM_COUNT + // pending_params
+        8 * PROTOCOL_PARAM_COUNT + // pending_param_etas
+        8 + // min_agent_stake
+        8 + // min_griefing_deposit
+        8 + // authority_last_active
+        8 + // authority_inactivity_period
+        8 + // agent_silence_period
+        1 + // bump
+        1 + // locked
+        (ACCOUNT_RESERVED_LEN - 1); // _reserved
+
+    /// Check a proposer against the creation requirement for `policy_type`
+    /// and return the griefing deposit to record on the proposal
+    pub fn check_proposer(
+        &self,
+        policy_type: PolicyType,
+        agent_registry: Option<&AgentRegistry>,
+    ) -> Result<u64> {
+        let requirement = self.proposal_requirements[policy_type as usize];
+        if let Some(min_tier) = requirement.min_tier {
+            let agent_registry = agent_registry
+                .ok_or(crate::errors::ErrorCode::ProposerNotAgent)?;
+            require!(agent_registry.is_active, crate::errors::ErrorCode::AgentNotActive);
+            require!(
+                agent_registry.agent_tier >= min_tier,
+                crate::errors::ErrorCode::InsufficientTier
+            );
+            require!(
+                agent_registry.reputation_score >= requirement.min_reputation,
+                crate::errors::ErrorCode::InsufficientReputation
+            );
+        }
+        Ok(requirement.deposit.max(self.min_griefing_deposit))
+    }
+
+    /// Require `signer` to be the authority and record the activity that
+    /// resets the inactivity clock
+    pub fn authorize(&mut self, signer: Pubkey) -> Result<()> {
+        require!(signer == self.authority, crate::errors::ErrorCode::Unauthorized);
+        self.authority_last_active = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether the authority has not signed anything for
+    /// authority_inactivity_period at `now`
+    pub fn authority_inactive(&self, now: i64) -> bool {
+        now.saturating_sub(self.authority_last_active) >= self.authority_inactivity_period
+    }
+
+    /// Whether the post-breaker cooldown is still running at `now`
+    pub fn in_breaker_cooldown(&self, now: i64) -> bool {
+        now < self.breaker_cooldown_ends
+    }
+
+    /// Whether `key` is one of the appointed guardians
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        self.guardians[..self.guardian_count as usize].contains(key)
+    }
+
+    /// Quadratic voting power of `stake`, scaled by the voter's reputation
+    /// multiplier and by their prediction accuracy multiplier (1x when
+    /// `accuracy_bps` is None)
+    pub fn voting_power(&self, stake: u64, reputation: i32, accuracy_bps: Option<u64>) -> u64 {
+        let multiplier = reputation_multiplier_bps(
+            reputation,
+            self.reputation_floor_bps,
+            self.reputation_ceiling_bps,
+            self.reputation_span,
+        );
+        let accuracy_multiplier = accuracy_bps.map_or(10_000, |accuracy| {
+            accuracy_multiplier_bps(accuracy, self.accuracy_floor_bps, self.accuracy_ceiling_bps)
+        });
+        ((stake as f64).sqrt() as u64 as u128 * multiplier as u128 / 10_000
+            * accuracy_multiplier as u128
+            / 10_000) as u64
+    }
 }
 
-/// Agent tier based on stake amount
+/// Voting power multiplier for a reputation score (basis points): 1x at zero,
+/// moving linearly to `ceiling_bps` at `span` and to `floor_bps` at `-span`,
+/// and flat beyond
+pub fn reputation_multiplier_bps(reputation: i32, floor_bps: u16, ceiling_bps: u16, span: u32) -> u64 {
+    let span = span.max(1) as i64;
+    let reputation = (reputation as i64).clamp(-span, span);
+    let (base, floor, ceiling) = (10_000i64, floor_bps as i64, ceiling_bps as i64);
+    let multiplier = if reputation >= 0 {
+        base + (ceiling - base) * reputation / span
+    } else {
+        base - (base - floor) * -reputation / span
+    };
+    multiplier as u64
+}
+
+/// Voting power multiplier for a prediction accuracy (basis points): linear
+/// from `floor_bps` at 0% to `ceiling_bps` at 100%
+pub fn accuracy_multiplier_bps(accuracy_bps: u64, floor_bps: u16, ceiling_bps: u16) -> u64 {
+    let accuracy = accuracy_bps.min(10_000) as i64;
+    let (floor, ceiling) = (floor_bps as i64, ceiling_bps as i64);
+    (floor + (ceiling - floor) * accuracy / 10_000) as u64
+}
+
+/// Maximum number of guardians that can hold the veto role
+pub const MAX_GUARDIANS: usize = 5;
+
+/// Upper bound on the per-vote participation reward (1 ARU)
+pub const MAX_PARTICIPATION_REWARD: u64 = 1_000_000;
+
+/// Default quorum: the voting power of three minimum-stake (100 ARU) agents
+pub const DEFAULT_QUORUM_POWER: u64 = 30_000;
+
+/// Default approval thresholds in PolicyType order: MintARU, BurnARU,
+/// UpdateParameters, RebalanceVault, OverrideILI, Emergency, SlashAgent,
+/// TreasurySpend
+pub const DEFAULT_APPROVAL_THRESHOLD_BPS: [u16; POLICY_TYPE_COUNT] =
+    [6_600, 6_000, 6_000, 5_001, 6_667, 6_667, 6_667, 6_600];
+
+/// Default minimum agent stake (100 ARU)
+pub const DEFAULT_MIN_AGENT_STAKE: u64 = 100_000_000;
+
+/// Griefing protection deposit of most proposals (10 ARU)
+pub const DEFAULT_GRIEFING_DEPOSIT: u64 = 10_000_000;
+
+/// Griefing protection deposit of supply-changing proposals (100 ARU)
+pub const SUPPLY_CHANGE_GRIEFING_DEPOSIT: u64 = 100_000_000;
+
+/// Who may create proposals of one PolicyType
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposalRequirement {
+    /// Lowest tier of the proposer's active agent registration, or None to
+    /// let anyone propose
+    pub min_tier: Option<AgentTier>,
+    /// Lowest proposer reputation (only checked when min_tier is set)
+    pub min_reputation: i32,
+    /// Griefing protection deposit recorded on the proposal
+    pub deposit: u64,
+}
+
+impl ProposalRequirement {
+    pub const LEN: usize = (1 + 1) + // min_tier (Option<AgentTier>)
+        4 + // min_reputation
+        8; // deposit
+
+    const fn new(min_tier: Option<AgentTier>, deposit: u64) -> Self {
+        Self { min_tier, min_reputation: 0, deposit }
+    }
+}
+
+/// Default creation requirements in PolicyType order. Supply changes and
+/// emergencies need a Gold agent (as create_emergency_proposal always
+/// requires); supply changes and treasury spends record a larger deposit.
+/// Governance tightens the rest per deployment.
+pub const DEFAULT_PROPOSAL_REQUIREMENTS: [ProposalRequirement; POLICY_TYPE_COUNT] = [
+    ProposalRequirement::new(Some(AgentTier::Gold), SUPPLY_CHANGE_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(Some(AgentTier::Gold), SUPPLY_CHANGE_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(None, DEFAULT_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(None, DEFAULT_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(None, DEFAULT_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(Some(AgentTier::Gold), DEFAULT_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(None, DEFAULT_GRIEFING_DEPOSIT),
+    ProposalRequirement::new(None, SUPPLY_CHANGE_GRIEFING_DEPOSIT),
+];
+
+/// Voting window of an emergency proposal (30 minutes)
+pub const EMERGENCY_VOTING_PERIOD: i64 = 30 * 60;
+
+/// Emergency proposals need this multiple of the normal quorum
+pub const EMERGENCY_QUORUM_MULTIPLIER: u64 = 2;
+
+/// How long a freshly triggered circuit breaker stays engaged (24 hours)
+pub const CIRCUIT_BREAKER_TIMELOCK: i64 = 24 * 60 * 60;
+
+/// Default raw vs smoothed ILI deviation counted as anomalous (20%)
+pub const DEFAULT_ANOMALY_DEVIATION_BPS: u16 = 2_000;
+
+/// Default number of consecutive anomalous rounds that trip the breaker
+pub const DEFAULT_ANOMALY_ROUNDS: u8 = 3;
+
+/// Largest configurable number of anomalous rounds
+pub const MAX_ANOMALY_ROUNDS: u8 = 10;
+
+/// How far an emergency pause pushes out the circuit breaker timelock
+/// (24 hours, the same as a fresh trigger)
+pub const EMERGENCY_PAUSE_EXTENSION: i64 = 24 * 60 * 60;
+
+/// Challenge period of an optimistic proposal (3 days)
+pub const OPTIMISTIC_CHALLENGE_PERIOD: i64 = 3 * 24 * 60 * 60;
+
+/// How long a conviction proposal can gather conviction (30 days)
+pub const CONVICTION_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Interval conviction accrues in (1 hour)
+pub const CONVICTION_STEP: i64 = 60 * 60;
+
+/// Share of conviction kept from one step to the next (basis points). At
+/// 90% steady support S converges on 10 × S, half of it within ~7 steps.
+pub const CONVICTION_DECAY_BPS: u64 = 9_000;
+
+/// Conviction threshold in multiples of quorum_power: net support held at
+/// the quorum crosses it after about seven hours, half the quorum never does
+pub const CONVICTION_THRESHOLD_QUORUMS: u64 = 5;
+
+/// Default cooldown after the circuit breaker clears (24 hours)
+pub const DEFAULT_BREAKER_COOLDOWN: i64 = 24 * 60 * 60;
+
+/// Longest configurable post-breaker cooldown (7 days)
+pub const MAX_BREAKER_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// Mint or burn proposals above this share of supply cannot execute during
+/// the post-breaker cooldown (basis points)
+pub const COOLDOWN_MAX_SUPPLY_CHANGE_BPS: u64 = 100;
+
+/// Whether minting or burning `amount` is too large to execute during the
+/// post-breaker cooldown
+pub fn is_large_supply_change(amount: u64, total_supply: u64) -> bool {
+    amount as u128 * 10_000 > total_supply as u128 * COOLDOWN_MAX_SUPPLY_CHANGE_BPS as u128
+}
+
+/// Default reputation multiplier floor (0.5x)
+pub const DEFAULT_REPUTATION_FLOOR_BPS: u16 = 5_000;
+
+/// Default reputation multiplier ceiling (1.5x)
+pub const DEFAULT_REPUTATION_CEILING_BPS: u16 = 15_000;
+
+/// Default reputation score at which the multiplier saturates
+pub const DEFAULT_REPUTATION_SPAN: u32 = 1_000;
+
+/// Default accuracy multiplier floor (0.5x)
+pub const DEFAULT_ACCURACY_FLOOR_BPS: u16 = 5_000;
+
+/// Default accuracy multiplier ceiling (1.5x)
+pub const DEFAULT_ACCURACY_CEILING_BPS: u16 = 15_000;
+
+/// Default number of settled votes accuracy is measured over
+pub const DEFAULT_ACCURACY_WINDOW: u8 = 20;
+
+/// Settled votes an agent needs in the window before accuracy affects their
+/// voting power
+pub const MIN_ACCURACY_SAMPLES: u8 = 5;
+
+/// Settled votes AgentStats remembers (bits in prediction_history)
+pub const MAX_ACCURACY_WINDOW: u8 = 32;
+
+/// NO stake that blocks an optimistic proposal (1,000 ARU)
+pub const OPTIMISTIC_CHALLENGE_STAKE: u64 = 1_000_000_000;
+
+/// Shortest voting window of a regular proposal (24 hours)
+pub const MIN_VOTING_PERIOD: i64 = 24 * 60 * 60;
+
+/// Longest voting window of any proposal (7 days)
+pub const MAX_VOTING_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Shortest voting window of a fast-tracked proposal (6 hours)
+pub const FAST_TRACK_MIN_VOTING_PERIOD: i64 = 6 * 60 * 60;
+
+/// Reputation the proposer and every co-sponsor of a fast-tracked proposal need
+pub const FAST_TRACK_MIN_REPUTATION: i32 = 500;
+
+/// Distinct co-sponsors, besides the proposer, a fast-tracked proposal needs
+pub const FAST_TRACK_COSPONSORS: usize = 2;
+
+/// Agent tier based on stake amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum AgentTier {
     /// 100-999 ARU staked
     Bronze,
@@ -111,10 +533,53 @@ pub struct AgentRegistry {
     pub registered_at: i64,
     /// Last activity timestamp
     pub last_active: i64,
+    /// Timestamp of the agent's last ILI submission
+    pub last_submission: i64,
     /// Active status flag
     pub is_active: bool,
+    /// Key type the agent signs ILI submissions with
+    pub key_type: AgentKeyType,
+    /// Ethereum address of the agent's secp256k1 key (zero for Ed25519 agents)
+    pub evm_address: [u8; 20],
+    /// Stake currently delegated to another agent's voting power
+    pub delegated_out: u64,
+    /// Last time the agent revoked a delegation (0 if never)
+    pub last_revoked_at: i64,
+    /// Number of slashes applied, indexing the agent's SlashRecords
+    pub slash_count: u64,
+    /// Stake requested for withdrawal, still slashable until it is withdrawn
+    pub pending_unstake: u64,
+    /// When the pending unstake can be withdrawn
+    pub unstake_available_at: i64,
+    /// ARU token holders have delegated to this agent's stake
+    pub holder_stake: u64,
+    /// Vote rewards earned per unit of holder stake, scaled by
+    /// REWARD_PER_SHARE_SCALE
+    pub holder_reward_per_share: u128,
+    /// Share of holder rewards the agent keeps (basis points)
+    pub commission_bps: u16,
+    /// Commission taking effect at commission_effective_at
+    pub pending_commission_bps: u16,
+    /// When pending_commission_bps replaces commission_bps; 0 if none pending
+    pub commission_effective_at: i64,
+    /// Until when the agent's votes can still be settled (and slashed)
+    pub vote_lock_until: i64,
+    /// When an exiting agent can withdraw its stake; 0 if not exiting
+    pub exit_available_at: i64,
+    /// Restake vote rewards into stake_amount instead of paying them out
+    pub compound_rewards: bool,
+    /// Hot key allowed to submit ILI values and vote for the agent; stake,
+    /// rewards and slashing stay with agent_pubkey (zero if none)
+    pub operator_pubkey: Pubkey,
+    /// Stake held back for proposed slashes not yet executed or dismissed
+    pub pending_slash: u64,
     /// PDA bump
     pub bump: u8,
+    /// Timestamp of the agent's last relayed secp256k1 submission; each
+    /// signed message must carry a later one
+    pub last_signed_timestamp: i64,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN - 8],
 }
 
 impl AgentRegistry {
@@ -129,8 +594,434 @@ impl AgentRegistry {
         8 + // slashed_amount
         8 + // registered_at
         8 + // last_active
+        8 + // last_submission
         1 + // is_active
-        1; // bump
+        1 + // key_type (enum)
+        20 + // evm_address
+        8 + // delegated_out
+        8 + // last_revoked_at
+        8 + // slash_count
+        8 + // pending_unstake
+        8 + // unstake_available_at
+        8 + // holder_stake
+        16 + // holder_reward_per_share
+        2 + // commission_bps
+        2 + // pending_commission_bps
+        8 + // commission_effective_at
+        8 + // vote_lock_until
+        8 + // exit_available_at
+        1 + // compound_rewards
+        32 + // operator_pubkey
+        8 + // pending_slash
+        1 + // bump
+        8 + // last_signed_timestamp
+        (ACCOUNT_RESERVED_LEN - 8); // _reserved
+
+    /// Whether `key` may submit and vote for the agent: the owner key or
+    /// its operator
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        *key == self.agent_pubkey
+            || (self.operator_pubkey != Pubkey::default() && *key == self.operator_pubkey)
+    }
+
+    /// Stake the agent can still vote with itself, including holder stake
+    pub fn available_stake(&self) -> u64 {
+        self.stake_amount
+            .saturating_add(self.holder_stake)
+            .saturating_sub(self.delegated_out)
+            .saturating_sub(self.pending_unstake)
+    }
+
+    /// Stake that stays bonded once the pending unstake is withdrawn
+    pub fn bonded_stake(&self) -> u64 {
+        self.stake_amount.saturating_sub(self.pending_unstake)
+    }
+
+    /// Recompute the tier from bonded and holder stake
+    pub fn refresh_tier(&mut self) {
+        self.agent_tier = AgentTier::from_stake(self.bonded_stake().saturating_add(self.holder_stake));
+    }
+
+    /// Score one ILI submission against its round's consensus median
+    pub fn record_consensus(&mut self, accurate: bool) {
+        if accurate {
+            self.successful_updates = self.successful_updates.saturating_add(1);
+            self.reputation_score = self.reputation_score.saturating_add(CONSENSUS_REPUTATION_REWARD);
+        } else {
+            self.reputation_score = self.reputation_score.saturating_sub(OUTLIER_REPUTATION_PENALTY);
+        }
+    }
+
+    /// Whether the agent has been silent for at least `silence_period` at
+    /// `now`
+    pub fn is_stale(&self, now: i64, silence_period: i64) -> bool {
+        now.saturating_sub(self.last_active) >= silence_period
+    }
+
+    /// Keep the agent from exiting while a vote on a proposal ending at
+    /// `end_time` can still be settled
+    pub fn lock_votes_until(&mut self, end_time: i64) {
+        self.vote_lock_until = self.vote_lock_until
+            .max(end_time.saturating_add(PROPOSAL_RETENTION));
+    }
+
+    /// Apply a scheduled commission change whose notice period has passed.
+    /// Returns the replaced commission if one was applied.
+    pub fn apply_pending_commission(&mut self, now: i64) -> Option<u16> {
+        if self.commission_effective_at == 0 || now < self.commission_effective_at {
+            return None;
+        }
+        let old_bps = self.commission_bps;
+        self.commission_bps = self.pending_commission_bps;
+        self.pending_commission_bps = 0;
+        self.commission_effective_at = 0;
+        Some(old_bps)
+    }
+
+    /// Split a vote reward pro rata between the agent's own stake and holder
+    /// stake. The agent keeps commission_bps of the holders' part and the rest
+    /// is credited to holder_reward_per_share, staying in the stake escrow
+    /// until claimed. Returns (holders' net part, commission).
+    pub fn credit_holders(&mut self, payout: u64) -> Result<(u64, u64)> {
+        if self.holder_stake == 0 {
+            return Ok((0, 0));
+        }
+        let total = (self.stake_amount as u128) + self.holder_stake as u128;
+        let holder_cut = ((payout as u128) * self.holder_stake as u128 / total) as u64;
+        let commission = (holder_cut as u128 * self.commission_bps as u128 / 10_000) as u64;
+        let holder_rewards = holder_cut - commission;
+        self.holder_reward_per_share = self.holder_reward_per_share
+            .checked_add(holder_rewards as u128 * REWARD_PER_SHARE_SCALE / self.holder_stake as u128)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        Ok((holder_rewards, commission))
+    }
+
+    /// Whether the agent may propose or co-sponsor a fast-tracked proposal
+    pub fn can_fast_track(&self) -> bool {
+        self.is_active && self.reputation_score >= FAST_TRACK_MIN_REPUTATION
+    }
+}
+
+/// Longest agent display name (bytes)
+pub const MAX_AGENT_NAME_LEN: usize = 32;
+
+/// Longest agent endpoint URI (bytes)
+pub const MAX_AGENT_URI_LEN: usize = 200;
+
+/// Self-reported agent details for indexers and the governance UI. Optional
+/// and unverified; nothing on chain reads it.
+#[account]
+pub struct AgentMetadata {
+    /// Agent (owner key) the metadata describes
+    pub agent: Pubkey,
+    /// Display name (at most MAX_AGENT_NAME_LEN bytes)
+    pub name: String,
+    /// Where the agent publishes its methodology and status (at most
+    /// MAX_AGENT_URI_LEN bytes)
+    pub endpoint_uri: String,
+    /// Hash of the operator contact, kept off chain
+    pub contact_hash: [u8; 32],
+    /// Last time the metadata changed
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl AgentMetadata {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        4 + MAX_AGENT_NAME_LEN + // name
+        4 + MAX_AGENT_URI_LEN + // endpoint_uri
+        32 + // contact_hash
+        8 + // updated_at
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Replace the metadata after checking field lengths
+    pub fn set(
+        &mut self,
+        name: String,
+        endpoint_uri: String,
+        contact_hash: [u8; 32],
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            name.len() <= MAX_AGENT_NAME_LEN,
+            crate::errors::ErrorCode::AgentNameTooLong
+        );
+        require!(
+            endpoint_uri.len() <= MAX_AGENT_URI_LEN,
+            crate::errors::ErrorCode::AgentUriTooLong
+        );
+        self.name = name;
+        self.endpoint_uri = endpoint_uri;
+        self.contact_hash = contact_hash;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// SHA-256 of the name, as carried in agent events
+    pub fn name_hash(&self) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hash(self.name.as_bytes()).to_bytes()
+    }
+}
+
+/// Voting power delegated from one agent to another. Counts toward the
+/// delegatee's votes on proposals that start after the delegation was made
+/// and before it expires.
+#[account]
+pub struct Delegation {
+    /// Agent delegating its voting power
+    pub delegator: Pubkey,
+    /// Agent voting with the delegated power
+    pub delegatee: Pubkey,
+    /// Delegated stake
+    pub amount: u64,
+    /// Delegation stops counting after this time
+    pub expiry: i64,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl Delegation {
+    /// Calculate space needed for Delegation account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // delegator
+        32 + // delegatee
+        8 + // amount
+        8 + // expiry
+        8 + // created_at
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Whether the delegation adds power to votes on `proposal`
+    pub fn counts_for(&self, proposal: &PolicyProposal, now: i64) -> bool {
+        self.created_at < proposal.start_time && now < self.expiry
+    }
+}
+
+/// Highest commission an agent may take on holder rewards (50%)
+pub const MAX_COMMISSION_BPS: u16 = 5_000;
+
+/// Notice holders get before an agent's commission increase applies (7 days)
+pub const COMMISSION_CHANGE_NOTICE: i64 = 7 * 24 * 60 * 60;
+
+/// Scale of AgentRegistry.holder_reward_per_share
+pub const REWARD_PER_SHARE_SCALE: u128 = 1_000_000_000_000;
+
+/// ARU a token holder delegated to an agent's stake. It counts toward the
+/// agent's tier and voting stake and earns a pro-rata share of the agent's
+/// vote rewards. Holder stake is not slashed; withdrawing it waits out
+/// UNSTAKE_COOLDOWN, independently of the agent and of other holders.
+#[account]
+pub struct StakeDelegation {
+    /// Token holder that delegated
+    pub holder: Pubkey,
+    /// Agent the stake is delegated to
+    pub agent: Pubkey,
+    /// Stake currently delegated
+    pub amount: u64,
+    /// amount × holder_reward_per_share already settled, scaled by
+    /// REWARD_PER_SHARE_SCALE
+    pub reward_debt: u128,
+    /// Settled rewards not yet claimed
+    pub unclaimed_rewards: u64,
+    /// Undelegated stake waiting out its cooldown
+    pub pending_withdrawal: u64,
+    /// When the pending withdrawal can be withdrawn
+    pub withdraw_available_at: i64,
+    /// Add claimed rewards to amount instead of paying them out
+    pub compound_rewards: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl StakeDelegation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // holder
+        32 + // agent
+        8 + // amount
+        16 + // reward_debt
+        8 + // unclaimed_rewards
+        8 + // pending_withdrawal
+        8 + // withdraw_available_at
+        1 + // compound_rewards
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Move rewards accrued at `reward_per_share` into unclaimed_rewards.
+    /// Called before every change of amount.
+    pub fn settle(&mut self, reward_per_share: u128) -> Result<()> {
+        let accrued = (self.amount as u128 * reward_per_share / REWARD_PER_SHARE_SCALE)
+            .saturating_sub(self.reward_debt);
+        self.unclaimed_rewards = self.unclaimed_rewards
+            .checked_add(accrued as u64)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Mark everything accrued at `reward_per_share` as settled
+    pub fn reset_debt(&mut self, reward_per_share: u128) {
+        self.reward_debt = self.amount as u128 * reward_per_share / REWARD_PER_SHARE_SCALE;
+    }
+}
+
+/// Wait between requesting an unstake and withdrawing it (7 days)
+pub const UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// Shortest ARU lock (1 week)
+pub const MIN_LOCK_DURATION: i64 = 7 * 24 * 60 * 60;
+
+/// Longest ARU lock (2 years); a lock this long carries its full amount as
+/// veARU
+pub const MAX_LOCK_DURATION: i64 = 2 * 365 * 24 * 60 * 60;
+
+/// ARU a holder locked for vote-escrowed voting power (veARU). The veARU
+/// balance is the locked amount scaled by the time left on the lock over
+/// MAX_LOCK_DURATION, so it decays linearly to zero at lock_end. Holders
+/// without an agent registry vote with it instead of stake.
+#[account]
+pub struct VoterLock {
+    /// Holder that locked the ARU
+    pub owner: Pubkey,
+    /// ARU locked
+    pub amount: u64,
+    /// When the ARU can be withdrawn
+    pub lock_end: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl VoterLock {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 + // amount
+        8 + // lock_end
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// veARU balance at `now`
+    pub fn balance_at(&self, now: i64) -> u64 {
+        let remaining = self.lock_end.saturating_sub(now).clamp(0, MAX_LOCK_DURATION);
+        (self.amount as u128 * remaining as u128 / MAX_LOCK_DURATION as u128) as u64
+    }
+}
+
+/// Maximum lifetime of a single delegation (1 year)
+pub const MAX_DELEGATION_DURATION: i64 = 365 * 24 * 60 * 60;
+
+/// Permissionless crank a keeper can hold exclusive rights to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KeeperJob {
+    /// ars-token start_new_epoch
+    EpochRollover,
+    /// ars-reserve accrue_interest
+    AccrueInterest,
+    /// ars-core resolve_proposal
+    ResolveProposal,
+}
+
+/// Length of one keeper window (1 hour)
+pub const KEEPER_WINDOW_DURATION: i64 = 60 * 60;
+
+/// How many windows ahead a keeper may claim (24 hours)
+pub const KEEPER_MAX_CLAIM_AHEAD: u64 = 24;
+
+/// Minimum keeper bond (10 ARU)
+pub const KEEPER_MIN_BOND: u64 = 10_000_000;
+
+/// Reward for a serviced window (1 ARU), paid from the keeper reward pool
+pub const KEEPER_REWARD: u64 = 1_000_000;
+
+/// Share of the bond slashed for a missed window (10%)
+pub const KEEPER_SLASH_BPS: u64 = 1_000;
+
+/// Bonded operator that can claim keeper windows
+#[account]
+pub struct KeeperRegistry {
+    /// Operator key
+    pub operator: Pubkey,
+    /// Bond held in the stake escrow
+    pub bond: u64,
+    /// Claimed windows not yet serviced or slashed
+    pub open_claims: u32,
+    /// Windows serviced on time
+    pub windows_serviced: u64,
+    /// Windows missed and slashed
+    pub windows_missed: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl KeeperRegistry {
+    /// Calculate space needed for KeeperRegistry account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // operator
+        8 + // bond
+        4 + // open_claims
+        8 + // windows_serviced
+        8 + // windows_missed
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Exclusive right of one keeper to run `job` during `window`
+/// ([window * KEEPER_WINDOW_DURATION, (window + 1) * KEEPER_WINDOW_DURATION))
+#[account]
+pub struct KeeperWindow {
+    /// Crank covered by the claim
+    pub job: KeeperJob,
+    /// Window index
+    pub window: u64,
+    /// Operator holding the claim
+    pub keeper: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl KeeperWindow {
+    /// Calculate space needed for KeeperWindow account
+    pub const LEN: usize = 8 + // discriminator
+        1 + // job (enum)
+        8 + // window
+        32 + // keeper
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Window index containing `timestamp`
+    pub fn index_at(timestamp: i64) -> u64 {
+        (timestamp.max(0) / KEEPER_WINDOW_DURATION) as u64
+    }
+
+    /// Unix time the window closes
+    pub fn end_time(&self) -> i64 {
+        (self.window as i64)
+            .saturating_add(1)
+            .saturating_mul(KEEPER_WINDOW_DURATION)
+    }
+}
+
+/// Signature scheme an agent signs ILI submissions with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AgentKeyType {
+    /// Solana Ed25519 key (the agent account signs the transaction)
+    Ed25519,
+    /// EVM secp256k1 key, checked through the secp256k1 precompile
+    Secp256k1,
 }
 
 /// Pending ILI update for Byzantine consensus
@@ -153,13 +1044,24 @@ impl ILIPendingUpdate {
         64; // signature
 }
 
+/// How long an ILI override stays in force after voting ends (6 hours)
+pub const ILI_OVERRIDE_DURATION: i64 = 6 * 60 * 60;
+
 /// ILI Oracle with Byzantine fault-tolerant consensus
 #[account]
 pub struct ILIOracle {
     /// Authority (global state)
     pub authority: Pubkey,
-    /// Current ILI value
+    /// Current ILI value (raw consensus median)
     pub current_ili: u64,
+    /// Exponentially smoothed ILI value
+    pub smoothed_ili: u64,
+    /// Smoothing factor alpha in basis points (10000 = no smoothing)
+    pub smoothing_bps: u16,
+    /// Governance override value, served while override_expires is in the future
+    pub override_ili: u64,
+    /// Override expiry timestamp (0 = no override)
+    pub override_expires: i64,
     /// Last update timestamp
     pub last_update: i64,
     /// Update interval in seconds (default 300 = 5 minutes)
@@ -170,6 +1072,8 @@ pub struct ILIOracle {
     pub consensus_threshold: u8,
     /// PDA bump
     pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
 impl ILIOracle {
@@ -178,11 +1082,433 @@ impl ILIOracle {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
         8 + // current_ili
+        8 + // smoothed_ili
+        2 + // smoothing_bps
+        8 + // override_ili
+        8 + // override_expires
         8 + // last_update
         8 + // update_interval
         4 + (10 * ILIPendingUpdate::LEN) + // pending_updates (Vec with max 10)
         1 + // consensus_threshold
-        1; // bump
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Queue an agent submission; once the consensus threshold is reached,
+    /// set the median as the current ILI and return (median, agent count).
+    /// Each agent counts once per round: a repeat submission replaces the
+    /// agent's earlier value.
+    pub fn record_submission(
+        &mut self,
+        agent: Pubkey,
+        ili_value: u64,
+        timestamp: i64,
+        current_time: i64,
+    ) -> Option<(u64, Vec<ILIPendingUpdate>)> {
+        match self.pending_updates.iter_mut().find(|u| u.agent == agent) {
+            Some(update) => {
+                update.ili_value = ili_value;
+                update.timestamp = timestamp;
+            }
+            None => self.pending_updates.push(ILIPendingUpdate {
+                agent,
+                ili_value,
+                timestamp,
+                signature: [0u8; 64],
+            }),
+        }
+
+        if self.pending_updates.len() < self.consensus_threshold as usize {
+            return None;
+        }
+
+        let mut values: Vec<u64> = self.pending_updates
+            .iter()
+            .map(|u| u.ili_value)
+            .collect();
+        ars_common::trace!("ili_consensus", values = &values);
+        let median = median(&mut values);
+        ars_common::trace!(
+            "ili_median",
+            median = median,
+            previous_smoothed = self.smoothed_ili,
+            smoothing_bps = self.smoothing_bps,
+        );
+
+        self.current_ili = median;
+        self.smoothed_ili = self.smooth(median);
+        self.last_update = current_time;
+
+        Some((median, std::mem::take(&mut self.pending_updates)))
+    }
+
+    /// smoothed = alpha * new + (1 - alpha) * old; the first value seeds the average
+    fn smooth(&self, value: u64) -> u64 {
+        if self.last_update == 0 {
+            return value;
+        }
+
+        let alpha = self.smoothing_bps.min(10_000) as u128;
+        let weighted = alpha * value as u128 + (10_000 - alpha) * self.smoothed_ili as u128;
+        (weighted / 10_000) as u64
+    }
+
+    /// ILI value for the requested source
+    pub fn ili(&self, source: IliSource) -> u64 {
+        match source {
+            IliSource::Raw => self.current_ili,
+            IliSource::Smoothed => self.smoothed_ili,
+        }
+    }
+
+    /// Deviation of the latest consensus median from the smoothed ILI
+    /// (basis points), or None before there is a smoothed value
+    pub fn deviation_bps(&self) -> Option<u64> {
+        if self.smoothed_ili == 0 {
+            return None;
+        }
+        let deviation = self.current_ili.abs_diff(self.smoothed_ili) as u128 * 10_000
+            / self.smoothed_ili as u128;
+        Some(deviation.min(u64::MAX as u128) as u64)
+    }
+
+    /// False until the first consensus round completes
+    pub fn is_initialized(&self) -> bool {
+        self.current_ili != 0 || self.last_update != 0
+    }
+
+    /// True while a governance override is in force
+    pub fn override_active(&self, current_time: i64) -> bool {
+        current_time < self.override_expires
+    }
+
+    /// Read the ILI for a consumer, failing if consensus has never been reached
+    /// (a zero value is never treated as a valid index). An active governance
+    /// override takes precedence over both series.
+    pub fn read(&self, source: IliSource, current_time: i64) -> Result<u64> {
+        if self.override_active(current_time) {
+            return Ok(self.override_ili);
+        }
+        require!(
+            self.is_initialized(),
+            crate::errors::ErrorCode::OracleNotInitialized
+        );
+        Ok(self.ili(source))
+    }
+}
+
+/// Which ILI series a consumer reads
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IliSource {
+    /// Latest consensus median
+    Raw,
+    /// Exponentially smoothed median
+    Smoothed,
+}
+
+/// Largest deviation from the consensus median (basis points) that still
+/// counts as matching consensus
+pub const CONSENSUS_BAND_BPS: u64 = 100;
+
+/// Reputation gained by a submission within the consensus band
+pub const CONSENSUS_REPUTATION_REWARD: i32 = 1;
+
+/// Reputation lost by a submission outside the consensus band
+pub const OUTLIER_REPUTATION_PENALTY: i32 = 2;
+
+/// Whether `value` is within CONSENSUS_BAND_BPS of `median`
+pub fn within_consensus_band(value: u64, median: u64) -> bool {
+    value.abs_diff(median) as u128 * 10_000 <= median as u128 * CONSENSUS_BAND_BPS as u128
+}
+
+/// Median of `values` in O(n) using quickselect (reorders the slice).
+/// For an even count, returns the midpoint of the two middle values.
+pub fn median(values: &mut [u64]) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+
+    let len = values.len();
+    let (lower_half, upper, _) = values.select_nth_unstable(len / 2);
+    let upper = *upper;
+
+    if len % 2 == 1 {
+        return upper;
+    }
+
+    // After selection every element left of `mid` is <= upper; the lower
+    // middle value is the largest of them
+    let lower = lower_half.iter().copied().max().unwrap_or(upper);
+    lower + (upper - lower) / 2
+}
+
+/// Reason code recorded when the circuit breaker is tripped
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CircuitBreakerReason {
+    /// ILI or price feed behaving abnormally
+    OracleAnomaly,
+    /// Reserve VHR below its safety threshold
+    VhrBreach,
+    /// Abnormal market conditions (depegs, liquidity crunch)
+    MarketStress,
+    /// Exploit or key compromise suspected
+    SecurityIncident,
+    /// See the attached details hash
+    Other,
+}
+
+/// Reason code recorded when an agent is slashed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlashReason {
+    /// Submitted a manipulated or fabricated ILI value
+    MaliciousSubmission,
+    /// Repeatedly submitted values far from consensus
+    ConsensusDeviation,
+    /// Failed to perform required duties
+    Downtime,
+    /// See the attached details hash
+    Other,
+}
+
+/// Second party that confirmed a slash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlashConfirmation {
+    /// At or below the SlashConfirmationThreshold registry parameter: the
+    /// authority alone
+    None,
+    /// Co-signed by a guardian
+    Guardian { guardian: Pubkey },
+    /// Approved by an executed SlashAgent proposal
+    Proposal { proposal_id: u64 },
+}
+
+/// Stage of a slash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlashStatus {
+    /// Proposed by the authority, executable after the appeal window
+    Proposed,
+    /// Appealed by the agent, frozen until governance votes on it
+    Appealed,
+    /// Stake moved to the treasury
+    Executed,
+    /// Rejected by governance on appeal; no stake moved
+    Dismissed,
+}
+
+/// Audit record of one slash, one per AgentRegistry.slash_count
+#[account]
+pub struct SlashRecord {
+    /// Agent slashed
+    pub agent: Pubkey,
+    /// Index among the agent's slashes (the slash_count it was proposed at)
+    pub index: u64,
+    /// Stake removed
+    pub amount: u64,
+    /// Reason code
+    pub reason: SlashReason,
+    /// Hash of the off-chain evidence, if any
+    pub details_hash: Option<[u8; 32]>,
+    /// Authority that proposed the slash
+    pub authority: Pubkey,
+    /// Second party for slashes above the confirmation threshold
+    pub confirmation: SlashConfirmation,
+    /// Stage of the slash
+    pub status: SlashStatus,
+    /// When the slash was proposed
+    pub proposed_at: i64,
+    /// When it can be executed unless appealed
+    pub executable_at: i64,
+    /// When the agent appealed (0 if not appealed)
+    pub appealed_at: i64,
+    /// When the stake was moved (0 until executed)
+    pub slashed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl SlashRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        8 + // index
+        8 + // amount
+        1 + // reason (enum)
+        (1 + 32) + // details_hash (Option<[u8; 32]>)
+        32 + // authority
+        (1 + 32) + // confirmation (enum, largest variant)
+        1 + // status (enum)
+        8 + // proposed_at
+        8 + // executable_at
+        8 + // appealed_at
+        8 + // slashed_at
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Whether `proposal` is a governance vote on this appealed slash: a
+    /// SlashAgent proposal for exactly this agent and amount, opened after
+    /// the appeal
+    pub fn is_appeal_vote(&self, proposal: &PolicyProposal) -> bool {
+        self.status == SlashStatus::Appealed
+            && proposal.start_time >= self.appealed_at
+            && proposal.policy_params
+                == (PolicyParams::SlashParams { agent: self.agent, amount: self.amount })
+    }
+}
+
+/// Entries kept by the AuditLog ring buffer before the oldest is overwritten
+pub const AUDIT_LOG_CAPACITY: usize = 64;
+
+/// Kind of privileged action recorded in the AuditLog
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AuditAction {
+    /// Unused slot
+    #[default]
+    None,
+    /// Authority-set protocol parameter (thresholds, guardians, rewards, ...)
+    ParamChange,
+    /// Pending admin transfer executed
+    AuthorityTransfer,
+    /// Agent stake slashed
+    Slash,
+    /// Circuit breaker tripped, by the authority or the anomaly crank
+    BreakerTriggered,
+    /// Circuit breaker cleared into cooldown
+    BreakerDeactivated,
+    /// Emergency ILI override set
+    IliOverride,
+    /// Executed proposal minted, burned or rebalanced reserve funds
+    TreasurySpend,
+    /// Executed proposal changed, rolled back or emergency-acted on parameters
+    ProposalExecuted,
+}
+
+/// One AuditLog entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct AuditEntry {
+    /// Signer (or proposal executor) responsible for the action
+    pub actor: Pubkey,
+    /// Action code
+    pub action: AuditAction,
+    /// SHA-256 of the borsh-serialized instruction arguments
+    pub payload_hash: [u8; 32],
+    /// Time recorded
+    pub timestamp: i64,
+}
+
+impl AuditEntry {
+    pub const LEN: usize = 32 + // actor
+        1 + // action (enum)
+        32 + // payload_hash
+        8; // timestamp
+}
+
+/// Ring buffer of the most recent privileged actions
+#[account]
+pub struct AuditLog {
+    /// Entries, slot `sequence % AUDIT_LOG_CAPACITY` holds entry `sequence`
+    pub entries: [AuditEntry; AUDIT_LOG_CAPACITY],
+    /// Total entries ever recorded
+    pub count: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl AuditLog {
+    pub const LEN: usize = 8 + // discriminator
+        AuditEntry::LEN * AUDIT_LOG_CAPACITY + // entries
+        8 + // count
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Append an entry, overwriting the oldest once full; returns its sequence
+    pub fn record(&mut self, actor: Pubkey, action: AuditAction, payload: &[u8], timestamp: i64) -> u64 {
+        let sequence = self.count;
+        self.entries[(sequence % AUDIT_LOG_CAPACITY as u64) as usize] = AuditEntry {
+            actor,
+            action,
+            payload_hash: anchor_lang::solana_program::hash::hash(payload).to_bytes(),
+            timestamp,
+        };
+        self.count = self.count.saturating_add(1);
+        sequence
+    }
+
+    /// Entry `sequence`, or None if not yet written or already overwritten
+    pub fn get(&self, sequence: u64) -> Option<&AuditEntry> {
+        if sequence >= self.count || self.count - sequence > AUDIT_LOG_CAPACITY as u64 {
+            return None;
+        }
+        Some(&self.entries[(sequence % AUDIT_LOG_CAPACITY as u64) as usize])
+    }
+}
+
+/// Bug bounty severity tiers: critical, high, medium, low
+pub const BOUNTY_TIER_COUNT: usize = 4;
+
+/// Bytes reserved for the bug bounty contact
+pub const BOUNTY_CONTACT_LEN: usize = 64;
+
+/// Bug bounty terms, maintained by the authority
+#[account]
+pub struct BugBounty {
+    /// Hash of the published scope document (programs, commits, exclusions)
+    pub scope_hash: [u8; 32],
+    /// Maximum reward per severity tier, critical first (ARU base units)
+    pub reward_tiers: [u64; BOUNTY_TIER_COUNT],
+    /// Disclosure contact, UTF-8 padded with zero bytes
+    pub contact: [u8; BOUNTY_CONTACT_LEN],
+    /// Last time the terms changed
+    pub updated_at: i64,
+    /// ReportCommitments recorded so far
+    pub report_count: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl BugBounty {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // scope_hash
+        8 * BOUNTY_TIER_COUNT + // reward_tiers
+        BOUNTY_CONTACT_LEN + // contact
+        8 + // updated_at
+        8 + // report_count
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Timestamped hash of an undisclosed finding, proving its reporter's
+/// priority once the finding is revealed
+#[account]
+pub struct ReportCommitment {
+    /// Whitehat who committed
+    pub reporter: Pubkey,
+    /// Hash of the finding, e.g. sha256(report || salt)
+    pub commitment: [u8; 32],
+    /// BugBounty scope in force when committed
+    pub scope_hash: [u8; 32],
+    /// Commit time
+    pub committed_at: i64,
+    /// Commit slot
+    pub slot: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl ReportCommitment {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // reporter
+        32 + // commitment
+        32 + // scope_hash
+        8 + // committed_at
+        8 + // slot
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
 }
 
 /// Policy type for proposals
@@ -196,6 +1522,324 @@ pub enum PolicyType {
     UpdateParameters,
     /// Rebalance reserve vault
     RebalanceVault,
+    /// Emergency ILI override
+    OverrideILI,
+    /// Fast-track action while the circuit breaker is active
+    Emergency,
+    /// Approve a slash above the SlashConfirmationThreshold registry
+    /// parameter
+    SlashAgent,
+    /// Pay out of a protocol treasury account
+    TreasurySpend,
+}
+
+/// Number of PolicyType variants
+pub const POLICY_TYPE_COUNT: usize = 8;
+
+/// Number of reserve assets a rebalance assigns weights to
+/// (USDC, SOL, mSOL, JitoSOL)
+pub const RESERVE_ASSET_COUNT: usize = 4;
+
+/// GlobalState field a ParamUpdate proposal can change. Closed to new
+/// variants: parameters added since the registry are ars_common::ParamKey
+/// entries instead.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProtocolParam {
+    /// mint_burn_cap_bps (0-10000)
+    MintBurnCapBps,
+    /// stability_fee_bps (0-10000)
+    StabilityFeeBps,
+    /// vhr_threshold (>= 10000)
+    VhrThreshold,
+    /// min_agent_consensus (1-10)
+    MinAgentConsensus,
+    /// reputation_floor_bps (0-10000)
+    ReputationFloorBps,
+    /// reputation_ceiling_bps (10000-20000)
+    ReputationCeilingBps,
+    /// reputation_span (1-1000000)
+    ReputationSpan,
+    /// epoch_duration in seconds (MIN_EPOCH_DURATION-MAX_EPOCH_DURATION)
+    EpochDuration,
+    /// min_agent_stake (1-1000 ARU)
+    MinAgentStake,
+    /// min_griefing_deposit (0-1000 ARU)
+    MinGriefingDeposit,
+    /// authority_inactivity_period in seconds
+    /// (MIN_AUTHORITY_INACTIVITY_PERIOD-MAX_AUTHORITY_INACTIVITY_PERIOD)
+    AuthorityInactivityPeriod,
+    /// accuracy_floor_bps (0-10000)
+    AccuracyFloorBps,
+    /// accuracy_ceiling_bps (10000-20000)
+    AccuracyCeilingBps,
+    /// accuracy_window (MIN_ACCURACY_SAMPLES-MAX_ACCURACY_WINDOW)
+    AccuracyWindow,
+    /// agent_silence_period in seconds
+    /// (MIN_AGENT_SILENCE_PERIOD-MAX_AGENT_SILENCE_PERIOD)
+    AgentSilencePeriod,
+}
+
+/// Number of ProtocolParam variants
+pub const PROTOCOL_PARAM_COUNT: usize = 15;
+
+/// Shortest epoch_duration a parameter update may set (1 hour)
+pub const MIN_EPOCH_DURATION: i64 = 60 * 60;
+
+/// Longest epoch_duration a parameter update may set (30 days)
+pub const MAX_EPOCH_DURATION: i64 = 30 * 24 * 60 * 60;
+
+/// Default authority_inactivity_period (90 days)
+pub const DEFAULT_AUTHORITY_INACTIVITY_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// Shortest authority_inactivity_period a parameter update may set (30 days)
+pub const MIN_AUTHORITY_INACTIVITY_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Longest authority_inactivity_period a parameter update may set (1 year)
+pub const MAX_AUTHORITY_INACTIVITY_PERIOD: i64 = 365 * 24 * 60 * 60;
+
+/// Default agent_silence_period (7 days)
+pub const DEFAULT_AGENT_SILENCE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Shortest agent_silence_period a parameter update may set (1 day)
+pub const MIN_AGENT_SILENCE_PERIOD: i64 = 24 * 60 * 60;
+
+/// Longest agent_silence_period a parameter update may set (90 days)
+pub const MAX_AGENT_SILENCE_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// Delay between propose_param_update and apply_param_update (48 hours)
+pub const PARAM_UPDATE_TIMELOCK: i64 = 48 * 60 * 60;
+
+impl ProtocolParam {
+    /// Largest change from the current value an optimistic proposal may
+    /// make, or None if the field always needs a full vote
+    pub fn optimistic_band(&self) -> Option<u64> {
+        match self {
+            ProtocolParam::MintBurnCapBps | ProtocolParam::StabilityFeeBps => Some(50),
+            ProtocolParam::VhrThreshold => Some(500),
+            ProtocolParam::MinAgentConsensus
+            | ProtocolParam::ReputationFloorBps
+            | ProtocolParam::ReputationCeilingBps
+            | ProtocolParam::ReputationSpan
+            | ProtocolParam::EpochDuration
+            | ProtocolParam::MinAgentStake
+            | ProtocolParam::MinGriefingDeposit
+            | ProtocolParam::AuthorityInactivityPeriod
+            | ProtocolParam::AccuracyFloorBps
+            | ProtocolParam::AccuracyCeilingBps
+            | ProtocolParam::AccuracyWindow
+            | ProtocolParam::AgentSilencePeriod => None,
+        }
+    }
+}
+
+/// Action an emergency proposal may take while the circuit breaker is active
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EmergencyAction {
+    /// Keep the circuit breaker engaged for EMERGENCY_PAUSE_EXTENSION from
+    /// execution
+    Pause,
+    /// Restore a GlobalState parameter to its value before the last change
+    RollbackParam { field: ProtocolParam },
+}
+
+/// Typed proposal payload, validated when the proposal is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PolicyParams {
+    /// Mint `amount` ARU into the `destination` token account
+    MintParams { amount: u64, destination: Pubkey },
+    /// Burn `amount` ARU from the protocol treasury
+    BurnParams { amount: u64 },
+    /// Set a GlobalState parameter
+    ParamUpdate { field: ProtocolParam, value: u64 },
+    /// Target reserve weights in basis points, summing to 10000
+    RebalanceParams { weights: [u16; RESERVE_ASSET_COUNT] },
+    /// Emergency ILI override value
+    OverrideParams { ili_value: u64 },
+    /// Emergency action, created through create_emergency_proposal
+    EmergencyParams { action: EmergencyAction },
+    /// Authorize slash_agent to slash `amount` from `agent` once executed
+    SlashParams { agent: Pubkey, amount: u64 },
+    /// Replace an authority that has been inactive for
+    /// authority_inactivity_period with `new_authority`
+    AssumeAuthority { new_authority: Pubkey },
+    /// Pay `amount` of `mint` from its treasury into the `destination` token
+    /// account
+    TreasurySpend { mint: Pubkey, amount: u64, destination: Pubkey },
+}
+
+impl PolicyParams {
+    /// Borsh size of the largest variant
+    pub const MAX_LEN: usize = 1 + // variant tag
+        32 + 8 + 32; // TreasurySpend { mint, amount, destination }
+
+    pub fn policy_type(&self) -> PolicyType {
+        match self {
+            PolicyParams::MintParams { .. } => PolicyType::MintARU,
+            PolicyParams::BurnParams { .. } => PolicyType::BurnARU,
+            PolicyParams::ParamUpdate { .. } => PolicyType::UpdateParameters,
+            PolicyParams::RebalanceParams { .. } => PolicyType::RebalanceVault,
+            PolicyParams::OverrideParams { .. } => PolicyType::OverrideILI,
+            PolicyParams::EmergencyParams { .. } => PolicyType::Emergency,
+            PolicyParams::SlashParams { .. } => PolicyType::SlashAgent,
+            PolicyParams::AssumeAuthority { .. } => PolicyType::UpdateParameters,
+            PolicyParams::TreasurySpend { .. } => PolicyType::TreasurySpend,
+        }
+    }
+
+    /// Deterministic hash of (policy_type, policy_params), identifying
+    /// duplicate proposals
+    pub fn hash(&self) -> [u8; 32] {
+        let params = self.try_to_vec().expect("serializing to a Vec cannot fail");
+        anchor_lang::solana_program::hash::hashv(&[&[self.policy_type() as u8], &params])
+            .to_bytes()
+    }
+
+    /// Reject payloads that could never execute
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            PolicyParams::MintParams { amount, destination } => {
+                amount > 0 && destination != Pubkey::default()
+            }
+            PolicyParams::BurnParams { amount } => amount > 0,
+            PolicyParams::ParamUpdate { field, value } => match field {
+                ProtocolParam::MintBurnCapBps | ProtocolParam::StabilityFeeBps => value <= 10_000,
+                ProtocolParam::VhrThreshold => (10_000..=u16::MAX as u64).contains(&value),
+                ProtocolParam::MinAgentConsensus => (1..=10).contains(&value),
+                ProtocolParam::ReputationFloorBps => value <= 10_000,
+                ProtocolParam::ReputationCeilingBps => (10_000..=20_000).contains(&value),
+                ProtocolParam::ReputationSpan => (1..=1_000_000).contains(&value),
+                ProtocolParam::EpochDuration => {
+                    (MIN_EPOCH_DURATION as u64..=MAX_EPOCH_DURATION as u64).contains(&value)
+                }
+                ProtocolParam::MinAgentStake => (1_000_000..=1_000_000_000).contains(&value),
+                ProtocolParam::MinGriefingDeposit => value <= 1_000_000_000,
+                ProtocolParam::AuthorityInactivityPeriod => (MIN_AUTHORITY_INACTIVITY_PERIOD as u64
+                    ..=MAX_AUTHORITY_INACTIVITY_PERIOD as u64)
+                    .contains(&value),
+                ProtocolParam::AccuracyFloorBps => value <= 10_000,
+                ProtocolParam::AccuracyCeilingBps => (10_000..=20_000).contains(&value),
+                ProtocolParam::AccuracyWindow => {
+                    (MIN_ACCURACY_SAMPLES as u64..=MAX_ACCURACY_WINDOW as u64).contains(&value)
+                }
+                ProtocolParam::AgentSilencePeriod => {
+                    (MIN_AGENT_SILENCE_PERIOD as u64..=MAX_AGENT_SILENCE_PERIOD as u64)
+                        .contains(&value)
+                }
+            },
+            PolicyParams::RebalanceParams { weights } => {
+                weights.iter().map(|w| *w as u32).sum::<u32>() == 10_000
+            }
+            PolicyParams::OverrideParams { ili_value } => ili_value > 0,
+            PolicyParams::EmergencyParams { .. } => true,
+            PolicyParams::SlashParams { agent, amount } => {
+                amount > 0 && agent != Pubkey::default()
+            }
+            PolicyParams::AssumeAuthority { new_authority } => new_authority != Pubkey::default(),
+            PolicyParams::TreasurySpend { mint, amount, destination } => {
+                amount > 0 && mint != Pubkey::default() && destination != Pubkey::default()
+            }
+        };
+        require!(valid, crate::errors::ErrorCode::InvalidPolicyParams);
+        Ok(())
+    }
+}
+
+impl GlobalState {
+    /// Current value of a GlobalState parameter
+    pub fn get_param(&self, field: ProtocolParam) -> u64 {
+        match field {
+            ProtocolParam::MintBurnCapBps => self.mint_burn_cap_bps as u64,
+            ProtocolParam::StabilityFeeBps => self.stability_fee_bps as u64,
+            ProtocolParam::VhrThreshold => self.vhr_threshold as u64,
+            ProtocolParam::MinAgentConsensus => self.min_agent_consensus as u64,
+            ProtocolParam::ReputationFloorBps => self.reputation_floor_bps as u64,
+            ProtocolParam::ReputationCeilingBps => self.reputation_ceiling_bps as u64,
+            ProtocolParam::ReputationSpan => self.reputation_span as u64,
+            ProtocolParam::EpochDuration => self.epoch_duration as u64,
+            ProtocolParam::MinAgentStake => self.min_agent_stake,
+            ProtocolParam::MinGriefingDeposit => self.min_griefing_deposit,
+            ProtocolParam::AuthorityInactivityPeriod => self.authority_inactivity_period as u64,
+            ProtocolParam::AccuracyFloorBps => self.accuracy_floor_bps as u64,
+            ProtocolParam::AccuracyCeilingBps => self.accuracy_ceiling_bps as u64,
+            ProtocolParam::AccuracyWindow => self.accuracy_window as u64,
+            ProtocolParam::AgentSilencePeriod => self.agent_silence_period as u64,
+        }
+    }
+
+    /// Swap a parameter back to the value it held before its last change
+    pub fn rollback_param(&mut self, field: ProtocolParam) {
+        let previous = self.previous_params[field as usize];
+        self.set_param(field, previous);
+    }
+
+    /// Whether moving `field` to `value` is small enough for an optimistic
+    /// proposal
+    pub fn within_optimistic_band(&self, field: ProtocolParam, value: u64) -> bool {
+        field
+            .optimistic_band()
+            .is_some_and(|band| self.get_param(field).abs_diff(value) <= band)
+    }
+
+    /// Apply a validated ParamUpdate value, remembering the old one for
+    /// rollback
+    pub fn set_param(&mut self, field: ProtocolParam, value: u64) {
+        self.previous_params[field as usize] = self.get_param(field);
+        match field {
+            ProtocolParam::MintBurnCapBps => self.mint_burn_cap_bps = value as u16,
+            ProtocolParam::StabilityFeeBps => self.stability_fee_bps = value as u16,
+            ProtocolParam::VhrThreshold => self.vhr_threshold = value as u16,
+            ProtocolParam::MinAgentConsensus => self.min_agent_consensus = value as u8,
+            ProtocolParam::ReputationFloorBps => self.reputation_floor_bps = value as u16,
+            ProtocolParam::ReputationCeilingBps => self.reputation_ceiling_bps = value as u16,
+            ProtocolParam::ReputationSpan => self.reputation_span = value as u32,
+            ProtocolParam::EpochDuration => self.epoch_duration = value as i64,
+            ProtocolParam::MinAgentStake => self.min_agent_stake = value,
+            ProtocolParam::MinGriefingDeposit => self.min_griefing_deposit = value,
+            ProtocolParam::AuthorityInactivityPeriod => {
+                self.authority_inactivity_period = value as i64
+            }
+            ProtocolParam::AccuracyFloorBps => self.accuracy_floor_bps = value as u16,
+            ProtocolParam::AccuracyCeilingBps => self.accuracy_ceiling_bps = value as u16,
+            ProtocolParam::AccuracyWindow => self.accuracy_window = value as u8,
+            ProtocolParam::AgentSilencePeriod => self.agent_silence_period = value as i64,
+        }
+    }
+}
+
+/// Latest proposal created with a given PolicyParams::hash, used to reject
+/// duplicates while that proposal is still open for voting
+#[account]
+pub struct ProposalHash {
+    /// ID of the latest proposal with this hash
+    pub proposal_id: u64,
+    /// Voting end of that proposal (0 before the first one)
+    pub end_time: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl ProposalHash {
+    /// Calculate space needed for ProposalHash account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // proposal_id
+        8 + // end_time
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Point the hash at a newly opened `proposal`, failing while the
+    /// previous proposal with the same hash still accepts votes
+    pub fn register(&mut self, proposal: &PolicyProposal, current_time: i64, bump: u8) -> Result<()> {
+        if current_time < self.end_time {
+            msg!("Identical proposal {} is still open for voting", self.proposal_id);
+            return err!(crate::errors::ErrorCode::DuplicateProposal);
+        }
+        self.proposal_id = proposal.id;
+        self.end_time = proposal.end_time;
+        self.bump = bump;
+        Ok(())
+    }
 }
 
 /// Proposal status
@@ -209,6 +1853,126 @@ pub enum ProposalStatus {
     Rejected,
     /// Proposal was executed
     Executed,
+    /// Proposal passed and is waiting out EXECUTION_DELAY
+    Queued,
+    /// Proposal was blocked by a guardian before execution
+    Vetoed,
+}
+
+/// Delay between queueing a passed proposal and executing it (48 hours)
+pub const EXECUTION_DELAY: i64 = 48 * 60 * 60;
+
+/// Share of losing-side vote stake slashed into a proposal's reward pool (basis points)
+pub const SLASHING_PENALTY_BPS: u64 = 1_000;
+
+/// Amount slashed from `stake` voted on the losing side
+pub fn losing_stake_penalty(stake: u64) -> u64 {
+    (stake as u128 * SLASHING_PENALTY_BPS as u128 / 10_000) as u64
+}
+
+/// Epochs after execution at which the outcome of a proposal is evaluated
+/// when its criterion does not set a horizon
+pub const OUTCOME_EVALUATION_EPOCHS: i64 = 7;
+
+/// Shortest outcome horizon a proposal may declare (1 day)
+pub const MIN_OUTCOME_HORIZON: i64 = 24 * 60 * 60;
+
+/// Longest outcome horizon a proposal may declare (21 days). The outcome
+/// must be evaluated while votes are still locked (PROPOSAL_RETENTION after
+/// voting ends), which leaves about a week after EXECUTION_DELAY to execute.
+pub const MAX_OUTCOME_HORIZON: i64 = 21 * 24 * 60 * 60;
+
+/// Futarchy verdict on an executed proposal, scoring the YES side's
+/// prediction that the policy would not lower its outcome metric
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalOutcome {
+    /// Not executed yet, or the evaluation window is still running
+    Pending,
+    /// Outcome metric held or rose: YES was correct
+    Improved,
+    /// Outcome metric fell: NO was correct
+    Worsened,
+}
+
+/// Published feed that decides an executed proposal's outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OutcomeMetric {
+    /// Smoothed ILI
+    #[default]
+    Ili,
+    /// ars-reserve vault VHR (basis points)
+    Vhr,
+    /// ARU market price TWAP from the AruPriceFeed
+    AruPrice,
+}
+
+/// Which feed scores a proposal and how long after execution it is read,
+/// declared when the proposal is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OutcomeCriterion {
+    pub metric: OutcomeMetric,
+    /// Seconds after execution the metric is read again; 0 means
+    /// OUTCOME_EVALUATION_EPOCHS epochs at the current epoch_duration
+    pub horizon: i64,
+}
+
+impl OutcomeCriterion {
+    pub const LEN: usize = 1 + // metric (enum)
+        8; // horizon
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.horizon == 0 || (MIN_OUTCOME_HORIZON..=MAX_OUTCOME_HORIZON).contains(&self.horizon),
+            crate::errors::ErrorCode::InvalidOutcomeCriterion
+        );
+        Ok(())
+    }
+
+    /// When a proposal executed at `executed_at` may be evaluated
+    pub fn evaluation_time(&self, executed_at: i64, epoch_duration: i64) -> Option<i64> {
+        let horizon = if self.horizon == 0 {
+            epoch_duration.checked_mul(OUTCOME_EVALUATION_EPOCHS)?
+        } else {
+            self.horizon
+        };
+        executed_at.checked_add(horizon)
+    }
+}
+
+/// How long after voting ends an active proposal is kept before anyone may close it (30 days)
+pub const PROPOSAL_RETENTION: i64 = 30 * 24 * 60 * 60;
+
+/// Kind of protocol account closed for rent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClosedAccountKind {
+    AgentRegistry,
+    Proposal,
+}
+
+/// Longest proposal metadata URI (bytes)
+pub const MAX_PROPOSAL_URI_LEN: usize = 200;
+
+/// What a proposal is about, for voters: a hash of its title and description
+/// and where to fetch them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ProposalMetadata {
+    /// Hash of the title and description document
+    pub description_hash: [u8; 32],
+    /// Location of the document (at most MAX_PROPOSAL_URI_LEN bytes)
+    pub uri: String,
+}
+
+impl ProposalMetadata {
+    pub const MAX_LEN: usize = 32 + // description_hash
+        4 + MAX_PROPOSAL_URI_LEN; // uri
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.uri.len() <= MAX_PROPOSAL_URI_LEN,
+            crate::errors::ErrorCode::ProposalUriTooLong
+        );
+        Ok(())
+    }
 }
 
 /// Policy proposal with futarchy governance and quadratic voting
@@ -220,8 +1984,10 @@ pub struct PolicyProposal {
     pub proposer: Pubkey,
     /// Type of policy
     pub policy_type: PolicyType,
-    /// Policy parameters (serialized)
-    pub policy_params: Vec<u8>,
+    /// Typed policy payload
+    pub policy_params: PolicyParams,
+    /// PolicyParams::hash of policy_params
+    pub params_hash: [u8; 32],
     /// Proposal start time
     pub start_time: i64,
     /// Proposal end time
@@ -240,18 +2006,56 @@ pub struct PolicyProposal {
     pub execution_tx: Option<[u8; 64]>,
     /// Griefing protection deposit (minimum 10 ARU)
     pub griefing_protection_deposit: u64,
+    /// Concurrent Merkle tree holding compressed votes (default = uncompressed)
+    pub vote_tree: Pubkey,
+    /// Number of votes appended to vote_tree
+    pub compressed_votes: u64,
+    /// Slashed losing-side stake set aside for winning voters at resolution
+    pub reward_pool: u64,
+    /// Total stake on the winning side at resolution
+    pub winning_stake: u64,
+    /// Reward pool paid out to winning voters so far
+    pub rewards_claimed: u64,
+    /// Earliest execution time once queued (0 until queued)
+    pub execution_eta: i64,
+    /// Passes unless OPTIMISTIC_CHALLENGE_STAKE of NO stake is posted
+    pub optimistic: bool,
+    /// Execution timestamp (0 until executed)
+    pub executed_at: i64,
+    /// Value of the outcome criterion's metric when the proposal was
+    /// executed
+    pub metric_at_execution: u64,
+    /// Result of evaluate_outcome
+    pub outcome: ProposalOutcome,
+    /// Executed SlashAgent proposal already used by slash_agent
+    pub slash_applied: bool,
+    /// Description hash and URI shown by indexers and voting UIs
+    pub metadata: ProposalMetadata,
+    /// Passes once conviction reaches GlobalState::conviction_threshold
+    /// instead of by vote tally at end_time
+    pub conviction_mode: bool,
+    /// Net quadratic YES support accumulated over time
+    pub conviction: u64,
+    /// When conviction was last accrued
+    pub conviction_updated_at: i64,
+    /// Feed and horizon that decide the outcome
+    pub outcome_criterion: OutcomeCriterion,
+    /// YES/NO prediction market on the outcome (see open_market)
+    pub market: OutcomeMarket,
     /// PDA bump
     pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
 impl PolicyProposal {
     /// Calculate space needed for PolicyProposal account
-    /// Allows up to 256 bytes for policy_params
     pub const LEN: usize = 8 + // discriminator
         8 + // id
         32 + // proposer
         1 + // policy_type (enum)
-        4 + 256 + // policy_params (Vec with max 256 bytes)
+        PolicyParams::MAX_LEN + // policy_params
+        32 + // params_hash
         8 + // start_time
         8 + // end_time
         8 + // yes_stake
@@ -261,5 +2065,577 @@ impl PolicyProposal {
         1 + // status (enum)
         (1 + 64) + // execution_tx (Option<[u8; 64]>)
         8 + // griefing_protection_deposit
-        1; // bump
+        32 + // vote_tree
+        8 + // compressed_votes
+        8 + // reward_pool
+        8 + // winning_stake
+        8 + // rewards_claimed
+        8 + // execution_eta
+        1 + // optimistic
+        8 + // executed_at
+        8 + // metric_at_execution
+        1 + // outcome (enum)
+        1 + // slash_applied
+        ProposalMetadata::MAX_LEN + // metadata
+        1 + // conviction_mode
+        8 + // conviction
+        8 + // conviction_updated_at
+        OutcomeCriterion::LEN + // outcome_criterion
+        OutcomeMarket::LEN + // market
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Accrue conviction for each whole CONVICTION_STEP since the last update,
+    /// up to end_time. Every step keeps CONVICTION_DECAY_BPS of the previous
+    /// conviction and adds the current net quadratic YES support. Call before
+    /// every change to the tallies.
+    pub fn update_conviction(&mut self, now: i64) {
+        if !self.conviction_mode {
+            return;
+        }
+        let steps = (now.min(self.end_time) - self.conviction_updated_at) / CONVICTION_STEP;
+        if steps <= 0 {
+            return;
+        }
+        let support = self.quadratic_yes.saturating_sub(self.quadratic_no) as u128;
+        let mut conviction = self.conviction as u128;
+        for _ in 0..steps {
+            conviction = conviction * CONVICTION_DECAY_BPS as u128 / 10_000 + support;
+        }
+        self.conviction = conviction.min(u64::MAX as u128) as u64;
+        self.conviction_updated_at += steps * CONVICTION_STEP;
+    }
+
+    /// Emergency proposals are decided by Gold+ agents only
+    pub fn allows_voter_tier(&self, tier: AgentTier) -> bool {
+        self.policy_type != PolicyType::Emergency || tier >= AgentTier::Gold
+    }
+
+    /// Checks shared by every instruction that casts a new vote with
+    /// `stake_amount` of the agent's own stake
+    pub fn require_votable(
+        &self,
+        agent_registry: &AgentRegistry,
+        stake_amount: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            current_time >= self.start_time && current_time < self.end_time,
+            crate::errors::ErrorCode::ProposalNotActive
+        );
+        require!(agent_registry.is_active, crate::errors::ErrorCode::AgentNotActive);
+        require!(
+            self.allows_voter_tier(agent_registry.agent_tier),
+            crate::errors::ErrorCode::InsufficientTier
+        );
+        require!(
+            stake_amount > 0 && stake_amount <= agent_registry.available_stake(),
+            crate::errors::ErrorCode::InvalidStakeAmount
+        );
+        // Power freed by a revocation may already have been used by the
+        // delegatee on proposals that were open at the time
+        require!(
+            agent_registry.last_revoked_at < self.start_time,
+            crate::errors::ErrorCode::DelegationChangedDuringVote
+        );
+        Ok(())
+    }
+
+    /// Checks for a new vote cast with a VoterLock's veARU instead of agent
+    /// stake. Such votes put no stake at risk, so `stake_amount` must be zero.
+    pub fn require_lock_votable(
+        &self,
+        voter_lock: &VoterLock,
+        stake_amount: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            current_time >= self.start_time && current_time < self.end_time,
+            crate::errors::ErrorCode::ProposalNotActive
+        );
+        // Emergency votes are reserved for Gold-tier agents
+        require!(
+            self.policy_type != PolicyType::Emergency,
+            crate::errors::ErrorCode::InsufficientTier
+        );
+        require!(stake_amount == 0, crate::errors::ErrorCode::InvalidStakeAmount);
+        require!(
+            voter_lock.balance_at(current_time) > 0,
+            crate::errors::ErrorCode::LockExpired
+        );
+        Ok(())
+    }
+
+    /// Mark the proposal executed, recording the value of its outcome
+    /// metric that the outcome is scored against. Fails if the outcome would
+    /// be evaluated after lock_votes_until releases the voters' stake, since
+    /// losing votes could then no longer be slashed to pay the winners.
+    pub fn mark_executed(
+        &mut self,
+        metric_at_execution: u64,
+        current_time: i64,
+        epoch_duration: i64,
+    ) -> Result<()> {
+        let evaluation_time = self.outcome_criterion
+            .evaluation_time(current_time, epoch_duration)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        require!(
+            evaluation_time <= self.end_time.saturating_add(PROPOSAL_RETENTION),
+            crate::errors::ErrorCode::OutcomeAfterVoteLock
+        );
+        self.status = ProposalStatus::Executed;
+        self.executed_at = current_time;
+        self.metric_at_execution = metric_at_execution;
+        Ok(())
+    }
+
+    /// Whether YES is the winning side for settlement: by vote for rejected
+    /// and vetoed proposals, by evaluated outcome for executed ones. None
+    /// while that is not known yet.
+    pub fn yes_won(&self) -> Option<bool> {
+        match (self.status, self.outcome) {
+            (ProposalStatus::Rejected | ProposalStatus::Vetoed, _) => Some(false),
+            (ProposalStatus::Executed, ProposalOutcome::Improved) => Some(true),
+            (ProposalStatus::Executed, ProposalOutcome::Worsened) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Initialize a new proposal whose voting starts at `start_time`
+    pub fn open(
+        &mut self,
+        id: u64,
+        proposer: Pubkey,
+        policy_params: PolicyParams,
+        metadata: ProposalMetadata,
+        start_time: i64,
+        voting_period: i64,
+        bump: u8,
+    ) -> Result<()> {
+        metadata.validate()?;
+        self.id = id;
+        self.proposer = proposer;
+        self.policy_type = policy_params.policy_type();
+        self.params_hash = policy_params.hash();
+        self.policy_params = policy_params;
+        self.start_time = start_time;
+        self.end_time = start_time
+            .checked_add(voting_period)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        self.yes_stake = 0;
+        self.no_stake = 0;
+        self.quadratic_yes = 0;
+        self.quadratic_no = 0;
+        self.status = ProposalStatus::Active;
+        self.execution_tx = None;
+        self.griefing_protection_deposit = DEFAULT_GRIEFING_DEPOSIT;
+        self.vote_tree = Pubkey::default();
+        self.compressed_votes = 0;
+        self.reward_pool = 0;
+        self.winning_stake = 0;
+        self.rewards_claimed = 0;
+        self.execution_eta = 0;
+        self.optimistic = false;
+        self.executed_at = 0;
+        self.metric_at_execution = 0;
+        self.outcome = ProposalOutcome::Pending;
+        self.slash_applied = false;
+        self.metadata = metadata;
+        self.conviction_mode = false;
+        self.conviction = 0;
+        self.conviction_updated_at = start_time;
+        self.outcome_criterion = OutcomeCriterion::default();
+        self.market = OutcomeMarket::default();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Add a vote's stake and voting power to the yes or no tally
+    pub fn add_vote(&mut self, vote_yes: bool, stake_amount: u64, voting_power: u64) -> Result<()> {
+        let (stake, power) = if vote_yes {
+            (&mut self.yes_stake, &mut self.quadratic_yes)
+        } else {
+            (&mut self.no_stake, &mut self.quadratic_no)
+        };
+        *stake = stake
+            .checked_add(stake_amount)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        *power = power
+            .checked_add(voting_power)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Remove a previously added vote from the yes or no tally
+    pub fn remove_vote(&mut self, vote_yes: bool, stake_amount: u64, voting_power: u64) -> Result<()> {
+        let (stake, power) = if vote_yes {
+            (&mut self.yes_stake, &mut self.quadratic_yes)
+        } else {
+            (&mut self.no_stake, &mut self.quadratic_no)
+        };
+        *stake = stake
+            .checked_sub(stake_amount)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        *power = power
+            .checked_sub(voting_power)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// One agent's vote on a proposal; the PDA can only be created once per
+/// (proposal, voter), which makes each vote unique
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+    /// Voting agent
+    pub voter: Pubkey,
+    /// Vote direction
+    pub vote_yes: bool,
+    /// Stake committed to the vote
+    pub stake_amount: u64,
+    /// Stake delegated to the voter and counted in voting_power; it is not
+    /// rewarded or slashed at settlement
+    pub delegated_stake: u64,
+    /// Quadratic voting power (sqrt of stake_amount + delegated_stake),
+    /// scaled by the voter's reputation multiplier
+    pub voting_power: u64,
+    /// Vote timestamp
+    pub voted_at: i64,
+    /// Set once the vote's settlement reward or slash has been applied
+    pub claimed: bool,
+    /// Stake slashed when the vote was settled as losing
+    pub slashed: u64,
+    /// Set once the participation reward has been paid
+    pub participation_claimed: bool,
+    /// ProposalSimulation result the voter attached (zero if none)
+    pub simulation_hash: [u8; 32],
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl VoteRecord {
+    /// Calculate space needed for VoteRecord account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // proposal
+        32 + // voter
+        1 + // vote_yes
+        8 + // stake_amount
+        8 + // delegated_stake
+        8 + // voting_power
+        8 + // voted_at
+        1 + // claimed
+        8 + // slashed
+        1 + // participation_claimed
+        32 + // simulation_hash
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Protocol parameter registry (see ars_common::params)
+#[account]
+pub struct ParameterRegistry {
+    /// Versioned parameter values
+    pub table: ParamTable,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl ParameterRegistry {
+    /// Calculate space needed for ParameterRegistry account, with room for
+    /// every registry parameter
+    pub const LEN: usize = 8 + // discriminator
+        ParamTable::MAX_LEN + // table
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Randomness seed for committee selection and auction tie-breaks
+#[account]
+pub struct RandomnessState {
+    /// Authority allowed to request randomness
+    pub authority: Pubkey,
+    /// Switchboard randomness account committed for the pending request
+    pub randomness_account: Pubkey,
+    /// Slot at which the request was committed
+    pub request_slot: u64,
+    /// Monotonic request counter
+    pub request_nonce: u64,
+    /// Last consumed seed
+    pub seed: [u8; 32],
+    /// Slot at which the seed was consumed
+    pub seeded_at_slot: u64,
+    /// True if the seed came from SlotHashes instead of Switchboard
+    pub is_fallback: bool,
+    /// True while a request awaits consumption
+    pub pending: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// Slot whose hash seeds the fallback, fixed at request time
+    pub fallback_slot: u64,
+    /// candidates_hash of the committee candidates committed with the request
+    pub candidates_hash: [u8; 32],
+    /// Members to draw from the committed candidates
+    pub committee_size: u8,
+    /// True once draw_committee has used the current seed
+    pub committee_drawn: bool,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN - 42],
+}
+
+impl RandomnessState {
+    /// Calculate space needed for RandomnessState account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // randomness_account
+        8 + // request_slot
+        8 + // request_nonce
+        32 + // seed
+        8 + // seeded_at_slot
+        1 + // is_fallback
+        1 + // pending
+        1 + // bump
+        8 + // fallback_slot
+        32 + // candidates_hash
+        1 + // committee_size
+        1 + // committee_drawn
+        (ACCOUNT_RESERVED_LEN - 42); // _reserved
+}
+
+/// Time weighted ARU market price read from a registered AMM pool (see
+/// price_feed.rs)
+#[account]
+pub struct AruPriceFeed {
+    /// Raydium CP-Swap pool trading ARU
+    pub pool: Pubkey,
+    /// The pool's observation account
+    pub observation_state: Pubkey,
+    /// The pool's other mint, which the price is quoted in
+    pub quote_mint: Pubkey,
+    /// Whether ARU is the pool's token_0
+    pub aru_is_token_0: bool,
+    /// ARU mint decimals
+    pub aru_decimals: u8,
+    /// Quote mint decimals
+    pub quote_decimals: u8,
+    /// Seconds of pool history each published price averages over
+    pub twap_window: i64,
+    /// Last published price, quote units per ARU at ARU_PRICE_SCALE
+    pub price: u64,
+    /// Seconds of history the last price actually covered
+    pub price_span: u64,
+    /// When the price was last published (0 if never)
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl AruPriceFeed {
+    /// Calculate space needed for AruPriceFeed account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // observation_state
+        32 + // quote_mint
+        1 + // aru_is_token_0
+        1 + // aru_decimals
+        1 + // quote_decimals
+        8 + // twap_window
+        8 + // price
+        8 + // price_span
+        8 + // updated_at
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Last published price, if it is no older than MAX_ARU_PRICE_AGE
+    pub fn read(&self, current_time: i64) -> Result<u64> {
+        require!(
+            self.updated_at > 0
+                && current_time.saturating_sub(self.updated_at) <= MAX_ARU_PRICE_AGE,
+            crate::errors::ErrorCode::StaleAruPrice
+        );
+        Ok(self.price)
+    }
+}
+
+/// Merkle root of an off-chain computed agent reward distribution
+#[account]
+pub struct RewardRoot {
+    /// Reward epoch
+    pub epoch: u64,
+    /// Merkle root over reward_leaf(epoch, agent, amount)
+    pub root: [u8; 32],
+    /// Total rewards covered by the root
+    pub total_amount: u64,
+    /// Rewards claimed so far
+    pub claimed_amount: u64,
+    /// Keeper that posted the root
+    pub poster: Pubkey,
+    /// Post timestamp
+    pub posted_at: i64,
+    /// Claims open after this timestamp
+    pub challenge_ends: i64,
+    /// Reward per ILI submission the distribution claims to apply
+    pub reward_per_update: u64,
+    /// Lamports bonded by the poster, forfeited to a successful challenger
+    pub bond: u64,
+    /// Root voided by a successful challenge
+    pub voided: bool,
+    /// Bond returned to the poster
+    pub bond_released: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl RewardRoot {
+    /// Calculate space needed for RewardRoot account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // epoch
+        32 + // root
+        8 + // total_amount
+        8 + // claimed_amount
+        32 + // poster
+        8 + // posted_at
+        8 + // challenge_ends
+        8 + // reward_per_update
+        8 + // bond
+        1 + // voided
+        1 + // bond_released
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Number of epochs of per-agent history kept in AgentStats
+pub const AGENT_STATS_EPOCHS: usize = 8;
+
+/// Per-agent activity for recent epochs, used to audit reward roots
+#[account]
+pub struct AgentStats {
+    /// Agent's public key
+    pub agent: Pubkey,
+    /// Epoch stored in each ring slot (slot = epoch % AGENT_STATS_EPOCHS)
+    pub epochs: [u64; AGENT_STATS_EPOCHS],
+    /// ILI submissions per slot
+    pub submissions: [u32; AGENT_STATS_EPOCHS],
+    /// Submissions accepted into consensus per slot
+    pub successful_updates: [u32; AGENT_STATS_EPOCHS],
+    /// Outcome of each recent settled vote, most recent in bit 0 (1 if the
+    /// vote picked the winning side)
+    pub prediction_history: u32,
+    /// Settled votes recorded in prediction_history (at most
+    /// MAX_ACCURACY_WINDOW)
+    pub prediction_count: u8,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl AgentStats {
+    /// Calculate space needed for AgentStats account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        8 * AGENT_STATS_EPOCHS + // epochs
+        4 * AGENT_STATS_EPOCHS + // submissions
+        4 * AGENT_STATS_EPOCHS + // successful_updates
+        4 + // prediction_history
+        1 + // prediction_count
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Ring slot for `epoch`, resetting it if it still holds an older epoch
+    fn slot_for(&mut self, epoch: u64) -> usize {
+        let slot = (epoch % AGENT_STATS_EPOCHS as u64) as usize;
+        if self.epochs[slot] != epoch {
+            self.epochs[slot] = epoch;
+            self.submissions[slot] = 0;
+            self.successful_updates[slot] = 0;
+        }
+        slot
+    }
+
+    pub fn record_submission(&mut self, epoch: u64) {
+        let slot = self.slot_for(epoch);
+        self.submissions[slot] = self.submissions[slot].saturating_add(1);
+    }
+
+    pub fn record_success(&mut self, epoch: u64) {
+        let slot = self.slot_for(epoch);
+        self.successful_updates[slot] = self.successful_updates[slot].saturating_add(1);
+    }
+
+    /// (submissions, successful_updates) for `epoch`, or None if the slot
+    /// has been overwritten by a later epoch
+    pub fn get(&self, epoch: u64) -> Option<(u32, u32)> {
+        let slot = (epoch % AGENT_STATS_EPOCHS as u64) as usize;
+        if self.epochs[slot] == epoch {
+            Some((self.submissions[slot], self.successful_updates[slot]))
+        } else if self.epochs[slot] < epoch {
+            Some((0, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Record whether a settled vote picked the winning side
+    pub fn record_prediction(&mut self, correct: bool) {
+        self.prediction_history = (self.prediction_history << 1) | correct as u32;
+        self.prediction_count = self.prediction_count.saturating_add(1).min(MAX_ACCURACY_WINDOW);
+    }
+
+    /// Share of the last `window` settled votes that picked the winning side
+    /// (basis points), or None with fewer than MIN_ACCURACY_SAMPLES of them
+    pub fn accuracy_bps(&self, window: u8) -> Option<u64> {
+        let samples = self.prediction_count.min(window).min(MAX_ACCURACY_WINDOW) as u32;
+        if samples < MIN_ACCURACY_SAMPLES as u32 {
+            return None;
+        }
+        let mask = u32::MAX >> (32 - samples);
+        let correct = (self.prediction_history & mask).count_ones() as u64;
+        Some(correct * 10_000 / samples as u64)
+    }
+
+    /// accuracy_bps of the AgentStats PDA at `info`, or None if the agent
+    /// has no stats account yet
+    pub fn accuracy_of(info: &AccountInfo, window: u8) -> Result<Option<u64>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require!(info.owner == &crate::ID, crate::errors::ErrorCode::StatsUnavailable);
+        let stats = AgentStats::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(stats.accuracy_bps(window))
+    }
+}
+
+/// Marker proving an agent already claimed from a reward root
+#[account]
+pub struct RewardClaim {
+    /// Reward root claimed from
+    pub reward_root: Pubkey,
+    /// Claiming agent
+    pub agent: Pubkey,
+    /// Amount claimed
+    pub amount: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl RewardClaim {
+    /// Calculate space needed for RewardClaim account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // reward_root
+        32 + // agent
+        8 + // amount
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
 }
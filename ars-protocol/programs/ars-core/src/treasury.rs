@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Protocol treasury
+///
+/// Every mint the protocol earns in has one treasury token account, the PDA
+/// at ["treasury", mint], owned by global_state. Protocol income lands there:
+/// ARU slashed from agents by slash_agent and the griefing deposits of new
+/// proposals. Tokens only leave through an executed TreasurySpend proposal or
+/// when the proposer of an executed proposal reclaims their deposit.
+
+/// Address of the treasury token account for `mint`
+pub fn treasury_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"treasury", mint.as_ref()], &crate::ID).0
+}
+
+/// Move a proposer's griefing deposit into the ARU treasury
+pub fn collect_deposit<'info>(
+    token_program: &Program<'info, Token>,
+    proposer_token_account: &Account<'info, TokenAccount>,
+    treasury: &Account<'info, TokenAccount>,
+    proposer: &Signer<'info>,
+    deposit: u64,
+) -> Result<()> {
+    if deposit == 0 {
+        return Ok(());
+    }
+
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: proposer_token_account.to_account_info(),
+                to: treasury.to_account_info(),
+                authority: proposer.to_account_info(),
+            },
+        ),
+        deposit,
+    )
+}
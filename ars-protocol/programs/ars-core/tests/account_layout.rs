@@ -0,0 +1,47 @@
+//! Every ars-core account ends with zeroed reserved bytes that its LEN
+//! accounts for, so fields can be added later without reallocating. Fields
+//! added since take their bytes from the reserve.
+
+use ars_core::{
+    AgentMetadata, AgentRegistry, AgentStats, AruPriceFeed, AuditLog, BugBounty, Delegation,
+    GlobalState, ILIOracle, KeeperRegistry, KeeperWindow, ParameterRegistry, PolicyProposal,
+    ProposalHash, ProposalSimulation, RandomnessState, ReportCommitment, RewardClaim, RewardRoot,
+    SlashRecord, StakeDelegation, VoteRecord, VoterLock,
+};
+use ars_program_test::assert_reserved_layout;
+
+#[test]
+fn fixed_size_accounts_end_with_reserved_space() {
+    assert_reserved_layout!(
+        AgentRegistry,
+        AgentStats,
+        AruPriceFeed,
+        AuditLog,
+        BugBounty,
+        Delegation,
+        KeeperRegistry,
+        KeeperWindow,
+        ProposalHash,
+        ProposalSimulation,
+        RandomnessState,
+        ReportCommitment,
+        RewardClaim,
+        RewardRoot,
+        StakeDelegation,
+        VoteRecord,
+        VoterLock,
+    );
+}
+
+#[test]
+fn variable_size_accounts_keep_reserved_space_within_len() {
+    // Options, strings, vectors and policy payloads decode at their smallest
+    assert_reserved_layout!(
+        variable: AgentMetadata,
+        GlobalState,
+        ILIOracle,
+        ParameterRegistry,
+        PolicyProposal,
+        SlashRecord,
+    );
+}
@@ -0,0 +1,60 @@
+//! The ARU price feed averages a CP-Swap observation ring over at least the
+//! registered window, follows the ring across its wrap point, and refuses to
+//! publish when the pool's history is too short.
+
+use ars_core::{scale_price_x32, twap_x32, PoolObservation, ARU_PRICE_SCALE, OBSERVATION_NUM};
+
+const ONE_X32: u128 = 1 << 32;
+
+/// Ring with one observation per minute starting at `start`, the newest at
+/// `newest`, with token_0 priced at `price_x32` throughout
+fn ring(start: u64, count: usize, newest: usize, price_x32: u128) -> Vec<PoolObservation> {
+    let mut ring = vec![PoolObservation::default(); OBSERVATION_NUM];
+    for i in 0..count {
+        let index = (newest + OBSERVATION_NUM - (count - 1 - i)) % OBSERVATION_NUM;
+        let elapsed = 60 * i as u64;
+        ring[index] = PoolObservation {
+            block_timestamp: start + elapsed,
+            cumulative_token_0_price_x32: price_x32 * elapsed as u128,
+            cumulative_token_1_price_x32: (ONE_X32 * ONE_X32 / price_x32) * elapsed as u128,
+        };
+    }
+    ring
+}
+
+#[test]
+fn constant_price_averages_to_itself() {
+    let observations = ring(1_000, 30, 29, 2 * ONE_X32);
+    let (price_x32, span) = twap_x32(&observations, 29, 600, true).unwrap();
+    assert_eq!(price_x32, 2 * ONE_X32);
+    assert_eq!(span, 600);
+    assert_eq!(scale_price_x32(price_x32, 6, 6), Some(2 * ARU_PRICE_SCALE));
+}
+
+#[test]
+fn quote_is_inverted_when_aru_is_token_1() {
+    let observations = ring(1_000, 30, 29, 4 * ONE_X32);
+    let (price_x32, _) = twap_x32(&observations, 29, 600, false).unwrap();
+    assert_eq!(scale_price_x32(price_x32, 6, 6), Some(ARU_PRICE_SCALE / 4));
+}
+
+#[test]
+fn ring_is_followed_across_its_wrap_point() {
+    let observations = ring(1_000, 20, 5, ONE_X32);
+    let (price_x32, span) = twap_x32(&observations, 5, 15 * 60, true).unwrap();
+    assert_eq!(price_x32, ONE_X32);
+    assert_eq!(span, 15 * 60);
+}
+
+#[test]
+fn short_history_is_rejected() {
+    let observations = ring(1_000, 5, 4, ONE_X32);
+    assert!(twap_x32(&observations, 4, 600, true).is_err());
+    assert!(twap_x32(&observations, 10, 60, true).is_err());
+}
+
+#[test]
+fn decimals_are_normalized() {
+    // 1 raw ARU (6 decimals) buys 1_000 raw units of a 9-decimal quote
+    assert_eq!(scale_price_x32(1_000 * ONE_X32, 6, 9), Some(ARU_PRICE_SCALE));
+}
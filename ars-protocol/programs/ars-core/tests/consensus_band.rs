@@ -0,0 +1,20 @@
+//! Submissions within CONSENSUS_BAND_BPS of the round median count as
+//! matching consensus; anything further out is an outlier.
+
+use ars_core::{within_consensus_band, CONSENSUS_BAND_BPS};
+
+#[test]
+fn band_edges_are_inclusive() {
+    let median = 1_000_000;
+    let edge = median * CONSENSUS_BAND_BPS / 10_000;
+    assert!(within_consensus_band(median + edge, median));
+    assert!(within_consensus_band(median - edge, median));
+    assert!(!within_consensus_band(median + edge + 1, median));
+    assert!(!within_consensus_band(median - edge - 1, median));
+}
+
+#[test]
+fn zero_median_only_matches_zero() {
+    assert!(within_consensus_band(0, 0));
+    assert!(!within_consensus_band(1, 0));
+}
@@ -0,0 +1,132 @@
+//! While honest agents outnumber the rest of a round, the consensus median
+//! stays within the range of the honest submissions, whatever the others
+//! submit: one colluding value, extreme outliers, or repeated submissions
+//! from the same agent.
+
+use anchor_lang::prelude::Pubkey;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_core::{median, ILIOracle};
+use proptest::prelude::*;
+
+/// Submissions an ILIOracle account has room for in one round
+const MAX_ROUND: usize = 10;
+
+fn oracle(consensus_threshold: usize) -> ILIOracle {
+    ILIOracle {
+        authority: Pubkey::new_unique(),
+        current_ili: 0,
+        smoothed_ili: 0,
+        smoothing_bps: 10_000,
+        override_ili: 0,
+        override_expires: 0,
+        last_update: 0,
+        update_interval: 300,
+        pending_updates: Vec::new(),
+        consensus_threshold: consensus_threshold as u8,
+        bump: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN],
+    }
+}
+
+/// Honest submissions within 1% of a common true value, and how many agents
+/// may act against them while staying a strict minority
+fn honest_round() -> impl Strategy<Value = (Vec<u64>, usize)> {
+    (1usize..=MAX_ROUND / 2 + 1).prop_flat_map(|honest| {
+        let values = (1_000u64..1_000_000_000_000, prop::collection::vec(-100i64..=100, honest))
+            .prop_map(|(truth, noise)| {
+                noise
+                    .into_iter()
+                    .map(|bps| (truth as i128 * (10_000 + bps as i128) / 10_000) as u64)
+                    .collect::<Vec<u64>>()
+            });
+        // Fewer adversaries than honest agents, within the round's capacity
+        let adversaries = 0..honest.min(MAX_ROUND + 1 - honest);
+        (values, adversaries)
+    })
+}
+
+/// Adversarial value: either end of the range or anything in between
+fn outlier() -> impl Strategy<Value = u64> {
+    prop_oneof![Just(0), Just(u64::MAX), any::<u64>()]
+}
+
+fn within_honest_range(value: u64, honest: &[u64]) -> bool {
+    let low = *honest.iter().min().unwrap();
+    let high = *honest.iter().max().unwrap();
+    (low..=high).contains(&value)
+}
+
+proptest! {
+    #[test]
+    fn extreme_outliers_cannot_move_the_median_out_of_range(
+        (honest, outliers) in honest_round().prop_flat_map(|(honest, adversaries)| {
+            (Just(honest), prop::collection::vec(outlier(), adversaries))
+        }),
+    ) {
+        let mut values = [honest.clone(), outliers].concat();
+        prop_assert!(within_honest_range(median(&mut values), &honest));
+    }
+
+    #[test]
+    fn a_colluding_minority_cannot_move_the_median_out_of_range(
+        (honest, adversaries, value) in honest_round()
+            .prop_flat_map(|(honest, adversaries)| (Just(honest), Just(adversaries), outlier())),
+    ) {
+        let mut values = [honest.clone(), vec![value; adversaries]].concat();
+        prop_assert!(within_honest_range(median(&mut values), &honest));
+    }
+
+    #[test]
+    fn repeated_submissions_count_once(
+        (honest, submissions) in honest_round()
+            .prop_flat_map(|(honest, adversaries)| {
+                // Each adversary submits one to three times
+                let repeats = prop::collection::vec(
+                    prop::collection::vec(outlier(), 1..=3),
+                    adversaries,
+                );
+                (Just(honest), repeats)
+            })
+            .prop_flat_map(|(honest, repeats)| {
+                let mut submissions: Vec<_> =
+                    honest.iter().map(|value| (Pubkey::new_unique(), *value)).collect();
+                for values in repeats {
+                    let agent = Pubkey::new_unique();
+                    submissions.extend(values.into_iter().map(|v| (agent, v)));
+                }
+                (Just(honest), Just(submissions).prop_shuffle())
+            }),
+    ) {
+        let mut agents: Vec<Pubkey> = submissions.iter().map(|(agent, _)| *agent).collect();
+        agents.sort();
+        agents.dedup();
+
+        let mut oracle = oracle(agents.len());
+        let mut completed = None;
+        for (agent, value) in submissions {
+            completed = oracle.record_submission(agent, value, 0, 1);
+            if completed.is_some() {
+                break;
+            }
+        }
+
+        let (median, round) = completed.expect("round completes once every agent submitted");
+        prop_assert_eq!(round.len(), agents.len());
+        prop_assert!(within_honest_range(median, &honest));
+    }
+}
+
+#[test]
+fn a_repeat_submission_replaces_the_earlier_value() {
+    let (honest_a, honest_b, adversary) =
+        (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let mut oracle = oracle(3);
+
+    assert!(oracle.record_submission(adversary, u64::MAX, 0, 1).is_none());
+    assert!(oracle.record_submission(adversary, u64::MAX, 0, 1).is_none());
+    assert!(oracle.record_submission(honest_a, 1_000_000, 0, 1).is_none());
+    let (median, round) = oracle.record_submission(honest_b, 1_000_100, 0, 1).unwrap();
+
+    assert_eq!(round.len(), 3);
+    assert_eq!(median, 1_000_100);
+}
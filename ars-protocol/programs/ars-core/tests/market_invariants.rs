@@ -0,0 +1,146 @@
+//! No sequence of outcome market trades mints shares the vault cannot back:
+//! each side's supply plus its pool reserve always equals the collateral,
+//! the pool's product never shrinks, and once the market is resolved and
+//! settled the vault holds exactly what winning holders can redeem.
+
+use anchor_lang::prelude::Pubkey;
+use ars_common::ParamKey;
+use ars_core::{market_fee, OutcomeMarket, MIN_MARKET_LIQUIDITY};
+use proptest::prelude::*;
+
+#[derive(Clone, Debug)]
+enum Trade {
+    MintSets(u64),
+    MergeSets(u64),
+    Swap { sell_yes: bool, amount: u64 },
+    Buy { buy_yes: bool, collateral: u64 },
+    Sell { sell_yes: bool, shares: u64 },
+}
+
+fn trade() -> impl Strategy<Value = Trade> {
+    let amount = 1u64..1_000_000_000;
+    prop_oneof![
+        amount.clone().prop_map(Trade::MintSets),
+        amount.clone().prop_map(Trade::MergeSets),
+        (any::<bool>(), amount.clone())
+            .prop_map(|(sell_yes, amount)| Trade::Swap { sell_yes, amount }),
+        (any::<bool>(), amount.clone())
+            .prop_map(|(buy_yes, collateral)| Trade::Buy { buy_yes, collateral }),
+        (any::<bool>(), amount).prop_map(|(sell_yes, shares)| Trade::Sell { sell_yes, shares }),
+    ]
+}
+
+/// Market plus the minted supply of each side and the ARU in the vault,
+/// updated the way the instruction handlers move tokens
+struct Model {
+    market: OutcomeMarket,
+    yes_supply: u64,
+    no_supply: u64,
+    vault: u64,
+}
+
+impl Model {
+    fn new(liquidity: u64) -> Self {
+        let mut market = OutcomeMarket::default();
+        market.open(Pubkey::new_unique(), liquidity).unwrap();
+        Model { market, yes_supply: 0, no_supply: 0, vault: liquidity }
+    }
+
+    fn supply(&mut self, yes: bool) -> &mut u64 {
+        if yes {
+            &mut self.yes_supply
+        } else {
+            &mut self.no_supply
+        }
+    }
+
+    fn apply(&mut self, trade: Trade) {
+        match trade {
+            Trade::MintSets(amount) => {
+                self.market.change_collateral(amount, true).unwrap();
+                self.vault += amount;
+                self.yes_supply += amount;
+                self.no_supply += amount;
+            }
+            Trade::MergeSets(amount) => {
+                let amount = amount.min(self.yes_supply).min(self.no_supply);
+                self.market.change_collateral(amount, false).unwrap();
+                self.vault -= amount;
+                self.yes_supply -= amount;
+                self.no_supply -= amount;
+            }
+            Trade::Swap { sell_yes, amount } => {
+                let amount = amount.min(*self.supply(sell_yes));
+                let out = self.market.swap(sell_yes, amount).unwrap();
+                *self.supply(sell_yes) -= amount;
+                *self.supply(!sell_yes) += out;
+            }
+            Trade::Buy { buy_yes, collateral } => {
+                let fee_bps = ParamKey::MarketFeeBps.default_value() as u16;
+                let net = collateral - market_fee(collateral, fee_bps);
+                let out = self.market.buy(buy_yes, net).unwrap();
+                self.vault += net;
+                *self.supply(buy_yes) += out;
+            }
+            Trade::Sell { sell_yes, shares } => {
+                let shares = shares.min(*self.supply(sell_yes));
+                let released = self.market.sell(sell_yes, shares).unwrap();
+                self.vault -= released;
+                *self.supply(sell_yes) -= shares;
+            }
+        }
+    }
+
+    fn product(&self) -> u128 {
+        self.market.yes_reserve as u128 * self.market.no_reserve as u128
+    }
+}
+
+proptest! {
+    #[test]
+    fn trades_never_mint_unbacked_shares(
+        liquidity in MIN_MARKET_LIQUIDITY..100 * MIN_MARKET_LIQUIDITY,
+        trades in proptest::collection::vec(trade(), 1..40),
+    ) {
+        let mut model = Model::new(liquidity);
+        for trade in trades {
+            let product = model.product();
+            let moves_sets = matches!(trade, Trade::MintSets(_) | Trade::MergeSets(_));
+            model.apply(trade);
+
+            prop_assert!(model.market.is_backed(model.yes_supply, model.no_supply, None));
+            prop_assert_eq!(model.vault, model.market.collateral);
+            if !moves_sets {
+                prop_assert!(model.product() >= product);
+            }
+        }
+    }
+
+    #[test]
+    fn settlement_leaves_exactly_the_winning_supply(
+        trades in proptest::collection::vec(trade(), 1..40),
+        yes_won in any::<bool>(),
+    ) {
+        let mut model = Model::new(MIN_MARKET_LIQUIDITY);
+        for trade in trades {
+            model.apply(trade);
+        }
+
+        model.market.resolve(yes_won).unwrap();
+        let paid = model.market.settle().unwrap();
+        model.vault -= paid;
+
+        let winning_supply = if yes_won { model.yes_supply } else { model.no_supply };
+        prop_assert!(model.market.is_backed(model.yes_supply, model.no_supply, Some(yes_won)));
+        prop_assert_eq!(model.vault, winning_supply);
+    }
+}
+
+#[test]
+fn selling_what_was_just_bought_returns_less_than_paid() {
+    let mut model = Model::new(MIN_MARKET_LIQUIDITY);
+    model.apply(Trade::Buy { buy_yes: true, collateral: 1_000_000 });
+    let shares = model.yes_supply;
+    let released = model.market.sell(true, shares).unwrap();
+    assert!(released < 1_000_000);
+}
@@ -0,0 +1,54 @@
+//! A proposal may only be executed if its outcome is evaluated while the
+//! voters' stake is still locked, so losing votes can be slashed to pay the
+//! winners.
+
+use anchor_lang::AnchorDeserialize;
+use ars_core::{
+    OutcomeCriterion, OutcomeMetric, PolicyProposal, ProposalStatus, MAX_OUTCOME_HORIZON,
+    PROPOSAL_RETENTION,
+};
+
+const END_TIME: i64 = 1_700_000_000;
+const EPOCH_DURATION: i64 = 86_400;
+
+fn proposal(horizon: i64) -> PolicyProposal {
+    let zeroed = vec![0u8; PolicyProposal::LEN - 8];
+    let mut proposal = PolicyProposal::deserialize(&mut zeroed.as_slice()).unwrap();
+    proposal.end_time = END_TIME;
+    proposal.outcome_criterion = OutcomeCriterion {
+        metric: OutcomeMetric::Ili,
+        horizon,
+    };
+    proposal
+}
+
+#[test]
+fn longest_horizon_fits_the_vote_lock() {
+    let lock_end = END_TIME + PROPOSAL_RETENTION;
+    let mut proposal = proposal(MAX_OUTCOME_HORIZON);
+
+    proposal
+        .mark_executed(100, lock_end - MAX_OUTCOME_HORIZON, EPOCH_DURATION)
+        .unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+}
+
+#[test]
+fn execution_that_outlives_the_vote_lock_is_rejected() {
+    let lock_end = END_TIME + PROPOSAL_RETENTION;
+    let mut proposal = proposal(MAX_OUTCOME_HORIZON);
+
+    assert!(proposal
+        .mark_executed(100, lock_end - MAX_OUTCOME_HORIZON + 1, EPOCH_DURATION)
+        .is_err());
+    assert_ne!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.executed_at, 0);
+}
+
+#[test]
+fn default_horizon_counts_epochs() {
+    // 7 epochs of 5 days outlast the 30-day lock even when executed at once
+    let mut proposal = proposal(0);
+    assert!(proposal.mark_executed(100, END_TIME, 5 * EPOCH_DURATION).is_err());
+    proposal.mark_executed(100, END_TIME, EPOCH_DURATION).unwrap();
+}
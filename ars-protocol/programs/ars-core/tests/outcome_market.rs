@@ -0,0 +1,56 @@
+//! The outcome market's constant-product pool moves the YES price with
+//! trades, never lets its invariant shrink, and burns only the losing reserve
+//! at resolution.
+
+use anchor_lang::prelude::Pubkey;
+use ars_core::{swap_out, OutcomeMarket, MIN_MARKET_LIQUIDITY};
+
+fn market() -> OutcomeMarket {
+    let mut market = OutcomeMarket::default();
+    market.open(Pubkey::new_unique(), MIN_MARKET_LIQUIDITY).unwrap();
+    market
+}
+
+#[test]
+fn new_market_prices_yes_at_even_odds() {
+    assert_eq!(market().yes_price_bps(), 5_000);
+}
+
+#[test]
+fn selling_no_raises_the_yes_price() {
+    let mut market = market();
+    let bought = market.swap(false, MIN_MARKET_LIQUIDITY).unwrap();
+    assert_eq!(bought, MIN_MARKET_LIQUIDITY / 2);
+    assert_eq!(market.yes_price_bps(), 8_000);
+}
+
+#[test]
+fn rounding_never_shrinks_the_product() {
+    for amount_in in [1, 7, 999, 123_456] {
+        let (reserve_in, reserve_out) = (1_000_003u64, 999_983u64);
+        let amount_out = swap_out(reserve_in, reserve_out, amount_in).unwrap();
+        let before = reserve_in as u128 * reserve_out as u128;
+        let after = (reserve_in + amount_in) as u128 * (reserve_out - amount_out) as u128;
+        assert!(after >= before);
+    }
+}
+
+#[test]
+fn resolution_burns_only_the_losing_reserve() {
+    let mut market = market();
+    market.swap(true, 1_000_000).unwrap();
+    let (yes_reserve, no_reserve) = (market.yes_reserve, market.no_reserve);
+
+    assert_eq!(market.resolve(true).unwrap(), no_reserve);
+    assert_eq!(market.no_reserve, 0);
+    assert_eq!(market.yes_reserve, yes_reserve);
+    assert!(market.require_trading(false).is_err());
+    assert!(market.resolve(true).is_err());
+}
+
+#[test]
+fn trading_stops_once_the_outcome_is_known() {
+    let market = market();
+    assert!(market.require_trading(false).is_ok());
+    assert!(market.require_trading(true).is_err());
+}
@@ -0,0 +1,64 @@
+//! Registry parameters read as their defaults until set, only change after
+//! the timelock, stay within their bounds and are only readable as their
+//! own kind.
+
+use ars_common::{ParamKey, ParamTable, MAX_REGISTRY_PARAMS};
+use ars_core::AgentTier;
+
+#[test]
+fn unset_keys_read_as_defaults() {
+    let table = ParamTable::default();
+    assert_eq!(table.bps(ParamKey::MarketFeeBps).unwrap(), 30);
+    assert_eq!(table.seconds(ParamKey::SlashAppealWindow).unwrap(), 24 * 60 * 60);
+    assert_eq!(table.amount(ParamKey::SlashConfirmationThreshold).unwrap(), 1_000_000_000);
+    assert_eq!(table.tier(ParamKey::IliSubmissionMinTier).unwrap(), AgentTier::Silver as u8);
+    assert_eq!(table.tier(ParamKey::BreakerTriggerMinTier).unwrap(), AgentTier::Platinum as u8);
+    assert_eq!(table.reputation(ParamKey::BreakerTriggerMinReputation).unwrap(), 100);
+}
+
+#[test]
+fn queued_value_applies_only_after_its_eta() {
+    let mut table = ParamTable::default();
+    table.propose(ParamKey::MarketFeeBps, 50, 100).unwrap();
+    assert_eq!(table.get(ParamKey::MarketFeeBps), 30);
+
+    assert!(table.apply(ParamKey::MarketFeeBps, 99).is_err());
+    assert_eq!(table.apply(ParamKey::MarketFeeBps, 100).unwrap(), (30, 50));
+    assert_eq!(table.bps(ParamKey::MarketFeeBps).unwrap(), 50);
+    assert_eq!(table.version, 1);
+
+    assert!(table.apply(ParamKey::MarketFeeBps, 200).is_err());
+}
+
+#[test]
+fn out_of_range_values_are_rejected() {
+    let mut table = ParamTable::default();
+    assert!(table.propose(ParamKey::MarketFeeBps, 501, 0).is_err());
+    assert!(table.propose(ParamKey::SlashAppealWindow, 60, 0).is_err());
+    // Above Platinum
+    assert!(table.propose(ParamKey::IliSubmissionMinTier, 4, 0).is_err());
+    assert!(table.entries.is_empty());
+}
+
+#[test]
+fn getters_check_the_kind() {
+    let table = ParamTable::default();
+    assert!(table.seconds(ParamKey::MarketFeeBps).is_err());
+    assert!(table.bps(ParamKey::SlashConfirmationThreshold).is_err());
+    assert!(table.reputation(ParamKey::BreakerTriggerMinTier).is_err());
+}
+
+#[test]
+fn keys_round_trip_through_their_ids() {
+    for key in [
+        ParamKey::MarketFeeBps,
+        ParamKey::SlashAppealWindow,
+        ParamKey::SlashConfirmationThreshold,
+        ParamKey::IliSubmissionMinTier,
+        ParamKey::BreakerTriggerMinTier,
+        ParamKey::BreakerTriggerMinReputation,
+    ] {
+        assert_eq!(ParamKey::try_from(key as u16).unwrap(), key);
+    }
+    assert!(ParamKey::try_from(MAX_REGISTRY_PARAMS as u16).is_err());
+}
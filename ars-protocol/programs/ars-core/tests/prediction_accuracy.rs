@@ -0,0 +1,81 @@
+//! Prediction accuracy over an agent's recent settled votes scales voting
+//! power between the configured floor and ceiling, and is neutral until
+//! enough votes have settled.
+
+use anchor_lang::prelude::Pubkey;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_core::{
+    accuracy_multiplier_bps, AgentStats, AGENT_STATS_EPOCHS, DEFAULT_ACCURACY_CEILING_BPS,
+    DEFAULT_ACCURACY_FLOOR_BPS, DEFAULT_ACCURACY_WINDOW, MIN_ACCURACY_SAMPLES,
+};
+use proptest::prelude::*;
+
+fn stats() -> AgentStats {
+    AgentStats {
+        agent: Pubkey::new_unique(),
+        epochs: [0; AGENT_STATS_EPOCHS],
+        submissions: [0; AGENT_STATS_EPOCHS],
+        successful_updates: [0; AGENT_STATS_EPOCHS],
+        prediction_history: 0,
+        prediction_count: 0,
+        bump: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN],
+    }
+}
+
+fn multiplier(accuracy_bps: u64) -> u64 {
+    accuracy_multiplier_bps(accuracy_bps, DEFAULT_ACCURACY_FLOOR_BPS, DEFAULT_ACCURACY_CEILING_BPS)
+}
+
+#[test]
+fn accuracy_is_neutral_below_the_minimum_sample() {
+    let mut stats = stats();
+    for _ in 0..MIN_ACCURACY_SAMPLES - 1 {
+        stats.record_prediction(true);
+    }
+    assert_eq!(stats.accuracy_bps(DEFAULT_ACCURACY_WINDOW), None);
+
+    stats.record_prediction(false);
+    assert_eq!(stats.accuracy_bps(DEFAULT_ACCURACY_WINDOW), Some(8_000));
+}
+
+#[test]
+fn only_the_window_counts() {
+    let mut stats = stats();
+    for _ in 0..10 {
+        stats.record_prediction(false);
+    }
+    for _ in 0..10 {
+        stats.record_prediction(true);
+    }
+    assert_eq!(stats.accuracy_bps(10), Some(10_000));
+    assert_eq!(stats.accuracy_bps(20), Some(5_000));
+    assert_eq!(stats.accuracy_bps(32), Some(5_000));
+}
+
+#[test]
+fn multiplier_spans_floor_to_ceiling() {
+    assert_eq!(multiplier(0), 5_000);
+    assert_eq!(multiplier(5_000), 10_000);
+    assert_eq!(multiplier(10_000), 15_000);
+}
+
+proptest! {
+    #[test]
+    fn multiplier_is_bounded_and_monotonic(a in 0u64..=10_000, b in 0u64..=10_000) {
+        let (low, high) = (a.min(b), a.max(b));
+        prop_assert!(multiplier(low) <= multiplier(high));
+        prop_assert!((5_000..=15_000).contains(&multiplier(a)));
+    }
+
+    #[test]
+    fn history_never_exceeds_the_window(outcomes in proptest::collection::vec(any::<bool>(), 0..100)) {
+        let mut stats = stats();
+        for correct in &outcomes {
+            stats.record_prediction(*correct);
+        }
+        if let Some(accuracy) = stats.accuracy_bps(DEFAULT_ACCURACY_WINDOW) {
+            prop_assert!(accuracy <= 10_000);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Committee draws pick distinct candidates deterministically from the seed,
+//! tie-breaks are a strict order, and the SlotHashes fallback resolves to the
+//! slot fixed at request time no matter when it is consumed.
+
+use anchor_lang::prelude::Pubkey;
+use ars_core::{select_committee, slot_hash_at, tie_break};
+use proptest::prelude::*;
+
+/// Raw SlotHashes data for `slots` (newest first), each hashed to [slot; 32]
+fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+    let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+    for slot in slots {
+        data.extend_from_slice(&slot.to_le_bytes());
+        data.extend_from_slice(&[*slot as u8; 32]);
+    }
+    data
+}
+
+#[test]
+fn fallback_uses_the_target_slot_while_it_is_in_the_sysvar() {
+    assert_eq!(slot_hash_at(&slot_hashes(&[105, 104, 103, 102]), 103), Some([103; 32]));
+    assert_eq!(slot_hash_at(&slot_hashes(&[110, 109, 104, 103]), 103), Some([103; 32]));
+}
+
+#[test]
+fn fallback_skips_to_the_next_produced_slot() {
+    assert_eq!(slot_hash_at(&slot_hashes(&[108, 106, 102, 101]), 103), Some([106; 32]));
+}
+
+#[test]
+fn fallback_is_unavailable_before_the_target_or_after_it_ages_out() {
+    assert_eq!(slot_hash_at(&slot_hashes(&[102, 101]), 103), None);
+    assert_eq!(slot_hash_at(&slot_hashes(&[108, 106, 105]), 103), None);
+}
+
+#[test]
+fn committee_is_capped_at_the_candidate_count() {
+    assert_eq!(select_committee(&[7; 32], 3, 5).len(), 3);
+    assert!(select_committee(&[7; 32], 0, 5).is_empty());
+}
+
+proptest! {
+    #[test]
+    fn committee_members_are_distinct_and_deterministic(
+        seed in any::<[u8; 32]>(),
+        n in 1usize..64,
+        k in 1usize..16,
+    ) {
+        let committee = select_committee(&seed, n, k);
+        prop_assert_eq!(committee.len(), k.min(n));
+        prop_assert!(committee.iter().all(|&index| index < n));
+
+        let mut sorted = committee.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        prop_assert_eq!(sorted.len(), committee.len());
+
+        prop_assert_eq!(select_committee(&seed, n, k), committee);
+    }
+
+    #[test]
+    fn tie_break_picks_exactly_one_winner(
+        seed in any::<[u8; 32]>(),
+        a in any::<[u8; 32]>(),
+        b in any::<[u8; 32]>(),
+    ) {
+        prop_assume!(a != b);
+        let (a, b) = (Pubkey::new_from_array(a), Pubkey::new_from_array(b));
+        prop_assert_ne!(tie_break(&seed, &a, &b), tie_break(&seed, &b, &a));
+    }
+}
@@ -0,0 +1,41 @@
+//! Reputation scales quadratic voting power between the configured floor and
+//! ceiling, reaching them at ±reputation_span and staying flat beyond.
+
+use ars_core::{
+    reputation_multiplier_bps, DEFAULT_REPUTATION_CEILING_BPS, DEFAULT_REPUTATION_FLOOR_BPS,
+    DEFAULT_REPUTATION_SPAN,
+};
+use proptest::prelude::*;
+
+fn multiplier(reputation: i32) -> u64 {
+    reputation_multiplier_bps(
+        reputation,
+        DEFAULT_REPUTATION_FLOOR_BPS,
+        DEFAULT_REPUTATION_CEILING_BPS,
+        DEFAULT_REPUTATION_SPAN,
+    )
+}
+
+#[test]
+fn neutral_reputation_keeps_plain_quadratic_power() {
+    assert_eq!(multiplier(0), 10_000);
+}
+
+#[test]
+fn multiplier_saturates_at_the_span() {
+    let span = DEFAULT_REPUTATION_SPAN as i32;
+    assert_eq!(multiplier(span), 15_000);
+    assert_eq!(multiplier(span * 10), 15_000);
+    assert_eq!(multiplier(-span), 5_000);
+    assert_eq!(multiplier(i32::MIN), 5_000);
+    assert_eq!(multiplier(span / 2), 12_500);
+}
+
+proptest! {
+    #[test]
+    fn multiplier_is_bounded_and_monotonic(a in any::<i32>(), b in any::<i32>()) {
+        let (low, high) = (a.min(b), a.max(b));
+        prop_assert!(multiplier(low) <= multiplier(high));
+        prop_assert!((5_000..=15_000).contains(&multiplier(a)));
+    }
+}
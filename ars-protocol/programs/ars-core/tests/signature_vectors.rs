@@ -0,0 +1,116 @@
+//! Checks ars-core's agent message builders against the canonical vectors in
+//! test-vectors/agent_messages.json (shared with external agent implementations).
+
+use anchor_lang::prelude::Pubkey;
+use ars_core::signing::{
+    burn_message, check_agent_message_format, ili_update_message, legacy, mint_message,
+    vote_message,
+};
+use ars_core::{check_signed_agent_message, ErrorCode, Secp256k1Signature};
+use ed25519_dalek::{PublicKey, SecretKey, Signature, Verifier};
+use serde_json::Value;
+
+const VECTORS: &str = include_str!("../../../test-vectors/agent_messages.json");
+
+fn field<T: std::str::FromStr>(inputs: &Value, name: &str) -> T {
+    inputs[name]
+        .as_str()
+        .unwrap_or_else(|| panic!("missing input {name}"))
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid input {name}"))
+}
+
+#[test]
+fn agent_message_vectors() {
+    let doc: Value = serde_json::from_str(VECTORS).unwrap();
+    let vectors = doc["vectors"].as_array().unwrap();
+    assert!(!vectors.is_empty());
+
+    for vector in vectors {
+        let key = &doc["keys"][vector["signer"].as_str().unwrap()];
+        let seed = hex::decode(key["seed_hex"].as_str().unwrap()).unwrap();
+        let pubkey_bytes = hex::decode(key["pubkey_hex"].as_str().unwrap()).unwrap();
+
+        let secret = SecretKey::from_bytes(&seed).unwrap();
+        let public = PublicKey::from(&secret);
+        assert_eq!(public.as_bytes().as_slice(), pubkey_bytes.as_slice());
+
+        let agent = Pubkey::try_from(pubkey_bytes.as_slice()).unwrap();
+        let inputs = &vector["inputs"];
+        let is_legacy = match vector["format"].as_str().unwrap() {
+            "v1" => false,
+            "legacy" => true,
+            other => panic!("unknown vector format {other}"),
+        };
+        let message = match (vector["kind"].as_str().unwrap(), is_legacy) {
+            ("ili_update", false) => ili_update_message(
+                &agent,
+                field(inputs, "ili_value"),
+                field(inputs, "timestamp"),
+            ),
+            ("ili_update", true) => legacy::ili_update_message(
+                &agent,
+                field(inputs, "ili_value"),
+                field(inputs, "timestamp"),
+            ),
+            ("mint", false) => mint_message(&agent, field(inputs, "amount"), field(inputs, "epoch")),
+            ("mint", true) => {
+                legacy::mint_message(&agent, field(inputs, "amount"), field(inputs, "epoch"))
+            }
+            ("burn", false) => burn_message(&agent, field(inputs, "amount"), field(inputs, "epoch")),
+            ("burn", true) => {
+                legacy::burn_message(&agent, field(inputs, "amount"), field(inputs, "epoch"))
+            }
+            ("vote", false) => vote_message(
+                &agent,
+                field(inputs, "proposal_id"),
+                inputs["vote_yes"].as_bool().unwrap(),
+                field(inputs, "stake_amount"),
+            ),
+            ("vote", true) => legacy::vote_message(
+                &agent,
+                field(inputs, "proposal_id"),
+                inputs["vote_yes"].as_bool().unwrap(),
+                field(inputs, "stake_amount"),
+            ),
+            (other, _) => panic!("unknown vector kind {other}"),
+        };
+
+        // Versioned messages are always accepted; legacy ones only before the cutoff
+        assert!(check_agent_message_format(&message, 9, 10).is_ok());
+        assert_eq!(check_agent_message_format(&message, 10, 10).is_ok(), !is_legacy);
+
+        let expected = hex::decode(vector["message_hex"].as_str().unwrap()).unwrap();
+        assert_eq!(message, expected, "message mismatch for {vector}");
+
+        let signature_bytes = hex::decode(vector["signature_hex"].as_str().unwrap()).unwrap();
+        let signature = Signature::from_bytes(&signature_bytes).unwrap();
+        public.verify(&message, &signature).unwrap();
+    }
+}
+
+#[test]
+fn signed_legacy_ili_update_is_rejected_after_the_cutoff() {
+    let agent = Pubkey::new_unique();
+    let address = [7u8; 20];
+    let message = ili_update_message(&agent, 5_000, 1_700_000_000);
+    let legacy_message = legacy::ili_update_message(&agent, 5_000, 1_700_000_000);
+    let signed = |message: &[u8]| {
+        vec![Secp256k1Signature { eth_address: address, message: message.to_vec() }]
+    };
+    let check = |signatures: &[Secp256k1Signature], epoch| {
+        check_signed_agent_message(signatures, &address, &message, &legacy_message, epoch, 10)
+    };
+
+    assert!(check(&signed(&message), 9).is_ok());
+    assert!(check(&signed(&message), 10).is_ok());
+    assert!(check(&signed(&legacy_message), 9).is_ok());
+    assert_eq!(
+        check(&signed(&legacy_message), 10).unwrap_err(),
+        ErrorCode::LegacyMessageRejected.into()
+    );
+
+    // Another key's signature over the same bytes does not count
+    let other = vec![Secp256k1Signature { eth_address: [8u8; 20], message: message.clone() }];
+    assert_eq!(check(&other, 9).unwrap_err(), ErrorCode::InvalidSignature.into());
+}
@@ -0,0 +1,37 @@
+//! Proposal simulation scales reserve liabilities with ARU supply and leaves
+//! policies that do not change supply without effect.
+
+use anchor_lang::prelude::Pubkey;
+use ars_core::{project_policy, simulation_hash, vhr, PolicyParams};
+
+#[test]
+fn minting_dilutes_the_vhr() {
+    let params = PolicyParams::MintParams { amount: 1_000, destination: Pubkey::default() };
+    let (supply, projected) = project_policy(&params, 1_000, 3_000, 1_000);
+    assert_eq!(supply, 2_000);
+    assert_eq!(projected, 15_000);
+}
+
+#[test]
+fn burning_raises_the_vhr() {
+    let params = PolicyParams::BurnParams { amount: 500 };
+    let (supply, projected) = project_policy(&params, 1_000, 3_000, 1_000);
+    assert_eq!(supply, 500);
+    assert_eq!(projected, 60_000);
+}
+
+#[test]
+fn other_policies_leave_supply_and_vhr_unchanged() {
+    let params = PolicyParams::OverrideParams { ili_value: 1 };
+    let (supply, projected) = project_policy(&params, 1_000, 3_000, 1_000);
+    assert_eq!(supply, 1_000);
+    assert_eq!(projected, vhr(3_000, 1_000));
+}
+
+#[test]
+fn result_hash_commits_to_every_output() {
+    let proposal = Pubkey::new_unique();
+    let hash = simulation_hash(&proposal, &[1; 32], 1_000, 2_000, 30_000, 15_000, 7);
+    assert_ne!(hash, simulation_hash(&proposal, &[1; 32], 1_000, 2_000, 30_000, 15_001, 7));
+    assert_ne!(hash, simulation_hash(&proposal, &[1; 32], 1_000, 2_001, 30_000, 15_000, 7));
+}
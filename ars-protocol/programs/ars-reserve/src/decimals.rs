@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Largest decimals difference that can be scaled without overflowing u128
+const MAX_SCALE_DIFF: u8 = 24;
+
+/// Largest mint decimals accepted for a reserve asset
+pub const MAX_ASSET_DECIMALS: u8 = 18;
+
+/// Convert `amount` from `from_decimals` to `to_decimals` fixed point.
+/// Scaling down truncates toward zero.
+pub fn normalize_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64> {
+    let scaled = if from_decimals >= to_decimals {
+        (amount as u128) / pow10(from_decimals - to_decimals)?
+    } else {
+        (amount as u128)
+            .checked_mul(pow10(to_decimals - from_decimals)?)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+    };
+    u64::try_from(scaled).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Quote value of `balance` base units of a token with `decimals`, priced at
+/// `price` quote units (in the vault's quote_decimals) per whole token
+pub fn asset_quote_value(balance: u64, price: u64, decimals: u8) -> Result<u64> {
+    let value = (balance as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / pow10(decimals)?;
+    u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn pow10(exp: u8) -> Result<u128> {
+    require!(exp <= MAX_SCALE_DIFF, ErrorCode::InvalidDecimals);
+    Ok(10u128.pow(exp as u32))
+}
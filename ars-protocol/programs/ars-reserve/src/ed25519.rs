@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::errors::ErrorCode;
+
+/// Size of the Ed25519 instruction header (count + padding)
+const HEADER_LEN: usize = 2;
+
+/// Size of one Ed25519SignatureOffsets entry
+const OFFSETS_LEN: usize = 14;
+
+/// Instruction index meaning "the Ed25519 instruction itself"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// One signature checked by an Ed25519 precompile instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519Signature {
+    pub public_key: Pubkey,
+    pub message: Vec<u8>,
+}
+
+/// Ed25519SignatureOffsets as laid out by the precompile
+struct SignatureOffsets {
+    signature_offset: usize,
+    signature_instruction_index: u16,
+    public_key_offset: usize,
+    public_key_instruction_index: u16,
+    message_data_offset: usize,
+    message_data_size: usize,
+    message_instruction_index: u16,
+}
+
+impl SignatureOffsets {
+    fn parse(entry: &[u8]) -> Self {
+        let u16_at = |i: usize| u16::from_le_bytes([entry[i], entry[i + 1]]);
+        Self {
+            signature_offset: u16_at(0) as usize,
+            signature_instruction_index: u16_at(2),
+            public_key_offset: u16_at(4) as usize,
+            public_key_instruction_index: u16_at(6),
+            message_data_offset: u16_at(8) as usize,
+            message_data_size: u16_at(10) as usize,
+            message_instruction_index: u16_at(12),
+        }
+    }
+}
+
+/// Resolve `len` bytes at `offset` in the instruction the precompile read them from.
+/// Mirrors the precompile: CURRENT_INSTRUCTION refers to `own_data`, any other
+/// index is an instruction of the same transaction.
+fn referenced_bytes(
+    instructions_sysvar: &AccountInfo,
+    own_data: &[u8],
+    instruction_index: u16,
+    offset: usize,
+    len: usize,
+) -> Result<Vec<u8>> {
+    let end = offset.checked_add(len).ok_or(ErrorCode::InvalidSignature)?;
+
+    if instruction_index == CURRENT_INSTRUCTION {
+        return Ok(own_data
+            .get(offset..end)
+            .ok_or(ErrorCode::InvalidSignature)?
+            .to_vec());
+    }
+
+    let ix = load_instruction_at_checked(instruction_index as usize, instructions_sysvar)
+        .map_err(|_| error!(ErrorCode::InvalidSignature))?;
+    Ok(ix
+        .data
+        .get(offset..end)
+        .ok_or(ErrorCode::InvalidSignature)?
+        .to_vec())
+}
+
+/// Parse every signature checked by an Ed25519 precompile instruction.
+///
+/// Handles the full offsets table: any number of signatures, and public keys,
+/// signatures and messages referenced from other instructions of the
+/// transaction. The returned pubkey/message pairs are exactly the bytes the
+/// precompile verified.
+pub fn parse_ed25519_ix(
+    instructions_sysvar: &AccountInfo,
+    data: &[u8],
+) -> Result<Vec<Ed25519Signature>> {
+    require!(data.len() >= HEADER_LEN, ErrorCode::InvalidSignature);
+
+    let count = data[0] as usize;
+    require!(count > 0, ErrorCode::InvalidSignature);
+    require!(
+        data.len() >= HEADER_LEN + count * OFFSETS_LEN,
+        ErrorCode::InvalidSignature
+    );
+
+    let mut signatures = Vec::with_capacity(count);
+    for i in 0..count {
+        let start = HEADER_LEN + i * OFFSETS_LEN;
+        let offsets = SignatureOffsets::parse(&data[start..start + OFFSETS_LEN]);
+
+        // The signature bytes are not needed, but must resolve like the precompile's
+        referenced_bytes(
+            instructions_sysvar,
+            data,
+            offsets.signature_instruction_index,
+            offsets.signature_offset,
+            64,
+        )?;
+        let public_key = referenced_bytes(
+            instructions_sysvar,
+            data,
+            offsets.public_key_instruction_index,
+            offsets.public_key_offset,
+            32,
+        )?;
+        let message = referenced_bytes(
+            instructions_sysvar,
+            data,
+            offsets.message_instruction_index,
+            offsets.message_data_offset,
+            offsets.message_data_size,
+        )?;
+
+        signatures.push(Ed25519Signature {
+            public_key: Pubkey::try_from(public_key.as_slice())
+                .map_err(|_| error!(ErrorCode::InvalidSignature))?,
+            message,
+        });
+    }
+
+    Ok(signatures)
+}
+
+/// Verify that an Ed25519 precompile instruction earlier in the transaction
+/// checked `expected_message` signed by `expected_signer`.
+///
+/// The precompile itself performs the cryptographic check (the transaction
+/// fails if any signature is invalid); this helper only confirms that one of
+/// the verified pubkey/message pairs is the one this program expects.
+pub fn verify_ed25519_ix(
+    instructions_sysvar: &AccountInfo,
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::MissingSignatureInstruction);
+
+    let mut found_precompile = false;
+    for index in 0..current_index {
+        let ix = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        found_precompile = true;
+
+        let matched = parse_ed25519_ix(instructions_sysvar, &ix.data)?
+            .iter()
+            .any(|sig| sig.public_key == *expected_signer && sig.message == expected_message);
+        if matched {
+            return Ok(());
+        }
+    }
+
+    require!(found_precompile, ErrorCode::MissingSignatureInstruction);
+    err!(ErrorCode::InvalidSignature)
+}
@@ -22,4 +22,88 @@ pub enum ErrorCode {
     
     #[msg("Rebalance not needed")]
     RebalanceNotNeeded,
+    
+    #[msg("Unauthorized access")]
+    Unauthorized,
+    
+    #[msg("Fee exceeds maximum")]
+    InvalidFee,
+    
+    #[msg("Invalid insurance fund account")]
+    InvalidInsuranceFund,
+    
+    #[msg("Per-user withdrawal limit exceeded for the rolling window")]
+    WithdrawalLimitExceeded,
+    
+    #[msg("Redemption haircut exceeds maximum")]
+    InvalidHaircut,
+    
+    #[msg("Invalid referral account")]
+    InvalidReferralAccount,
+    
+    #[msg("Missing Ed25519 signature instruction")]
+    MissingSignatureInstruction,
+    
+    #[msg("Invalid Ed25519 signature")]
+    InvalidSignature,
+    
+    #[msg("Permit deadline has passed")]
+    PermitExpired,
+    
+    #[msg("Invalid permit nonce")]
+    InvalidNonce,
+    
+    #[msg("SOL vault must hold wrapped SOL")]
+    InvalidSolVault,
+    
+    #[msg("Target weights must sum to 10000 bps")]
+    InvalidWeights,
+    
+    #[msg("Asset price must be positive")]
+    InvalidPrice,
+    
+    #[msg("Liability rate exceeds maximum")]
+    InvalidLiabilityRate,
+    
+    #[msg("Quote decimals exceed maximum")]
+    InvalidQuoteDecimals,
+    
+    #[msg("Quote asset cannot change while the vault holds value or liabilities")]
+    QuoteAssetLocked,
+    
+    #[msg("Token decimals out of supported range")]
+    InvalidDecimals,
+    
+    #[msg("Invalid asset weight configuration")]
+    InvalidAssetWeights,
+    
+    #[msg("Flash borrow must be followed by flash_repay in the same transaction")]
+    FlashRepayMissing,
+    
+    #[msg("Flash borrow cannot be invoked through CPI")]
+    FlashLoanCpi,
+    
+    #[msg("No flash loan is open")]
+    NoFlashLoan,
+    
+    #[msg("Flash loan was not repaid in full")]
+    FlashLoanNotRepaid,
+    
+    #[msg("Program was built for a different profile")]
+    BuildProfileMismatch,
+    
+    #[msg("Token account is not held by the vault")]
+    InvalidVaultTokenAccount,
+    
+    #[msg("Depositor cannot be their own referrer")]
+    SelfReferral,
+    
+    #[msg("Epoch has not ended yet")]
+    EpochNotEnded,
+    
+    #[msg("Epochs must be snapshotted in order")]
+    SnapshotOutOfOrder,
+    
+    #[msg("Mint state is not the one registered with the vault")]
+    InvalidMintState,
 }
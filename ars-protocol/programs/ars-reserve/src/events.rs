@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct Deposited {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub vhr: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct Withdrawn {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub vhr: u16,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AssetValued {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub price: u64,
+    pub balance: u64,
+    pub value: u64,
+    pub weight_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultRevalued {
+    pub vault: Pubkey,
+    pub total_value: u64,
+    pub vhr: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct LiabilitiesAccrued {
+    pub vault: Pubkey,
+    pub interest: u64,
+    pub liabilities: u64,
+    pub vhr: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BridgedSupplySynced {
+    pub vault: Pubkey,
+    pub bridged_supply: u64,
+    pub bridged_liabilities: u64,
+    pub vhr: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ShortfallCovered {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub value: u64,
+    pub vhr: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct FlashLoanRepaid {
+    pub vault: Pubkey,
+    pub borrower: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct SnapshotEpochsSkipped {
+    pub vault: Pubkey,
+    pub from_epoch: u64,
+    pub to_epoch: u64,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+
+use crate::errors::ErrorCode;
+
+/// Fee for borrowing `amount`, rounded up so no loan is free while the fee
+/// is non-zero
+pub fn flash_loan_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .div_ceil(10_000);
+    Ok(u64::try_from(fee).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?)
+}
+
+/// Require that flash_borrow is a top-level instruction (not a CPI) and that
+/// a later top-level instruction of the same transaction is a flash_repay
+/// against `vault`. The repayment itself is checked by flash_repay; if it is
+/// missing or fails, the runtime reverts the borrow with it.
+pub fn require_flash_repay(instructions_sysvar: &AccountInfo, vault: &Pubkey) -> Result<()> {
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    let current_ix = load_instruction_at_checked(current, instructions_sysvar)?;
+    require_keys_eq!(current_ix.program_id, crate::ID, ErrorCode::FlashLoanCpi);
+
+    let mut index = current + 1;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        if ix.program_id == crate::ID
+            && ix.data.get(..8) == Some(&crate::instruction::FlashRepay::DISCRIMINATOR[..])
+            && ix.accounts.first().map(|meta| meta.pubkey) == Some(*vault)
+        {
+            return Ok(());
+        }
+        index += 1;
+    }
+
+    err!(ErrorCode::FlashRepayMissing)
+}
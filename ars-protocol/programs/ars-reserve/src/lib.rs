@@ -1,13 +1,39 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{
+    self, spl_token::native_mint, CloseAccount, Mint, SyncNative, Token, TokenAccount, Transfer,
+};
+use ars_common::{log_event, trace, ACCOUNT_RESERVED_LEN};
 
 declare_id!("ARS7PfJZeYAhsYGvR68ccZEpoXWHLYvJ3YbKoG5GHb5o");
 
+/// Whether this build includes the devnet-only instructions (`devnet` feature,
+/// see ars-core's DEVNET_BUILD)
+pub const DEVNET_BUILD: bool = cfg!(feature = "devnet");
+
 pub mod state;
 pub mod errors;
+pub mod events;
+pub mod ed25519;
+pub mod decimals;
+pub mod flash_loan;
 
 pub use state::*;
 pub use errors::ErrorCode;
+pub use events::*;
+pub use ed25519::*;
+pub use decimals::*;
+pub use flash_loan::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_security_txt::security_txt! {
+    name: "ARS Reserve",
+    project_url: "https://github.com/protocoldaemon-sec/agentic-reserve-system",
+    contacts: "link:https://github.com/protocoldaemon-sec/agentic-reserve-system/security/advisories/new",
+    policy: "https://github.com/protocoldaemon-sec/agentic-reserve-system/blob/main/ars-protocol/SECURITY.md",
+    source_code: "https://github.com/protocoldaemon-sec/agentic-reserve-system",
+    source_release: env!("CARGO_PKG_VERSION")
+}
 
 #[program]
 pub mod ars_reserve {
@@ -28,12 +54,37 @@ pub mod ars_reserve {
         vault.sol_vault = ctx.accounts.sol_vault.key();
         vault.msol_vault = ctx.accounts.msol_vault.key();
         vault.jitosol_vault = ctx.accounts.jitosol_vault.key();
-        vault.total_value_usd = 0;
-        vault.liabilities_usd = 0;
+        vault.total_value = 0;
+        vault.liabilities = 0;
         vault.vhr = u16::MAX;
         vault.last_rebalance = 0;
         vault.rebalance_threshold_bps = rebalance_threshold_bps;
         vault.min_vhr = min_vhr;
+        vault.fees_collected = 0;
+        vault.deposit_fee_bps = 0;
+        vault.withdraw_fee_bps = 0;
+        vault.insurance_fund = ctx.accounts.authority.key();
+        vault.user_withdraw_limit = 0;
+        vault.user_withdraw_limit_bps = 0;
+        vault.target_vhr = min_vhr;
+        vault.max_redemption_haircut_bps = 0;
+        vault.referral_share_bps = 0;
+        vault.locked = false;
+        vault.governance = ctx.accounts.authority.key();
+        vault.target_weights_bps = [0; 4];
+        vault.liability_rate_bps = 0;
+        vault.last_accrual = Clock::get()?.unix_timestamp;
+        vault.quote_feed = Pubkey::default();
+        vault.quote_decimals = DEFAULT_QUOTE_DECIMALS;
+        vault.flash_loan_fee_bps = DEFAULT_FLASH_LOAN_FEE_BPS;
+        vault.flash_loan_token_account = Pubkey::default();
+        vault.flash_loan_repayment = 0;
+        vault.flash_loan_fee = 0;
+        vault.cooldown_ends = 0;
+        vault.snapshot_fees_collected = 0;
+        vault.next_snapshot_epoch = 0;
+        vault.aru_mint_state = Pubkey::default();
+        vault.bridged_liabilities = 0;
         vault.bump = ctx.bumps.vault;
 
         Ok(())
@@ -42,10 +93,45 @@ pub mod ars_reserve {
     pub fn deposit(
         ctx: Context<Deposit>,
         amount: u64,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
         require!(amount > 0, ErrorCode::InvalidAmount);
         
         let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
+        
+        let fee = calculate_fee(amount, vault.deposit_fee_bps)?;
+        let net_amount = amount
+            .checked_sub(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Referrer's cut of the fee is held in the vault until claimed
+        let referral_cut = match referrer {
+            Some(referrer) => {
+                require!(referrer != ctx.accounts.user.key(), ErrorCode::SelfReferral);
+                let referral = ctx.accounts.referral_account
+                    .as_mut()
+                    .ok_or(ErrorCode::InvalidReferralAccount)?;
+                require!(
+                    referral.referrer == referrer
+                        && referral.mint == ctx.accounts.vault_token_account.mint,
+                    ErrorCode::InvalidReferralAccount
+                );
+                
+                let cut = calculate_fee(fee, vault.referral_share_bps)?;
+                referral.accrued = referral.accrued
+                    .checked_add(cut)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                referral.total_earned = referral.total_earned
+                    .checked_add(cut)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                cut
+            }
+            None => 0,
+        };
+        let insurance_fee = fee
+            .checked_sub(referral_cut)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
         
         token::transfer(
             CpiContext::new(
@@ -56,17 +142,50 @@ pub mod ars_reserve {
                     authority: ctx.accounts.user.to_account_info(),
                 },
             ),
-            amount,
+            net_amount
+                .checked_add(referral_cut)
+                .ok_or(ErrorCode::ArithmeticOverflow)?,
         )?;
         
-        // Simplified: assume 1:1 USD for now
-        let value_usd = amount;
+        if insurance_fee > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user_token_account.to_account_info(),
+                        to: ctx.accounts.insurance_fund_token_account.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                insurance_fee,
+            )?;
+        }
         
-        vault.total_value_usd = vault.total_value_usd
-            .checked_add(value_usd)
+        // Simplified: one whole token is worth one quote unit until revalued
+        let value = normalize_amount(
+            net_amount,
+            ctx.accounts.asset_config.decimals,
+            vault.quote_decimals,
+        )?;
+        
+        vault.total_value = vault.total_value
+            .checked_add(value)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.fees_collected = vault.fees_collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
         
-        vault.vhr = calculate_vhr(vault.total_value_usd, vault.liabilities_usd)?;
+        log_event!(Deposited {
+            user: ctx.accounts.user.key(),
+            mint: ctx.accounts.vault_token_account.mint,
+            amount,
+            fee,
+            vhr: vault.vhr,
+        });
+        
+        ars_common::unlock(vault);
         
         Ok(())
     }
@@ -76,21 +195,26 @@ pub mod ars_reserve {
         amount: u64,
     ) -> Result<()> {
         let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
         
         require!(
             amount <= ctx.accounts.vault_token_account.amount,
             ErrorCode::InsufficientBalance
         );
         
-        let value_usd = amount;
-        
-        let new_total_value = vault.total_value_usd
-            .checked_sub(value_usd)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.accrue_liabilities(Clock::get()?.unix_timestamp)?;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
         
-        let new_vhr = calculate_vhr(new_total_value, vault.liabilities_usd)?;
+        let quote = quote_withdrawal(vault, amount, ctx.accounts.asset_config.decimals)?;
         
-        require!(new_vhr >= vault.min_vhr, ErrorCode::VHRTooLow);
+        record_withdrawal(
+            &mut ctx.accounts.withdrawal_tracker,
+            vault,
+            ctx.accounts.user.key(),
+            ctx.bumps.withdrawal_tracker,
+            quote.gross_value,
+            Clock::get()?.unix_timestamp,
+        )?;
         
         let vault_seeds = &[
             b"vault",
@@ -109,18 +233,82 @@ pub mod ars_reserve {
                 },
                 signer,
             ),
-            amount,
+            quote.net_amount,
         )?;
         
-        vault.total_value_usd = new_total_value;
-        vault.vhr = new_vhr;
+        if quote.fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_token_account.to_account_info(),
+                        to: ctx.accounts.insurance_fund_token_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer,
+                ),
+                quote.fee,
+            )?;
+        }
+        
+        vault.total_value = quote.new_total_value;
+        vault.fees_collected = vault.fees_collected
+            .checked_add(quote.fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.vhr = quote.new_vhr;
+        
+        log_event!(Withdrawn {
+            user: ctx.accounts.user.key(),
+            mint: ctx.accounts.vault_token_account.mint,
+            amount,
+            fee: quote.fee,
+            vhr: vault.vhr,
+        });
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+
+    /// Register a reserve asset, recording its mint decimals so deposits and
+    /// withdrawals can be normalized into the vault's quote units
+    pub fn register_asset(
+        ctx: Context<RegisterAsset>,
+        target_weight_bps: u16,
+        min_weight_bps: u16,
+        max_weight_bps: u16,
+        volatility_threshold_bps: u16,
+        oracle_source: Pubkey,
+    ) -> Result<()> {
+        require!(
+            min_weight_bps <= target_weight_bps
+                && target_weight_bps <= max_weight_bps
+                && max_weight_bps <= 10_000,
+            ErrorCode::InvalidAssetWeights
+        );
+        require!(
+            ctx.accounts.mint.decimals <= MAX_ASSET_DECIMALS,
+            ErrorCode::InvalidDecimals
+        );
+        
+        let asset_config = &mut ctx.accounts.asset_config;
+        asset_config.mint = ctx.accounts.mint.key();
+        asset_config.vault = ctx.accounts.vault.key();
+        asset_config.decimals = ctx.accounts.mint.decimals;
+        asset_config.target_weight_bps = target_weight_bps;
+        asset_config.min_weight_bps = min_weight_bps;
+        asset_config.max_weight_bps = max_weight_bps;
+        asset_config.volatility_threshold_bps = volatility_threshold_bps;
+        asset_config.current_weight_bps = 0;
+        asset_config.oracle_source = oracle_source;
+        asset_config.bump = ctx.bumps.asset_config;
         
         Ok(())
     }
 
     pub fn rebalance(
         ctx: Context<Rebalance>,
-        _amount: u64,
+        target_weights_bps: [u16; 4],
     ) -> Result<()> {
         let vault = &mut ctx.accounts.vault;
         
@@ -128,108 +316,1765 @@ pub mod ars_reserve {
             vault.vhr < vault.rebalance_threshold_bps,
             ErrorCode::RebalanceNotNeeded
         );
+        require!(
+            target_weights_bps.iter().map(|w| *w as u32).sum::<u32>() == 10_000,
+            ErrorCode::InvalidWeights
+        );
         
         // Simplified rebalancing logic
+        vault.target_weights_bps = target_weights_bps;
         vault.last_rebalance = Clock::get()?.unix_timestamp;
-        vault.vhr = calculate_vhr(vault.total_value_usd, vault.liabilities_usd)?;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
         
         Ok(())
     }
-}
 
-fn calculate_vhr(total_value_usd: u64, liabilities_usd: u64) -> Result<u16> {
-    if liabilities_usd == 0 {
-        return Ok(u16::MAX);
+    /// Mark the four reserve assets to market. `prices` is the price of one
+    /// whole token in the vault's quote asset (quote_decimals), in vault
+    /// order: USDC, SOL, mSOL, JitoSOL. Emits an AssetValued event per asset
+    /// before updating the vault's total value and VHR.
+    pub fn revalue_vault(
+        ctx: Context<RevalueVault>,
+        prices: [u64; 4],
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        // Balances are short while a flash loan is open
+        require!(!accounts.vault.locked, ars_common::CommonError::Reentrancy);
+        let assets = [
+            (&accounts.usdc_vault, &accounts.usdc_mint),
+            (&accounts.sol_vault, &accounts.sol_mint),
+            (&accounts.msol_vault, &accounts.msol_mint),
+            (&accounts.jitosol_vault, &accounts.jitosol_mint),
+        ];
+        
+        let mut values = [0u64; 4];
+        let mut total_value: u64 = 0;
+        for (i, (token_account, mint)) in assets.iter().enumerate() {
+            require!(prices[i] > 0, ErrorCode::InvalidPrice);
+            values[i] = asset_quote_value(token_account.amount, prices[i], mint.decimals)?;
+            total_value = total_value
+                .checked_add(values[i])
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        let timestamp = Clock::get()?.unix_timestamp;
+        for (i, (token_account, mint)) in assets.iter().enumerate() {
+            let weight_bps = if total_value == 0 {
+                0
+            } else {
+                ((values[i] as u128) * 10_000 / total_value as u128) as u16
+            };
+            log_event!(AssetValued {
+                vault: accounts.vault.key(),
+                mint: mint.key(),
+                price: prices[i],
+                balance: token_account.amount,
+                value: values[i],
+                weight_bps,
+                timestamp,
+            });
+        }
+        
+        let vault = &mut ctx.accounts.vault;
+        vault.total_value = total_value;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        log_event!(VaultRevalued {
+            vault: vault.key(),
+            total_value,
+            vhr: vault.vhr,
+            timestamp,
+        });
+        
+        Ok(())
     }
-    
-    let ratio = total_value_usd
-        .checked_mul(10000)
-        .ok_or(ErrorCode::ArithmeticOverflow)?
-        .checked_div(liabilities_usd)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    
-    Ok(ratio as u16)
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = ReserveVault::LEN,
-        seeds = [b"vault", authority.key().as_ref()],
-        bump
-    )]
-    pub vault: Account<'info, ReserveVault>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    /// CHECK: USDC vault token account
-    pub usdc_vault: AccountInfo<'info>,
-    
-    /// CHECK: SOL vault token account
-    pub sol_vault: AccountInfo<'info>,
-    
-    /// CHECK: mSOL vault token account
-    pub msol_vault: AccountInfo<'info>,
-    
-    /// CHECK: JitoSOL vault token account
-    pub jitosol_vault: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Permissionless crank: record the closed ars-token epoch
+    /// next_snapshot_epoch for off-chain accounting. Supply figures come from
+    /// the epoch's history; reserve balances, value, liabilities and VHR are
+    /// read when the crank runs, not at epoch_end.
+    pub fn snapshot_epoch(
+        ctx: Context<SnapshotEpoch>,
+        epoch_number: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let epoch_history = &ctx.accounts.epoch_history;
+        let snapshot = &mut ctx.accounts.snapshot;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(!vault.locked, ars_common::CommonError::Reentrancy);
+        require!(epoch_history.end_time <= current_time, ErrorCode::EpochNotEnded);
+        // Fees are attributed to the epoch being snapshotted, so epochs are
+        // snapshotted one after another; only the authority can skip any
+        require!(epoch_number == vault.next_snapshot_epoch, ErrorCode::SnapshotOutOfOrder);
+        
+        snapshot.epoch_number = epoch_number;
+        snapshot.epoch_start = epoch_history.start_time;
+        snapshot.epoch_end = epoch_history.end_time;
+        snapshot.snapshot_time = current_time;
+        snapshot.total_supply = epoch_history.final_supply;
+        snapshot.total_minted = epoch_history.total_minted;
+        snapshot.total_burned = epoch_history.total_burned;
+        snapshot.usdc_balance = ctx.accounts.usdc_vault.amount;
+        snapshot.sol_balance = ctx.accounts.sol_vault.amount;
+        snapshot.msol_balance = ctx.accounts.msol_vault.amount;
+        snapshot.jitosol_balance = ctx.accounts.jitosol_vault.amount;
+        snapshot.total_value = vault.total_value;
+        snapshot.liabilities = vault.total_liabilities()?;
+        snapshot.vhr = vault.vhr;
+        snapshot.fees_collected = vault.fees_collected
+            .checked_sub(vault.snapshot_fees_collected)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        snapshot.bump = ctx.bumps.snapshot;
+        
+        vault.snapshot_fees_collected = vault.fees_collected;
+        vault.next_snapshot_epoch = epoch_number
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct Deposit<'info> {
-    #[account(
-        mut,
-        seeds = [b"vault", vault.authority.as_ref()],
-        bump = vault.bump
-    )]
-    pub vault: Account<'info, ReserveVault>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
-    
-    pub token_program: Program<'info, Token>,
-}
+    pub fn set_fees(
+        ctx: Context<UpdateVaultConfig>,
+        deposit_fee_bps: u16,
+        withdraw_fee_bps: u16,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(deposit_fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFee);
+        require!(withdraw_fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFee);
+        
+        vault.deposit_fee_bps = deposit_fee_bps;
+        vault.withdraw_fee_bps = withdraw_fee_bps;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct Withdraw<'info> {
-    #[account(
-        mut,
-        seeds = [b"vault", vault.authority.as_ref()],
-        bump = vault.bump
-    )]
-    pub vault: Account<'info, ReserveVault>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
-    
-    pub token_program: Program<'info, Token>,
-}
+    pub fn set_flash_loan_fee(
+        ctx: Context<UpdateVaultConfig>,
+        flash_loan_fee_bps: u16,
+    ) -> Result<()> {
+        require!(flash_loan_fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFee);
+        
+        ctx.accounts.vault.flash_loan_fee_bps = flash_loan_fee_bps;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct Rebalance<'info> {
-    #[account(
-        mut,
-        seeds = [b"vault", vault.authority.as_ref()],
-        bump = vault.bump
-    )]
-    pub vault: Account<'info, ReserveVault>,
-    
-    pub authority: Signer<'info>,
+    /// Lend `amount` of an idle reserve asset for the rest of the
+    /// transaction. A later flash_repay instruction in the same transaction
+    /// must restore the vault balance and pay flash_loan_fee_bps to the
+    /// insurance fund. The vault stays locked in between, so no other vault
+    /// instruction can observe the borrowed balance.
+    pub fn flash_borrow(
+        ctx: Context<FlashBorrow>,
+        amount: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
+        
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            amount <= ctx.accounts.vault_token_account.amount,
+            ErrorCode::InsufficientBalance
+        );
+        require_flash_repay(&ctx.accounts.instructions_sysvar, &vault.key())?;
+        
+        vault.flash_loan_token_account = ctx.accounts.vault_token_account.key();
+        vault.flash_loan_repayment = ctx.accounts.vault_token_account.amount;
+        vault.flash_loan_fee = flash_loan_fee(amount, vault.flash_loan_fee_bps)?;
+        
+        let vault_seeds = &[
+            b"vault",
+            vault.authority.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&vault_seeds[..]];
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.borrower_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        
+        // Deliberately left locked: flash_repay releases the guard, and the
+        // transaction cannot succeed without it
+        Ok(())
+    }
+
+    /// Close the open flash loan: return the borrowed amount to the vault and
+    /// the fee to the insurance fund, then check the vault balance is whole
+    pub fn flash_repay(ctx: Context<FlashRepay>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            vault.locked && vault.flash_loan_token_account != Pubkey::default(),
+            ErrorCode::NoFlashLoan
+        );
+        
+        let repayment = vault.flash_loan_repayment;
+        let fee = vault.flash_loan_fee;
+        let amount = repayment.saturating_sub(ctx.accounts.vault_token_account.amount);
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.borrower_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.borrower.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        if fee > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.borrower_token_account.to_account_info(),
+                        to: ctx.accounts.insurance_fund_token_account.to_account_info(),
+                        authority: ctx.accounts.borrower.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+        
+        ctx.accounts.vault_token_account.reload()?;
+        require!(
+            ctx.accounts.vault_token_account.amount >= repayment,
+            ErrorCode::FlashLoanNotRepaid
+        );
+        
+        vault.fees_collected = vault.fees_collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.flash_loan_token_account = Pubkey::default();
+        vault.flash_loan_repayment = 0;
+        vault.flash_loan_fee = 0;
+        
+        log_event!(FlashLoanRepaid {
+            vault: vault.key(),
+            borrower: ctx.accounts.borrower.key(),
+            mint: ctx.accounts.vault_token_account.mint,
+            amount,
+            fee,
+        });
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+
+    /// Re-denominate the vault in another quote asset. Only allowed while the
+    /// vault holds no value or liabilities, since existing balances cannot be
+    /// converted on-chain.
+    pub fn set_quote_asset(
+        ctx: Context<UpdateVaultConfig>,
+        quote_feed: Pubkey,
+        quote_decimals: u8,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            quote_decimals <= MAX_QUOTE_DECIMALS,
+            ErrorCode::InvalidQuoteDecimals
+        );
+        require!(
+            vault.total_value == 0 && vault.total_liabilities()? == 0,
+            ErrorCode::QuoteAssetLocked
+        );
+        
+        vault.quote_feed = quote_feed;
+        vault.quote_decimals = quote_decimals;
+        
+        Ok(())
+    }
+
+    /// Set the annual liability rate, accruing at the old rate first
+    pub fn set_liability_rate(
+        ctx: Context<UpdateVaultConfig>,
+        liability_rate_bps: u16,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            liability_rate_bps <= MAX_LIABILITY_RATE_BPS,
+            ErrorCode::InvalidLiabilityRate
+        );
+        
+        vault.accrue_liabilities(Clock::get()?.unix_timestamp)?;
+        vault.liability_rate_bps = liability_rate_bps;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        Ok(())
+    }
+
+    /// Permissionless crank: accrue liability interest and refresh the VHR
+    pub fn accrue_interest(ctx: Context<AccrueInterest>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let now = Clock::get()?.unix_timestamp;
+        
+        let interest = vault.accrue_liabilities(now)?;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        trace!(
+            "liability_accrual",
+            interest = interest,
+            liabilities = vault.liabilities,
+            rate_bps = vault.liability_rate_bps,
+        );
+        
+        log_event!(LiabilitiesAccrued {
+            vault: vault.key(),
+            interest,
+            liabilities: vault.liabilities,
+            vhr: vault.vhr,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    /// Skip snapshots up to `next_snapshot_epoch`, e.g. epochs that ended
+    /// before the vault existed or whose history has been closed. Fees
+    /// collected meanwhile go to the next snapshot taken.
+    pub fn skip_snapshot_epochs(
+        ctx: Context<UpdateVaultConfig>,
+        next_snapshot_epoch: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            next_snapshot_epoch > vault.next_snapshot_epoch,
+            ErrorCode::SnapshotOutOfOrder
+        );
+        
+        log_event!(SnapshotEpochsSkipped {
+            vault: vault.key(),
+            from_epoch: vault.next_snapshot_epoch,
+            to_epoch: next_snapshot_epoch,
+        });
+        
+        vault.next_snapshot_epoch = next_snapshot_epoch;
+        
+        Ok(())
+    }
+
+    /// Point the vault at the ars-token MintState whose bridged-out supply it
+    /// backs. Bridged liabilities reset until the next sync_bridged_supply.
+    pub fn set_aru_mint_state(
+        ctx: Context<UpdateVaultConfig>,
+        aru_mint_state: Pubkey,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        vault.aru_mint_state = aru_mint_state;
+        vault.bridged_liabilities = 0;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        Ok(())
+    }
+
+    /// Permissionless crank: copy the net ARU bridged out of Solana (which
+    /// the remote mirrors redeem against this reserve) into the vault's
+    /// liabilities and refresh the VHR. Run after every bridge_out/bridge_in.
+    pub fn sync_bridged_supply(ctx: Context<SyncBridgedSupply>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let bridged_supply = ctx.accounts.mint_state.bridged_supply()?;
+        
+        // Same simplification as deposits: one whole ARU is one quote unit
+        vault.bridged_liabilities = normalize_amount(
+            bridged_supply,
+            ctx.accounts.aru_mint.decimals,
+            vault.quote_decimals,
+        )?;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        log_event!(BridgedSupplySynced {
+            vault: vault.key(),
+            bridged_supply,
+            bridged_liabilities: vault.bridged_liabilities,
+            vhr: vault.vhr,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    pub fn set_insurance_fund(
+        ctx: Context<UpdateVaultConfig>,
+        insurance_fund: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.vault.insurance_fund = insurance_fund;
+        
+        Ok(())
+    }
+
+    /// Hand rebalancing rights to `governance`, normally the ars-core
+    /// global_state PDA so that passed RebalanceVault proposals run via CPI
+    pub fn set_governance(
+        ctx: Context<UpdateVaultConfig>,
+        governance: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.vault.governance = governance;
+        
+        Ok(())
+    }
+
+    pub fn set_withdrawal_limits(
+        ctx: Context<UpdateVaultConfig>,
+        user_withdraw_limit: u64,
+        user_withdraw_limit_bps: u16,
+    ) -> Result<()> {
+        require!(user_withdraw_limit_bps <= 10000, ErrorCode::InvalidThreshold);
+        
+        let vault = &mut ctx.accounts.vault;
+        vault.user_withdraw_limit = user_withdraw_limit;
+        vault.user_withdraw_limit_bps = user_withdraw_limit_bps;
+        
+        Ok(())
+    }
+
+    /// Halve per-user withdrawal limits until `cooldown_ends`. Called by
+    /// governance when the ars-core circuit breaker clears.
+    pub fn set_breaker_cooldown(
+        ctx: Context<SetBreakerCooldown>,
+        cooldown_ends: i64,
+    ) -> Result<()> {
+        ctx.accounts.vault.cooldown_ends = cooldown_ends;
+        
+        Ok(())
+    }
+
+    /// Top the vault up from governance-held funds, such as the ars-core
+    /// insurance fund. No deposit fee is charged; the amount is added to
+    /// total_value and VHR is recomputed.
+    pub fn cover_shortfall(
+        ctx: Context<CoverShortfall>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.source_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.governance.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        let value = normalize_amount(
+            amount,
+            ctx.accounts.asset_config.decimals,
+            vault.quote_decimals,
+        )?;
+        
+        vault.total_value = vault.total_value
+            .checked_add(value)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        log_event!(ShortfallCovered {
+            vault: vault.key(),
+            mint: ctx.accounts.vault_token_account.mint,
+            amount,
+            value,
+            vhr: vault.vhr,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+
+    pub fn set_redemption_pricing(
+        ctx: Context<UpdateVaultConfig>,
+        target_vhr: u16,
+        max_redemption_haircut_bps: u16,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(target_vhr >= vault.min_vhr, ErrorCode::InvalidVHR);
+        require!(
+            max_redemption_haircut_bps <= MAX_REDEMPTION_HAIRCUT_BPS,
+            ErrorCode::InvalidHaircut
+        );
+        
+        vault.target_vhr = target_vhr;
+        vault.max_redemption_haircut_bps = max_redemption_haircut_bps;
+        
+        Ok(())
+    }
+
+    /// Simulate-only check of whether `user` could withdraw `amount` of the
+    /// vault token account's asset now, and if not, which limit binds
+    pub fn can_withdraw(
+        ctx: Context<CanWithdraw>,
+        amount: u64,
+        user: Pubkey,
+    ) -> Result<WithdrawVerdict> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut vault = (*ctx.accounts.vault).clone();
+        vault.accrue_liabilities(now)?;
+        
+        let tracker = match ctx.accounts.withdrawal_tracker.as_ref() {
+            Some(tracker) => (**tracker).clone(),
+            None => WithdrawalTracker {
+                vault: ctx.accounts.vault.key(),
+                user,
+                recent_withdrawn: 0,
+                last_withdrawal: 0,
+                bump: 0,
+                _reserved: [0; ACCOUNT_RESERVED_LEN],
+            },
+        };
+        let limit = user_withdraw_limit(&vault, now)?;
+        let recent_withdrawn = tracker.decayed_withdrawn(now);
+        let headroom = limit.saturating_sub(recent_withdrawn);
+        let verdict = |binding_limit, unblocks_at| WithdrawVerdict {
+            binding_limit,
+            headroom,
+            unblocks_at,
+        };
+        
+        if amount > ctx.accounts.vault_token_account.amount {
+            return Ok(verdict(Some(WithdrawLimit::VaultBalance), None));
+        }
+        let quote = price_withdrawal(&vault, amount, ctx.accounts.asset_config.decimals)?;
+        if quote.new_vhr < vault.min_vhr {
+            return Ok(verdict(Some(WithdrawLimit::MinVhr), None));
+        }
+        if quote.gross_value > headroom {
+            let mut unblocks_at = tracker.fits_at(quote.gross_value, limit, now);
+            if now < vault.cooldown_ends {
+                let full_limit = user_withdraw_limit(&vault, vault.cooldown_ends)?;
+                let after_cooldown = tracker
+                    .fits_at(quote.gross_value, full_limit, now)
+                    .map(|at| at.max(vault.cooldown_ends));
+                unblocks_at = match (unblocks_at, after_cooldown) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+            }
+            return Ok(verdict(Some(WithdrawLimit::UserWithdrawLimit), unblocks_at));
+        }
+        
+        Ok(verdict(None, None))
+    }
+
+    pub fn get_redemption_haircut(ctx: Context<ViewVault>) -> Result<u16> {
+        Ok(ctx.accounts.vault.redemption_haircut_bps())
+    }
+
+    /// Fail unless the deployed build matches the expected flavor, so clients
+    /// can check (e.g. by simulation) that a program id serves a mainnet build
+    /// without devnet-only instructions
+    pub fn assert_build_profile(_ctx: Context<AssertBuildProfile>, devnet: bool) -> Result<()> {
+        require!(devnet == DEVNET_BUILD, ErrorCode::BuildProfileMismatch);
+        Ok(())
+    }
+
+    pub fn set_referral_share(
+        ctx: Context<UpdateVaultConfig>,
+        referral_share_bps: u16,
+    ) -> Result<()> {
+        require!(referral_share_bps <= MAX_REFERRAL_SHARE_BPS, ErrorCode::InvalidFee);
+        
+        ctx.accounts.vault.referral_share_bps = referral_share_bps;
+        
+        Ok(())
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        let referral = &mut ctx.accounts.referral_account;
+        
+        referral.vault = ctx.accounts.vault.key();
+        referral.referrer = ctx.accounts.referrer.key();
+        referral.mint = ctx.accounts.mint.key();
+        referral.accrued = 0;
+        referral.total_earned = 0;
+        referral.bump = ctx.bumps.referral_account;
+        
+        Ok(())
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
+        let referral = &mut ctx.accounts.referral_account;
+        
+        let amount = referral.accrued;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        let vault_seeds = &[
+            b"vault",
+            vault.authority.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&vault_seeds[..]];
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.referrer_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        
+        referral.accrued = 0;
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+
+    pub fn deposit_with_permit(
+        ctx: Context<DepositWithPermit>,
+        amount: u64,
+        deadline: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            Clock::get()?.unix_timestamp <= deadline,
+            ErrorCode::PermitExpired
+        );
+        
+        let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
+        let permit_nonce = &mut ctx.accounts.permit_nonce;
+        
+        require!(nonce == permit_nonce.next_nonce, ErrorCode::InvalidNonce);
+        
+        let message = deposit_permit_message(
+            &vault.key(),
+            &ctx.accounts.user.key(),
+            &ctx.accounts.user_token_account.mint,
+            &ctx.accounts.vault_token_account.key(),
+            amount,
+            deadline,
+            nonce,
+        );
+        verify_ed25519_ix(
+            &ctx.accounts.instructions_sysvar,
+            &ctx.accounts.user.key(),
+            &message,
+        )?;
+        
+        permit_nonce.vault = vault.key();
+        permit_nonce.user = ctx.accounts.user.key();
+        permit_nonce.next_nonce = nonce
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        permit_nonce.bump = ctx.bumps.permit_nonce;
+        
+        let fee = calculate_fee(amount, vault.deposit_fee_bps)?;
+        let net_amount = amount
+            .checked_sub(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // The user pre-approves the vault PDA as delegate on their token account
+        let vault_seeds = &[
+            b"vault",
+            vault.authority.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&vault_seeds[..]];
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer,
+            ),
+            net_amount,
+        )?;
+        
+        if fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user_token_account.to_account_info(),
+                        to: ctx.accounts.insurance_fund_token_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer,
+                ),
+                fee,
+            )?;
+        }
+        
+        let value = normalize_amount(
+            net_amount,
+            ctx.accounts.asset_config.decimals,
+            vault.quote_decimals,
+        )?;
+        vault.total_value = vault.total_value
+            .checked_add(value)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.fees_collected = vault.fees_collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        log_event!(Deposited {
+            user: ctx.accounts.user.key(),
+            mint: ctx.accounts.vault_token_account.mint,
+            amount,
+            fee,
+            vhr: vault.vhr,
+        });
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+
+    /// Deposit native SOL: wraps `amount` lamports through the user's wSOL ATA
+    /// and moves it into sol_vault. The ATA is closed afterwards unless it
+    /// already held wSOL before the deposit.
+    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        ars_common::lock(&mut ctx.accounts.vault, ctx.program_id)?;
+        
+        let prior_wsol = ctx.accounts.user_wsol_account.amount;
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.user_wsol_account.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.user_wsol_account.to_account_info(),
+            },
+        ))?;
+        
+        let vault = &mut ctx.accounts.vault;
+        let fee = calculate_fee(amount, vault.deposit_fee_bps)?;
+        let net_amount = amount
+            .checked_sub(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_wsol_account.to_account_info(),
+                    to: ctx.accounts.sol_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            net_amount,
+        )?;
+        
+        if fee > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user_wsol_account.to_account_info(),
+                        to: ctx.accounts.insurance_fund_token_account.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+        
+        if prior_wsol == 0 {
+            token::close_account(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: ctx.accounts.user_wsol_account.to_account_info(),
+                    destination: ctx.accounts.user.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ))?;
+        }
+        
+        // Simplified: one whole SOL is worth one quote unit until revalued
+        let value = normalize_amount(
+            net_amount,
+            ctx.accounts.native_mint.decimals,
+            vault.quote_decimals,
+        )?;
+        
+        vault.total_value = vault.total_value
+            .checked_add(value)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.fees_collected = vault.fees_collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        log_event!(Deposited {
+            user: ctx.accounts.user.key(),
+            mint: native_mint::id(),
+            amount,
+            fee,
+            vhr: vault.vhr,
+        });
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+
+    /// Withdraw native SOL: pays out wSOL from sol_vault to the user's wSOL ATA
+    /// and unwraps it by closing the ATA, unless it already held wSOL.
+    pub fn withdraw_sol(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        ars_common::lock(vault, ctx.program_id)?;
+        
+        require!(
+            amount <= ctx.accounts.sol_vault.amount,
+            ErrorCode::InsufficientBalance
+        );
+        
+        vault.accrue_liabilities(Clock::get()?.unix_timestamp)?;
+        vault.vhr = calculate_vhr(vault.total_value, vault.total_liabilities()?)?;
+        
+        let quote = quote_withdrawal(vault, amount, ctx.accounts.native_mint.decimals)?;
+        
+        record_withdrawal(
+            &mut ctx.accounts.withdrawal_tracker,
+            vault,
+            ctx.accounts.user.key(),
+            ctx.bumps.withdrawal_tracker,
+            quote.gross_value,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let prior_wsol = ctx.accounts.user_wsol_account.amount;
+        
+        let vault_seeds = &[
+            b"vault",
+            vault.authority.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&vault_seeds[..]];
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.user_wsol_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer,
+            ),
+            quote.net_amount,
+        )?;
+        
+        if quote.fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.sol_vault.to_account_info(),
+                        to: ctx.accounts.insurance_fund_token_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer,
+                ),
+                quote.fee,
+            )?;
+        }
+        
+        if prior_wsol == 0 {
+            token::close_account(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: ctx.accounts.user_wsol_account.to_account_info(),
+                    destination: ctx.accounts.user.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ))?;
+        }
+        
+        vault.total_value = quote.new_total_value;
+        vault.fees_collected = vault.fees_collected
+            .checked_add(quote.fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.vhr = quote.new_vhr;
+        
+        log_event!(Withdrawn {
+            user: ctx.accounts.user.key(),
+            mint: native_mint::id(),
+            amount,
+            fee: quote.fee,
+            vhr: vault.vhr,
+        });
+        
+        ars_common::unlock(vault);
+        
+        Ok(())
+    }
+}
+
+/// Payout and resulting vault state for a withdrawal of `amount`
+struct WithdrawalQuote {
+    fee: u64,
+    net_amount: u64,
+    /// `amount` in quote units, counted against withdrawal limits
+    gross_value: u64,
+    new_total_value: u64,
+    new_vhr: u16,
+}
+
+fn quote_withdrawal(vault: &ReserveVault, amount: u64, decimals: u8) -> Result<WithdrawalQuote> {
+    let quote = price_withdrawal(vault, amount, decimals)?;
+    require!(quote.new_vhr >= vault.min_vhr, ErrorCode::VHRTooLow);
+    Ok(quote)
+}
+
+/// quote_withdrawal without the min_vhr check
+fn price_withdrawal(vault: &ReserveVault, amount: u64, decimals: u8) -> Result<WithdrawalQuote> {
+    // Haircut stays in the vault to recapitalize the reserve
+    let haircut = calculate_fee(amount, vault.redemption_haircut_bps())?;
+    let fee = calculate_fee(amount, vault.withdraw_fee_bps)?;
+    let net_amount = amount
+        .checked_sub(haircut)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_sub(fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    
+    let gross_value = normalize_amount(amount, decimals, vault.quote_decimals)?;
+    let value = normalize_amount(
+        amount.checked_sub(haircut).ok_or(ErrorCode::ArithmeticOverflow)?,
+        decimals,
+        vault.quote_decimals,
+    )?;
+    
+    let new_total_value = vault.total_value
+        .checked_sub(value)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    
+    let new_vhr = calculate_vhr(new_total_value, vault.total_liabilities()?)?;
+    
+    trace!(
+        "withdrawal_quote",
+        amount = amount,
+        haircut = haircut,
+        fee = fee,
+        new_total_value = new_total_value,
+        new_vhr = new_vhr,
+        min_vhr = vault.min_vhr,
+    );
+    
+    Ok(WithdrawalQuote {
+        fee,
+        net_amount,
+        gross_value,
+        new_total_value,
+        new_vhr,
+    })
+}
+
+/// Count `value` (quote units) against the user's rolling withdrawal limit
+fn record_withdrawal(
+    tracker: &mut WithdrawalTracker,
+    vault: &Account<ReserveVault>,
+    user: Pubkey,
+    bump: u8,
+    value: u64,
+    now: i64,
+) -> Result<()> {
+    let recent_withdrawn = tracker.decayed_withdrawn(now)
+        .checked_add(value)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    
+    require!(
+        recent_withdrawn <= user_withdraw_limit(vault, now)?,
+        ErrorCode::WithdrawalLimitExceeded
+    );
+    
+    tracker.vault = vault.key();
+    tracker.user = user;
+    tracker.recent_withdrawn = recent_withdrawn;
+    tracker.last_withdrawal = now;
+    tracker.bump = bump;
+    
+    Ok(())
+}
+
+/// Effective per-user limit for the rolling window: the tighter of the
+/// absolute limit and the share-of-vault limit (0 disables either)
+fn user_withdraw_limit(vault: &ReserveVault, now: i64) -> Result<u64> {
+    let mut limit = u64::MAX;
+    
+    if vault.user_withdraw_limit > 0 {
+        limit = vault.user_withdraw_limit;
+    }
+    
+    if vault.user_withdraw_limit_bps > 0 {
+        let share_limit = vault.total_value
+            .checked_mul(vault.user_withdraw_limit_bps as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        limit = limit.min(share_limit);
+    }
+    
+    if now < vault.cooldown_ends && limit != u64::MAX {
+        limit /= 2;
+    }
+    
+    Ok(limit)
+}
+
+/// Domain-separated message a user signs off-chain to authorize a relayed
+/// deposit into `destination`
+pub fn deposit_permit_message(
+    vault: &Pubkey,
+    user: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+    deadline: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(DEPOSIT_PERMIT_DOMAIN.len() + 32 * 4 + 24);
+    message.extend_from_slice(DEPOSIT_PERMIT_DOMAIN);
+    message.extend_from_slice(vault.as_ref());
+    message.extend_from_slice(user.as_ref());
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(destination.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&deadline.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    Ok(amount
+        .checked_mul(fee_bps as u64)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::ArithmeticOverflow)?)
+}
+
+fn calculate_vhr(total_value: u64, liabilities: u64) -> Result<u16> {
+    if liabilities == 0 {
+        return Ok(u16::MAX);
+    }
+    
+    let ratio = total_value
+        .checked_mul(10000)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(liabilities)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    
+    trace!(
+        "vhr",
+        total_value = total_value,
+        liabilities = liabilities,
+        ratio = ratio,
+    );
+    
+    Ok(ratio as u16)
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ReserveVault::LEN,
+        seeds = [b"vault", authority.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: USDC vault token account
+    pub usdc_vault: AccountInfo<'info>,
+    
+    /// CHECK: SOL vault token account
+    pub sol_vault: AccountInfo<'info>,
+    
+    /// CHECK: mSOL vault token account
+    pub msol_vault: AccountInfo<'info>,
+    
+    /// CHECK: JitoSOL vault token account
+    pub jitosol_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"asset_config", vault.key().as_ref(), vault_token_account.mint.as_ref()],
+        bump = asset_config.bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+    
+    #[account(
+        mut,
+        constraint = insurance_fund_token_account.owner == vault.insurance_fund
+            @ ErrorCode::InvalidInsuranceFund,
+        constraint = insurance_fund_token_account.mint == vault_token_account.mint
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub insurance_fund_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"referral",
+            vault.key().as_ref(),
+            referral_account.referrer.as_ref(),
+            referral_account.mint.as_ref()
+        ],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(address = vault_token_account.mint)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        seeds = [b"asset_config", vault.key().as_ref(), vault_token_account.mint.as_ref()],
+        bump = asset_config.bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+    
+    /// Created on demand (paid by the user) if the user has no ATA yet
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = insurance_fund_token_account.owner == vault.insurance_fund
+            @ ErrorCode::InvalidInsuranceFund,
+        constraint = insurance_fund_token_account.mint == vault_token_account.mint
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub insurance_fund_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = WithdrawalTracker::LEN,
+        seeds = [b"withdrawal_tracker", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub withdrawal_tracker: Account<'info, WithdrawalTracker>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterAsset<'info> {
+    #[account(
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = AssetConfig::LEN,
+        seeds = [b"asset_config", vault.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump,
+        has_one = governance @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    pub governance: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetBreakerCooldown<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump,
+        has_one = governance @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    pub governance: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CoverShortfall<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump,
+        has_one = governance @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::Unauthorized
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"asset_config", vault.key().as_ref(), vault_token_account.mint.as_ref()],
+        bump = asset_config.bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+    
+    #[account(mut)]
+    pub source_token_account: Account<'info, TokenAccount>,
+    
+    pub governance: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RevalueVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(address = vault.usdc_vault, token::mint = usdc_mint)]
+    pub usdc_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vault.sol_vault, token::mint = sol_mint)]
+    pub sol_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vault.msol_vault, token::mint = msol_mint)]
+    pub msol_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vault.jitosol_vault, token::mint = jitosol_mint)]
+    pub jitosol_vault: Account<'info, TokenAccount>,
+    
+    pub usdc_mint: Account<'info, Mint>,
+    pub sol_mint: Account<'info, Mint>,
+    pub msol_mint: Account<'info, Mint>,
+    pub jitosol_mint: Account<'info, Mint>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch_number: u64)]
+pub struct SnapshotEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    /// Closed epoch record written by ars-token's start_new_epoch
+    #[account(
+        seeds = [b"epoch_history", epoch_number.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = ars_token::ID
+    )]
+    pub epoch_history: Account<'info, ars_token::EpochHistory>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = EpochSnapshot::LEN,
+        seeds = [b"epoch_snapshot", vault.key().as_ref(), epoch_number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, EpochSnapshot>,
+    
+    #[account(address = vault.usdc_vault)]
+    pub usdc_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vault.sol_vault)]
+    pub sol_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vault.msol_vault)]
+    pub msol_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vault.jitosol_vault)]
+    pub jitosol_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FlashBorrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = borrower_token_account.mint == vault_token_account.mint
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+    
+    pub borrower: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, read to find the matching flash_repay
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FlashRepay<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        mut,
+        address = vault.flash_loan_token_account @ ErrorCode::NoFlashLoan
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = insurance_fund_token_account.owner == vault.insurance_fund
+            @ ErrorCode::InvalidInsuranceFund,
+        constraint = insurance_fund_token_account.mint == vault_token_account.mint
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub insurance_fund_token_account: Account<'info, TokenAccount>,
+    
+    pub borrower: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueInterest<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+}
+
+#[derive(Accounts)]
+pub struct SyncBridgedSupply<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(address = vault.aru_mint_state @ ErrorCode::InvalidMintState)]
+    pub mint_state: Account<'info, ars_token::MintState>,
+    
+    #[account(address = mint_state.aru_mint)]
+    pub aru_mint: Account<'info, Mint>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, user: Pubkey)]
+pub struct CanWithdraw<'info> {
+    #[account(
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"asset_config", vault.key().as_ref(), vault_token_account.mint.as_ref()],
+        bump = asset_config.bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+    
+    /// The user's tracker, omitted if they have never withdrawn
+    #[account(
+        seeds = [b"withdrawal_tracker", vault.key().as_ref(), user.as_ref()],
+        bump = withdrawal_tracker.bump
+    )]
+    pub withdrawal_tracker: Option<Account<'info, WithdrawalTracker>>,
+}
+
+#[derive(Accounts)]
+pub struct ViewVault<'info> {
+    #[account(
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+}
+
+#[derive(Accounts)]
+pub struct AssertBuildProfile {}
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        init,
+        payer = referrer,
+        space = ReferralAccount::LEN,
+        seeds = [b"referral", vault.key().as_ref(), referrer.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"referral", vault.key().as_ref(), referrer.key().as_ref(), referral_account.mint.as_ref()],
+        bump = referral_account.bump,
+        has_one = referrer
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key(),
+        constraint = vault_token_account.mint == referral_account.mint
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(address = referral_account.mint)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Created on demand (paid by the referrer) if the referrer has no ATA yet
+    #[account(
+        init_if_needed,
+        payer = referrer,
+        associated_token::mint = mint,
+        associated_token::authority = referrer
+    )]
+    pub referrer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositWithPermit<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = PermitNonce::LEN,
+        seeds = [b"permit_nonce", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+    
+    /// CHECK: Permit signer, authenticated via the Ed25519 instruction
+    pub user: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount,
+        constraint = vault_token_account.mint == user_token_account.mint
+            @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"asset_config", vault.key().as_ref(), vault_token_account.mint.as_ref()],
+        bump = asset_config.bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+    
+    #[account(
+        mut,
+        constraint = insurance_fund_token_account.owner == vault.insurance_fund
+            @ ErrorCode::InvalidInsuranceFund,
+        constraint = insurance_fund_token_account.mint == vault_token_account.mint
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub insurance_fund_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositSol<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = native_mint,
+        associated_token::authority = user
+    )]
+    pub user_wsol_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = vault.sol_vault,
+        constraint = sol_vault.mint == native_mint.key() @ ErrorCode::InvalidSolVault
+    )]
+    pub sol_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = insurance_fund_token_account.owner == vault.insurance_fund
+            @ ErrorCode::InvalidInsuranceFund,
+        constraint = insurance_fund_token_account.mint == native_mint.key()
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub insurance_fund_token_account: Account<'info, TokenAccount>,
+    
+    #[account(address = native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.authority.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ReserveVault>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = native_mint,
+        associated_token::authority = user
+    )]
+    pub user_wsol_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = vault.sol_vault,
+        constraint = sol_vault.mint == native_mint.key() @ ErrorCode::InvalidSolVault
+    )]
+    pub sol_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = insurance_fund_token_account.owner == vault.insurance_fund
+            @ ErrorCode::InvalidInsuranceFund,
+        constraint = insurance_fund_token_account.mint == native_mint.key()
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub insurance_fund_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = WithdrawalTracker::LEN,
+        seeds = [b"withdrawal_tracker", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub withdrawal_tracker: Account<'info, WithdrawalTracker>,
+    
+    #[account(address = native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
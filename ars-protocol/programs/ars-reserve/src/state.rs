@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use ars_common::ACCOUNT_RESERVED_LEN;
 
 #[account]
 pub struct ReserveVault {
@@ -7,15 +8,93 @@ pub struct ReserveVault {
     pub sol_vault: Pubkey,
     pub msol_vault: Pubkey,
     pub jitosol_vault: Pubkey,
-    pub total_value_usd: u64,
-    pub liabilities_usd: u64,
+    /// Reserve value, in quote asset units (quote_decimals)
+    pub total_value: u64,
+    /// Outstanding liabilities, in quote asset units (quote_decimals)
+    pub liabilities: u64,
     pub vhr: u16,
     pub last_rebalance: i64,
     pub rebalance_threshold_bps: u16,
     pub min_vhr: u16,
+    pub fees_collected: u64,
+    pub deposit_fee_bps: u16,
+    pub withdraw_fee_bps: u16,
+    pub insurance_fund: Pubkey,
+    pub user_withdraw_limit: u64,
+    pub user_withdraw_limit_bps: u16,
+    pub target_vhr: u16,
+    pub max_redemption_haircut_bps: u16,
+    pub referral_share_bps: u16,
+    pub locked: bool,
+    /// Signer allowed to rebalance (the ars-core global_state PDA once
+    /// governance is wired up)
+    pub governance: Pubkey,
+    /// Governance-set target weights in bps (USDC, SOL, mSOL, JitoSOL);
+    /// all zero until the first rebalance
+    pub target_weights_bps: [u16; 4],
+    /// Annual interest accrued on liabilities (bps, simple interest)
+    pub liability_rate_bps: u16,
+    /// Last time interest was accrued into liabilities
+    pub last_accrual: i64,
+    /// Price feed the vault is denominated in (default: USD)
+    pub quote_feed: Pubkey,
+    /// Decimals of total_value, liabilities and revaluation prices
+    pub quote_decimals: u8,
+    /// Fee on flash loans (bps), paid to the insurance fund
+    pub flash_loan_fee_bps: u16,
+    /// Vault token account lent out by the open flash loan
+    pub flash_loan_token_account: Pubkey,
+    /// Balance flash_loan_token_account must be restored to by flash_repay
+    /// (zero when no loan is open)
+    pub flash_loan_repayment: u64,
+    /// Fee owed on the open flash loan
+    pub flash_loan_fee: u64,
+    /// Until this time per-user withdrawal limits are halved (set by
+    /// governance when the ars-core circuit breaker clears)
+    pub cooldown_ends: i64,
     pub bump: u8,
+    /// fees_collected when the last epoch snapshot was taken
+    pub snapshot_fees_collected: u64,
+    /// Only epoch that can be snapshotted next
+    pub next_snapshot_epoch: u64,
+    /// ars-token MintState whose bridged-out supply this reserve backs
+    pub aru_mint_state: Pubkey,
+    /// ARU held on remote chains, in quote asset units, as of the last
+    /// sync_bridged_supply; counted in the VHR on top of liabilities
+    pub bridged_liabilities: u64,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN - 56],
 }
 
+/// Upper bound for governance-set deposit/withdraw fees (5%)
+pub const MAX_FEE_BPS: u16 = 500;
+
+/// Upper bound for the referrer's share of a deposit fee (30%)
+pub const MAX_REFERRAL_SHARE_BPS: u16 = 3_000;
+
+/// Hard upper bound for the governance-set redemption haircut (10%)
+pub const MAX_REDEMPTION_HAIRCUT_BPS: u16 = 1000;
+
+/// Domain tag prefixed to deposit permit messages
+pub const DEPOSIT_PERMIT_DOMAIN: &[u8] = b"ARS_DEPOSIT_PERMIT_V2";
+
+/// Upper bound for the governance-set annual liability rate (20%)
+pub const MAX_LIABILITY_RATE_BPS: u16 = 2_000;
+
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
+/// Window over which per-user withdrawals are rate limited
+pub const WITHDRAWAL_WINDOW: i64 = 24 * 60 * 60;
+
+/// Decimals of the default USD quote asset
+pub const DEFAULT_QUOTE_DECIMALS: u8 = 6;
+
+/// Default flash loan fee (0.09%)
+pub const DEFAULT_FLASH_LOAN_FEE_BPS: u16 = 9;
+
+/// Largest supported quote_decimals
+pub const MAX_QUOTE_DECIMALS: u8 = 12;
+
 impl ReserveVault {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
@@ -23,19 +102,112 @@ impl ReserveVault {
         32 + // sol_vault
         32 + // msol_vault
         32 + // jitosol_vault
-        8 + // total_value_usd
-        8 + // liabilities_usd
+        8 + // total_value
+        8 + // liabilities
         2 + // vhr
         8 + // last_rebalance
         2 + // rebalance_threshold_bps
         2 + // min_vhr
-        1; // bump
+        8 + // fees_collected
+        2 + // deposit_fee_bps
+        2 + // withdraw_fee_bps
+        32 + // insurance_fund
+        8 + // user_withdraw_limit
+        2 + // user_withdraw_limit_bps
+        2 + // target_vhr
+        2 + // max_redemption_haircut_bps
+        2 + // referral_share_bps
+        1 + // locked
+        32 + // governance
+        2 * 4 + // target_weights_bps
+        2 + // liability_rate_bps
+        8 + // last_accrual
+        32 + // quote_feed
+        1 + // quote_decimals
+        2 + // flash_loan_fee_bps
+        32 + // flash_loan_token_account
+        8 + // flash_loan_repayment
+        8 + // flash_loan_fee
+        8 + // cooldown_ends
+        1 + // bump
+        8 + // snapshot_fees_collected
+        8 + // next_snapshot_epoch
+        32 + // aru_mint_state
+        8 + // bridged_liabilities
+        (ACCOUNT_RESERVED_LEN - 56); // _reserved
+
+    /// Liabilities the VHR is measured against: accrued liabilities plus
+    /// ARU bridged out to other chains
+    pub fn total_liabilities(&self) -> Result<u64> {
+        Ok(self.liabilities
+            .checked_add(self.bridged_liabilities)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?)
+    }
+
+    /// Accrue interest on liabilities since last_accrual:
+    /// liabilities += liabilities * rate * dt / (10000 * SECONDS_PER_YEAR).
+    /// last_accrual only advances once a non-zero amount is accrued, so
+    /// frequent calls cannot round the interest away. Returns the interest.
+    pub fn accrue_liabilities(&mut self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.last_accrual);
+        if elapsed <= 0 {
+            return Ok(0);
+        }
+        if self.liabilities == 0 || self.liability_rate_bps == 0 {
+            self.last_accrual = now;
+            return Ok(0);
+        }
+        
+        let interest = (self.liabilities as u128)
+            .checked_mul(self.liability_rate_bps as u128)
+            .and_then(|v| v.checked_mul(elapsed as u128))
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?
+            / (10_000 * SECONDS_PER_YEAR as u128);
+        let interest = u64::try_from(interest)
+            .map_err(|_| error!(crate::errors::ErrorCode::ArithmeticOverflow))?;
+        if interest == 0 {
+            return Ok(0);
+        }
+        
+        self.liabilities = self.liabilities
+            .checked_add(interest)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        self.last_accrual = now;
+        Ok(interest)
+    }
+
+    /// Redemption haircut in bps for the current VHR.
+    /// Zero at or above target_vhr, rising linearly to max_redemption_haircut_bps at min_vhr.
+    pub fn redemption_haircut_bps(&self) -> u16 {
+        if self.vhr >= self.target_vhr || self.target_vhr <= self.min_vhr {
+            return 0;
+        }
+        if self.vhr <= self.min_vhr {
+            return self.max_redemption_haircut_bps;
+        }
+        
+        let shortfall = (self.target_vhr - self.vhr) as u64;
+        let band = (self.target_vhr - self.min_vhr) as u64;
+        (self.max_redemption_haircut_bps as u64 * shortfall / band) as u16
+    }
+}
+
+impl ars_common::Lockable for ReserveVault {
+    fn locked(&self) -> bool {
+        self.locked
+    }
+
+    fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
 }
 
 #[account]
 pub struct AssetConfig {
     pub mint: Pubkey,
     pub vault: Pubkey,
+    /// Mint decimals, used to normalize amounts into the vault's quote units
+    pub decimals: u8,
     pub target_weight_bps: u16,
     pub min_weight_bps: u16,
     pub max_weight_bps: u16,
@@ -43,17 +215,178 @@ pub struct AssetConfig {
     pub current_weight_bps: u16,
     pub oracle_source: Pubkey,
     pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
 impl AssetConfig {
     pub const LEN: usize = 8 + // discriminator
         32 + // mint
         32 + // vault
+        1 + // decimals
         2 + // target_weight_bps
         2 + // min_weight_bps
         2 + // max_weight_bps
         2 + // volatility_threshold_bps
         2 + // current_weight_bps
         32 + // oracle_source
-        1; // bump
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+#[account]
+pub struct EpochSnapshot {
+    pub epoch_number: u64,
+    pub epoch_start: i64,
+    pub epoch_end: i64,
+    pub snapshot_time: i64,
+    pub total_supply: u64,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    /// Reserve balances and vault figures as of snapshot_time, which can be
+    /// well after epoch_end if the crank ran late
+    pub usdc_balance: u64,
+    pub sol_balance: u64,
+    pub msol_balance: u64,
+    pub jitosol_balance: u64,
+    pub total_value: u64,
+    pub liabilities: u64,
+    pub vhr: u16,
+    /// Fees collected between the previous snapshot_time and this one
+    pub fees_collected: u64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl EpochSnapshot {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // epoch_number
+        8 + // epoch_start
+        8 + // epoch_end
+        8 + // snapshot_time
+        8 + // total_supply
+        8 + // total_minted
+        8 + // total_burned
+        8 + // usdc_balance
+        8 + // sol_balance
+        8 + // msol_balance
+        8 + // jitosol_balance
+        8 + // total_value
+        8 + // liabilities
+        2 + // vhr
+        8 + // fees_collected
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+#[account]
+pub struct WithdrawalTracker {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub recent_withdrawn: u64,
+    pub last_withdrawal: i64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl WithdrawalTracker {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        32 + // user
+        8 + // recent_withdrawn
+        8 + // last_withdrawal
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Withdrawn amount still counted against the limit at `now`.
+    /// Decays linearly to zero over WITHDRAWAL_WINDOW.
+    pub fn decayed_withdrawn(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.last_withdrawal);
+        if elapsed >= WITHDRAWAL_WINDOW {
+            return 0;
+        }
+        let remaining = (WITHDRAWAL_WINDOW - elapsed.max(0)) as u128;
+        ((self.recent_withdrawn as u128 * remaining) / WITHDRAWAL_WINDOW as u128) as u64
+    }
+
+    /// Earliest time from `now` at which withdrawing `value` more stays
+    /// within `limit`, or None if `value` alone exceeds it
+    pub fn fits_at(&self, value: u64, limit: u64, now: i64) -> Option<i64> {
+        let allowed = limit.checked_sub(value)? as u128;
+        if self.decayed_withdrawn(now) as u128 <= allowed {
+            return Some(now);
+        }
+        // recent_withdrawn * (WINDOW - elapsed) / WINDOW <= allowed
+        let window = WITHDRAWAL_WINDOW as u128;
+        let elapsed = window - allowed * window / self.recent_withdrawn as u128;
+        Some(self.last_withdrawal.saturating_add(elapsed as i64).max(now))
+    }
+}
+
+/// Limit that would reject a can_withdraw amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WithdrawLimit {
+    /// The vault token account holds less than the amount
+    VaultBalance,
+    /// The withdrawal would take the VHR below min_vhr
+    MinVhr,
+    /// The user's rolling withdrawal limit (halved during a breaker cooldown)
+    UserWithdrawLimit,
+}
+
+/// Answer of can_withdraw
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WithdrawVerdict {
+    /// Limit the amount would hit now, None if it would go through
+    pub binding_limit: Option<WithdrawLimit>,
+    /// Quote units the user's rolling limit still allows right now
+    pub headroom: u64,
+    /// When waiting alone lifts the binding limit (the user's window decays
+    /// or the cooldown ends). None if nothing binds or the limit does not
+    /// depend on time.
+    pub unblocks_at: Option<i64>,
+}
+
+#[account]
+pub struct ReferralAccount {
+    pub vault: Pubkey,
+    pub referrer: Pubkey,
+    pub mint: Pubkey,
+    pub accrued: u64,
+    pub total_earned: u64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl ReferralAccount {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        32 + // referrer
+        32 + // mint
+        8 + // accrued
+        8 + // total_earned
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+#[account]
+pub struct PermitNonce {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub next_nonce: u64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl PermitNonce {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        32 + // user
+        8 + // next_nonce
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
 }
@@ -0,0 +1,20 @@
+//! Every ars-reserve account ends with zeroed reserved bytes that its LEN
+//! accounts for, so fields can be added later without reallocating. Fields
+//! added since take their bytes from the reserve.
+
+use ars_program_test::assert_reserved_layout;
+use ars_reserve::{
+    AssetConfig, EpochSnapshot, PermitNonce, ReferralAccount, ReserveVault, WithdrawalTracker,
+};
+
+#[test]
+fn accounts_end_with_reserved_space() {
+    assert_reserved_layout!(
+        AssetConfig,
+        EpochSnapshot,
+        PermitNonce,
+        ReferralAccount,
+        ReserveVault,
+        WithdrawalTracker,
+    );
+}
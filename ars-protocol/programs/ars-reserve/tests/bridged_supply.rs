@@ -0,0 +1,157 @@
+//! ARU bridged out through ars-token counts against the reserve: syncing the
+//! registered MintState moves the net bridged supply into the vault's
+//! liabilities and the VHR, and no other MintState is accepted.
+
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_program_test::{account, fetch, init_vault, packed, program_test, send};
+use ars_reserve::{ReserveVault, DEFAULT_QUOTE_DECIMALS};
+use ars_token::MintState;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::Account, instruction::Instruction, program_option::COption, pubkey::Pubkey,
+    signature::Signer,
+};
+
+const ARU_DECIMALS: u8 = 9;
+const BRIDGED_OUT: u64 = 5_000 * 10u64.pow(ARU_DECIMALS as u32);
+const BRIDGED_IN: u64 = 2_000 * 10u64.pow(ARU_DECIMALS as u32);
+
+struct Setup {
+    ctx: ProgramTestContext,
+    vault: Pubkey,
+    mint_state: Pubkey,
+    aru_mint: Pubkey,
+}
+
+fn mint_state_account(aru_mint: Pubkey) -> Account {
+    let mint_state = MintState {
+        authority: Pubkey::new_unique(),
+        aru_mint,
+        current_epoch: 0,
+        epoch_start: 0,
+        epoch_duration: 86_400,
+        total_supply: 0,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: 0,
+        burn_cap_per_epoch_bps: 0,
+        bridge_adapter: Pubkey::new_unique(),
+        bridged_out: BRIDGED_OUT,
+        bridged_in: BRIDGED_IN,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: Pubkey::new_unique(),
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends: 0,
+        bump: 255,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    };
+    let mut data = Vec::with_capacity(MintState::LEN);
+    mint_state.try_serialize(&mut data).unwrap();
+    account(data, ars_token::id())
+}
+
+fn aru_mint_account() -> Account {
+    account(
+        packed(spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply: BRIDGED_OUT - BRIDGED_IN,
+            decimals: ARU_DECIMALS,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }),
+        spl_token::id(),
+    )
+}
+
+async fn setup() -> Setup {
+    let mut program = program_test!(ars_reserve);
+    let aru_mint = Pubkey::new_unique();
+    let mint_state = Pubkey::new_unique();
+    program.add_account(aru_mint, aru_mint_account());
+    program.add_account(mint_state, mint_state_account(aru_mint));
+    let mut ctx = program.start_with_context().await;
+
+    let vault = init_vault(&mut ctx).await;
+
+    Setup {
+        ctx,
+        vault,
+        mint_state,
+        aru_mint,
+    }
+}
+
+fn set_aru_mint_state(setup: &Setup, aru_mint_state: Pubkey) -> Instruction {
+    Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::UpdateVaultConfig {
+            vault: setup.vault,
+            authority: setup.ctx.payer.pubkey(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::SetAruMintState { aru_mint_state }.data(),
+    }
+}
+
+fn sync_bridged_supply(setup: &Setup, mint_state: Pubkey) -> Instruction {
+    Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::SyncBridgedSupply {
+            vault: setup.vault,
+            mint_state,
+            aru_mint: setup.aru_mint,
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::SyncBridgedSupply {}.data(),
+    }
+}
+
+async fn vault_state(setup: &mut Setup) -> ReserveVault {
+    fetch(&mut setup.ctx, setup.vault).await
+}
+
+#[tokio::test]
+async fn bridged_supply_counts_as_a_reserve_liability() {
+    let mut setup = setup().await;
+    assert_eq!(vault_state(&mut setup).await.vhr, u16::MAX);
+
+    let instructions = [
+        set_aru_mint_state(&setup, setup.mint_state),
+        sync_bridged_supply(&setup, setup.mint_state),
+    ];
+    send(&mut setup.ctx, &instructions, &[]).await.unwrap();
+
+    let vault = vault_state(&mut setup).await;
+    let net_bridged = 3_000 * 10u64.pow(DEFAULT_QUOTE_DECIMALS as u32);
+    assert_eq!(vault.bridged_liabilities, net_bridged);
+    assert_eq!(vault.total_liabilities().unwrap(), net_bridged);
+    // Nothing backs the bridged supply yet
+    assert_eq!(vault.vhr, 0);
+}
+
+#[tokio::test]
+async fn only_the_registered_mint_state_can_be_synced() {
+    let mut setup = setup().await;
+    let mint_state = setup.mint_state;
+
+    // Not registered yet
+    let sync = sync_bridged_supply(&setup, mint_state);
+    assert!(send(&mut setup.ctx, &[sync], &[]).await.is_err());
+
+    let register = set_aru_mint_state(&setup, Pubkey::new_unique());
+    send(&mut setup.ctx, &[register], &[]).await.unwrap();
+    let sync = sync_bridged_supply(&setup, mint_state);
+    assert!(send(&mut setup.ctx, &[sync], &[]).await.is_err());
+
+    let vault = vault_state(&mut setup).await;
+    assert_eq!(vault.bridged_liabilities, 0);
+    assert_eq!(vault.vhr, u16::MAX);
+}
@@ -0,0 +1,70 @@
+//! Normalization of every reserve asset's amounts into quote units.
+
+use ars_reserve::{asset_quote_value, normalize_amount, DEFAULT_QUOTE_DECIMALS};
+
+const USDC_DECIMALS: u8 = 6;
+const SOL_DECIMALS: u8 = 9;
+const MSOL_DECIMALS: u8 = 9;
+const JITOSOL_DECIMALS: u8 = 9;
+
+const ASSETS: [(&str, u8); 4] = [
+    ("USDC", USDC_DECIMALS),
+    ("SOL", SOL_DECIMALS),
+    ("mSOL", MSOL_DECIMALS),
+    ("JitoSOL", JITOSOL_DECIMALS),
+];
+
+#[test]
+fn one_whole_token_normalizes_to_one_quote_unit() {
+    let one_quote_unit = 10u64.pow(DEFAULT_QUOTE_DECIMALS as u32);
+    for (name, decimals) in ASSETS {
+        let one_token = 10u64.pow(decimals as u32);
+        assert_eq!(
+            normalize_amount(one_token, decimals, DEFAULT_QUOTE_DECIMALS).unwrap(),
+            one_quote_unit,
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn usdc_is_unchanged_at_e6() {
+    assert_eq!(normalize_amount(123_456_789, USDC_DECIMALS, 6).unwrap(), 123_456_789);
+}
+
+#[test]
+fn nine_decimal_assets_truncate_sub_e6_dust() {
+    for decimals in [SOL_DECIMALS, MSOL_DECIMALS, JITOSOL_DECIMALS] {
+        // 1.000000999 tokens -> 1.000000 quote units
+        assert_eq!(normalize_amount(1_000_000_999, decimals, 6).unwrap(), 1_000_000);
+        assert_eq!(normalize_amount(999, decimals, 6).unwrap(), 0);
+    }
+}
+
+#[test]
+fn scaling_up_overflow_is_an_error() {
+    assert!(normalize_amount(u64::MAX, USDC_DECIMALS, 9).is_err());
+    assert_eq!(
+        normalize_amount(u64::MAX / 1_000, USDC_DECIMALS, 9).unwrap(),
+        u64::MAX / 1_000 * 1_000
+    );
+}
+
+#[test]
+fn unsupported_decimal_gap_is_an_error() {
+    assert!(normalize_amount(1, 30, 0).is_err());
+}
+
+#[test]
+fn quote_value_matches_normalized_amount_times_price() {
+    // 2.5 tokens of each asset at $150.00 (e6) = $375.00
+    let price = 150_000_000;
+    for (name, decimals) in ASSETS {
+        let balance = 25 * 10u64.pow(decimals as u32) / 10;
+        assert_eq!(
+            asset_quote_value(balance, price, decimals).unwrap(),
+            375_000_000,
+            "{name}"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Flash loans must be repaid, with the fee, by a flash_repay later in the
+//! same transaction. Anything else reverts the borrow with the transaction.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ars_program_test::{
+    create_mint, create_token_account, fetch, init_vault, mint_to, program_test, send,
+    token_balance,
+};
+use ars_reserve::{ReserveVault, DEFAULT_FLASH_LOAN_FEE_BPS};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    sysvar,
+};
+
+const VAULT_BALANCE: u64 = 10_000;
+const BORROWER_BALANCE: u64 = 100;
+const FEE: u64 = VAULT_BALANCE * DEFAULT_FLASH_LOAN_FEE_BPS as u64 / 10_000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    vault: Pubkey,
+    mint: Pubkey,
+    borrower: Keypair,
+    borrower_token_account: Pubkey,
+    vault_token_account: Pubkey,
+    insurance_token_account: Pubkey,
+}
+
+async fn setup() -> Setup {
+    let mut ctx = program_test!(ars_reserve).start_with_context().await;
+
+    let authority = ctx.payer.pubkey();
+    let vault = init_vault(&mut ctx).await;
+
+    let mint = create_mint(&mut ctx, 6).await;
+
+    let borrower = Keypair::new();
+    let borrower_token_account = create_token_account(&mut ctx, &mint, &borrower.pubkey()).await;
+    let vault_token_account = create_token_account(&mut ctx, &mint, &vault).await;
+    // insurance_fund defaults to the vault authority
+    let insurance_token_account = create_token_account(&mut ctx, &mint, &authority).await;
+    mint_to(&mut ctx, &mint, &vault_token_account, VAULT_BALANCE).await;
+    mint_to(&mut ctx, &mint, &borrower_token_account, BORROWER_BALANCE).await;
+
+    Setup {
+        ctx,
+        vault,
+        mint,
+        borrower,
+        borrower_token_account,
+        vault_token_account,
+        insurance_token_account,
+    }
+}
+
+fn flash_borrow(setup: &Setup, borrower_token_account: Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::FlashBorrow {
+            vault: setup.vault,
+            vault_token_account: setup.vault_token_account,
+            borrower_token_account,
+            borrower: setup.borrower.pubkey(),
+            instructions_sysvar: sysvar::instructions::id(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::FlashBorrow { amount }.data(),
+    }
+}
+
+fn flash_repay(setup: &Setup, borrower_token_account: Pubkey) -> Instruction {
+    Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::FlashRepay {
+            vault: setup.vault,
+            vault_token_account: setup.vault_token_account,
+            borrower_token_account,
+            insurance_fund_token_account: setup.insurance_token_account,
+            borrower: setup.borrower.pubkey(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::FlashRepay {}.data(),
+    }
+}
+
+async fn vault_state(setup: &mut Setup) -> ReserveVault {
+    fetch(&mut setup.ctx, setup.vault).await
+}
+
+async fn assert_untouched(setup: &mut Setup) {
+    let vault = vault_state(setup).await;
+    assert!(!vault.locked);
+    assert_eq!(vault.flash_loan_repayment, 0);
+    assert_eq!(vault.fees_collected, 0);
+    assert_eq!(token_balance(&mut setup.ctx, setup.vault_token_account).await, VAULT_BALANCE);
+    assert_eq!(token_balance(&mut setup.ctx, setup.insurance_token_account).await, 0);
+}
+
+#[tokio::test]
+async fn borrow_and_repay_in_one_transaction() {
+    let mut setup = setup().await;
+    let borrower_token_account = setup.borrower_token_account;
+    let borrower = setup.borrower.insecure_clone();
+
+    let instructions = [
+        flash_borrow(&setup, borrower_token_account, VAULT_BALANCE),
+        flash_repay(&setup, borrower_token_account),
+    ];
+    send(&mut setup.ctx, &instructions, &[&borrower]).await.unwrap();
+
+    let vault = vault_state(&mut setup).await;
+    assert!(!vault.locked);
+    assert_eq!(vault.flash_loan_token_account, Pubkey::default());
+    assert_eq!(vault.fees_collected, FEE);
+    assert_eq!(token_balance(&mut setup.ctx, setup.vault_token_account).await, VAULT_BALANCE);
+    assert_eq!(token_balance(&mut setup.ctx, setup.insurance_token_account).await, FEE);
+    assert_eq!(
+        token_balance(&mut setup.ctx, borrower_token_account).await,
+        BORROWER_BALANCE - FEE
+    );
+}
+
+#[tokio::test]
+async fn borrow_without_repay_is_rejected() {
+    let mut setup = setup().await;
+    let borrower_token_account = setup.borrower_token_account;
+    let borrower = setup.borrower.insecure_clone();
+
+    let instructions = [flash_borrow(&setup, borrower_token_account, VAULT_BALANCE)];
+    assert!(send(&mut setup.ctx, &instructions, &[&borrower]).await.is_err());
+
+    assert_untouched(&mut setup).await;
+}
+
+#[tokio::test]
+async fn repay_before_borrow_does_not_count() {
+    let mut setup = setup().await;
+    let borrower_token_account = setup.borrower_token_account;
+    let borrower = setup.borrower.insecure_clone();
+
+    let instructions = [
+        flash_repay(&setup, borrower_token_account),
+        flash_borrow(&setup, borrower_token_account, VAULT_BALANCE),
+    ];
+    assert!(send(&mut setup.ctx, &instructions, &[&borrower]).await.is_err());
+
+    assert_untouched(&mut setup).await;
+}
+
+#[tokio::test]
+async fn unpaid_fee_reverts_the_loan() {
+    let mut setup = setup().await;
+    let mint = setup.mint;
+    let borrower_pubkey = setup.borrower.pubkey();
+    let empty_account = create_token_account(&mut setup.ctx, &mint, &borrower_pubkey).await;
+    let borrower = setup.borrower.insecure_clone();
+
+    // The principal comes back but the borrower has nothing left for the fee
+    let instructions = [
+        flash_borrow(&setup, empty_account, VAULT_BALANCE),
+        flash_repay(&setup, empty_account),
+    ];
+    assert!(send(&mut setup.ctx, &instructions, &[&borrower]).await.is_err());
+
+    assert_untouched(&mut setup).await;
+    assert_eq!(token_balance(&mut setup.ctx, empty_account).await, 0);
+}
+
+#[tokio::test]
+async fn vault_is_locked_for_other_instructions_during_a_loan() {
+    let mut setup = setup().await;
+    let borrower_token_account = setup.borrower_token_account;
+    let borrower = setup.borrower.insecure_clone();
+
+    // A second borrow between borrow and repay hits the reentrancy guard
+    let instructions = [
+        flash_borrow(&setup, borrower_token_account, VAULT_BALANCE / 2),
+        flash_borrow(&setup, borrower_token_account, VAULT_BALANCE / 2),
+        flash_repay(&setup, borrower_token_account),
+    ];
+    assert!(send(&mut setup.ctx, &instructions, &[&borrower]).await.is_err());
+
+    assert_untouched(&mut setup).await;
+}
+
+#[tokio::test]
+async fn accounts_delegated_to_the_vault_cannot_be_borrowed_from() {
+    let mut setup = setup().await;
+    let mint = setup.mint;
+    let vault = setup.vault;
+    let borrower_token_account = setup.borrower_token_account;
+    let borrower = setup.borrower.insecure_clone();
+
+    // A depositor who approved the vault PDA for permit deposits
+    let victim = Keypair::new();
+    let victim_token_account = create_token_account(&mut setup.ctx, &mint, &victim.pubkey()).await;
+    mint_to(&mut setup.ctx, &mint, &victim_token_account, VAULT_BALANCE).await;
+    let approve = spl_token::instruction::approve(
+        &spl_token::id(),
+        &victim_token_account,
+        &vault,
+        &victim.pubkey(),
+        &[],
+        VAULT_BALANCE,
+    )
+    .unwrap();
+    send(&mut setup.ctx, &[approve], &[&victim]).await.unwrap();
+
+    let borrow = Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::FlashBorrow {
+            vault,
+            vault_token_account: victim_token_account,
+            borrower_token_account,
+            borrower: borrower.pubkey(),
+            instructions_sysvar: sysvar::instructions::id(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::FlashBorrow { amount: VAULT_BALANCE }.data(),
+    };
+    assert!(send(&mut setup.ctx, &[borrow], &[&borrower]).await.is_err());
+
+    assert_untouched(&mut setup).await;
+    assert_eq!(token_balance(&mut setup.ctx, victim_token_account).await, VAULT_BALANCE);
+}
@@ -0,0 +1,158 @@
+//! Checks that the vault's reentrancy lock cannot outlive a failed inner CPI.
+//!
+//! `deposit` flushes `locked = true` to the vault before its token transfers.
+//! When a transfer fails the transaction must roll the flag back together
+//! with the rest of the vault state, leaving the next deposit unaffected.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ars_program_test::{
+    create_mint, create_token_account, fetch, init_vault, mint_to, program_test, send,
+    token_balance,
+};
+use ars_reserve::ReserveVault;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+const USER_BALANCE: u64 = 100;
+const DEPOSIT_FEE_BPS: u16 = 500;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    vault: Pubkey,
+    asset_config: Pubkey,
+    user: Keypair,
+    user_token_account: Pubkey,
+    vault_token_account: Pubkey,
+    insurance_token_account: Pubkey,
+}
+
+async fn setup() -> Setup {
+    let mut ctx = program_test!(ars_reserve).start_with_context().await;
+
+    let authority = ctx.payer.pubkey();
+    let vault = init_vault(&mut ctx).await;
+    let set_fees = Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::UpdateVaultConfig { vault, authority }.to_account_metas(None),
+        data: ars_reserve::instruction::SetFees {
+            deposit_fee_bps: DEPOSIT_FEE_BPS,
+            withdraw_fee_bps: 0,
+        }
+        .data(),
+    };
+    send(&mut ctx, &[set_fees], &[]).await.unwrap();
+
+    let mint = create_mint(&mut ctx, 6).await;
+
+    let (asset_config, _) = Pubkey::find_program_address(
+        &[b"asset_config", vault.as_ref(), mint.as_ref()],
+        &ars_reserve::id(),
+    );
+    let register_asset = Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::RegisterAsset {
+            vault,
+            asset_config,
+            mint,
+            authority,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::RegisterAsset {
+            target_weight_bps: 10_000,
+            min_weight_bps: 0,
+            max_weight_bps: 10_000,
+            volatility_threshold_bps: 0,
+            oracle_source: Pubkey::default(),
+        }
+        .data(),
+    };
+    send(&mut ctx, &[register_asset], &[]).await.unwrap();
+
+    let user = Keypair::new();
+    let user_token_account = create_token_account(&mut ctx, &mint, &user.pubkey()).await;
+    let vault_token_account = create_token_account(&mut ctx, &mint, &vault).await;
+    // insurance_fund defaults to the vault authority
+    let insurance_token_account = create_token_account(&mut ctx, &mint, &authority).await;
+
+    mint_to(&mut ctx, &mint, &user_token_account, USER_BALANCE).await;
+
+    Setup {
+        ctx,
+        vault,
+        asset_config,
+        user,
+        user_token_account,
+        vault_token_account,
+        insurance_token_account,
+    }
+}
+
+async fn deposit(setup: &mut Setup, amount: u64) -> Result<(), BanksClientError> {
+    let ix = Instruction {
+        program_id: ars_reserve::id(),
+        accounts: ars_reserve::accounts::Deposit {
+            vault: setup.vault,
+            user: setup.user.pubkey(),
+            user_token_account: setup.user_token_account,
+            vault_token_account: setup.vault_token_account,
+            asset_config: setup.asset_config,
+            insurance_fund_token_account: setup.insurance_token_account,
+            referral_account: None,
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_reserve::instruction::Deposit {
+            amount,
+            referrer: None,
+        }
+        .data(),
+    };
+    send(&mut setup.ctx, &[ix], &[&setup.user]).await
+}
+
+async fn vault_state(setup: &mut Setup) -> ReserveVault {
+    fetch(&mut setup.ctx, setup.vault).await
+}
+
+#[tokio::test]
+async fn failed_first_transfer_does_not_leave_vault_locked() {
+    let mut setup = setup().await;
+
+    // The net transfer exceeds the user's balance and fails inside SPL Token
+    assert!(deposit(&mut setup, USER_BALANCE * 2).await.is_err());
+
+    let vault = vault_state(&mut setup).await;
+    assert!(!vault.locked);
+    assert_eq!(vault.total_value, 0);
+
+    deposit(&mut setup, USER_BALANCE).await.unwrap();
+    let vault = vault_state(&mut setup).await;
+    assert!(!vault.locked);
+    assert_eq!(vault.total_value, 95);
+}
+
+#[tokio::test]
+async fn failed_second_transfer_rolls_back_lock_and_first_transfer() {
+    let mut setup = setup().await;
+
+    // fee = 5, net = 99: the vault transfer succeeds, leaving 1 token for the
+    // insurance fee transfer, which then fails
+    assert!(deposit(&mut setup, 104).await.is_err());
+
+    let vault = vault_state(&mut setup).await;
+    assert!(!vault.locked);
+    assert_eq!(vault.total_value, 0);
+    assert_eq!(vault.fees_collected, 0);
+    assert_eq!(token_balance(&mut setup.ctx, setup.user_token_account).await, USER_BALANCE);
+    assert_eq!(token_balance(&mut setup.ctx, setup.vault_token_account).await, 0);
+
+    deposit(&mut setup, USER_BALANCE).await.unwrap();
+    assert!(!vault_state(&mut setup).await.locked);
+}
@@ -22,4 +22,46 @@ pub enum ErrorCode {
     
     #[msg("Epoch duration not complete")]
     EpochNotComplete,
+    
+    #[msg("Unauthorized access")]
+    Unauthorized,
+    
+    #[msg("Invalid bridge message")]
+    InvalidBridgeMessage,
+    
+    #[msg("Invalid Wormhole program ID")]
+    InvalidBridgeProgram,
+    
+    #[msg("Bridged-in amount exceeds bridged-out supply")]
+    BridgedSupplyExceeded,
+    
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    
+    #[msg("Insufficient staked balance")]
+    InsufficientStake,
+    
+    #[msg("No rewards to claim")]
+    NoRewards,
+    
+    #[msg("Epoch offset must be within one epoch duration")]
+    InvalidEpochAlignment,
+    
+    #[msg("Epoch has ended; start a new epoch first")]
+    EpochExpired,
+    
+    #[msg("Epoch history is still within the retention window")]
+    EpochHistoryRetained,
+    
+    #[msg("Epoch history must be archived in epoch order")]
+    EpochArchiveOutOfOrder,
+    
+    #[msg("Invalid net supply cap")]
+    InvalidNetSupplyCap,
+    
+    #[msg("Net supply change cap exceeded for this epoch")]
+    NetSupplyCapExceeded,
+    
+    #[msg("Program was built for a different profile")]
+    BuildProfileMismatch,
 }
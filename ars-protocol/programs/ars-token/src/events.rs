@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct AruMinted {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub epoch: u64,
+    pub total_supply: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AruBurned {
+    pub source: Pubkey,
+    pub amount: u64,
+    pub epoch: u64,
+    pub total_supply: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct EpochStarted {
+    pub epoch: u64,
+    pub start_time: i64,
+    pub skipped_epochs: u64,
+    pub previous_minted: u64,
+    pub previous_burned: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct EvmBurnRecorded {
+    pub burner: [u8; 32],
+    pub amount: u64,
+    pub source_chain: u16,
+    pub epoch: u64,
+    pub nonce: u64,
+}
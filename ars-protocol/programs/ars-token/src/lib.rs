@@ -1,13 +1,33 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Burn};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Burn, Transfer};
+use ars_common::{log_event, trace};
 
 declare_id!("ARSM8uCNGUDYCVJPNnoKenBNTzKbJANyJS3KpbUVEmQb");
 
+/// Whether this build includes the devnet-only instructions (`devnet` feature,
+/// see ars-core's DEVNET_BUILD)
+pub const DEVNET_BUILD: bool = cfg!(feature = "devnet");
+
 pub mod state;
 pub mod errors;
+pub mod events;
+pub mod wormhole;
 
 pub use state::*;
 pub use errors::ErrorCode;
+pub use events::*;
+pub use wormhole::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_security_txt::security_txt! {
+    name: "ARS Token",
+    project_url: "https://github.com/protocoldaemon-sec/agentic-reserve-system",
+    contacts: "link:https://github.com/protocoldaemon-sec/agentic-reserve-system/security/advisories/new",
+    policy: "https://github.com/protocoldaemon-sec/agentic-reserve-system/blob/main/ars-protocol/SECURITY.md",
+    source_code: "https://github.com/protocoldaemon-sec/agentic-reserve-system",
+    source_release: env!("CARGO_PKG_VERSION")
+}
 
 #[program]
 pub mod ars_token {
@@ -30,12 +50,25 @@ pub mod ars_token {
         mint_state.current_epoch = 0;
         mint_state.epoch_start = Clock::get()?.unix_timestamp;
         mint_state.epoch_duration = epoch_duration;
+        mint_state.epoch_aligned = false;
+        mint_state.epoch_offset = 0;
         mint_state.total_supply = 0;
         mint_state.epoch_minted = 0;
         mint_state.epoch_burned = 0;
         mint_state.mint_cap_per_epoch_bps = mint_cap_per_epoch_bps;
         mint_state.burn_cap_per_epoch_bps = burn_cap_per_epoch_bps;
+        mint_state.bridge_adapter = ctx.accounts.authority.key();
+        mint_state.bridged_out = 0;
+        mint_state.bridged_in = 0;
+        mint_state.bridge_sequence = 0;
+        mint_state.locked = false;
+        mint_state.governance = ctx.accounts.authority.key();
+        mint_state.net_cap_enabled = false;
+        mint_state.net_cap_per_epoch_bps = 0;
+        mint_state.cooldown_ends = 0;
         mint_state.bump = ctx.bumps.mint_state;
+        mint_state.gauge_emissions = 0;
+        mint_state.evm_burn_sequence = 0;
 
         Ok(())
     }
@@ -45,21 +78,31 @@ pub mod ars_token {
         amount: u64,
     ) -> Result<()> {
         let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
         
-        let mint_cap = mint_state.total_supply
-            .checked_mul(mint_state.mint_cap_per_epoch_bps as u64)
-            .ok_or(ErrorCode::ArithmeticOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        mint_state.require_epoch_open(current_time)?;
+        
+        let mint_cap = mint_state.epoch_cap(mint_state.mint_cap_per_epoch_bps, current_time)?;
         
         let new_epoch_minted = mint_state.epoch_minted
             .checked_add(amount)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
         
+        trace!(
+            "mint_cap",
+            global_supply = mint_state.global_supply()?,
+            cap_bps = mint_state.mint_cap_per_epoch_bps,
+            mint_cap = mint_cap,
+            epoch_minted = mint_state.epoch_minted,
+            amount = amount,
+        );
+        
         require!(
             new_epoch_minted <= mint_cap,
             ErrorCode::MintCapExceeded
         );
+        mint_state.check_net_cap(new_epoch_minted, mint_state.epoch_burned)?;
         
         let mint_seeds = &[
             b"mint_state",
@@ -86,6 +129,15 @@ pub mod ars_token {
             .checked_add(amount)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
         
+        log_event!(AruMinted {
+            destination: ctx.accounts.destination.key(),
+            amount,
+            epoch: mint_state.current_epoch,
+            total_supply: mint_state.total_supply,
+        });
+        
+        ars_common::unlock(mint_state);
+        
         Ok(())
     }
 
@@ -94,21 +146,31 @@ pub mod ars_token {
         amount: u64,
     ) -> Result<()> {
         let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
         
-        let burn_cap = mint_state.total_supply
-            .checked_mul(mint_state.burn_cap_per_epoch_bps as u64)
-            .ok_or(ErrorCode::ArithmeticOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        mint_state.require_epoch_open(current_time)?;
+        
+        let burn_cap = mint_state.epoch_cap(mint_state.burn_cap_per_epoch_bps, current_time)?;
         
         let new_epoch_burned = mint_state.epoch_burned
             .checked_add(amount)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
         
+        trace!(
+            "burn_cap",
+            global_supply = mint_state.global_supply()?,
+            cap_bps = mint_state.burn_cap_per_epoch_bps,
+            burn_cap = burn_cap,
+            epoch_burned = mint_state.epoch_burned,
+            amount = amount,
+        );
+        
         require!(
             new_epoch_burned <= burn_cap,
             ErrorCode::BurnCapExceeded
         );
+        mint_state.check_net_cap(mint_state.epoch_minted, new_epoch_burned)?;
         
         token::burn(
             CpiContext::new(
@@ -127,6 +189,15 @@ pub mod ars_token {
             .checked_sub(amount)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
         
+        log_event!(AruBurned {
+            source: ctx.accounts.source.key(),
+            amount,
+            epoch: mint_state.current_epoch,
+            total_supply: mint_state.total_supply,
+        });
+        
+        ars_common::unlock(mint_state);
+        
         Ok(())
     }
 
@@ -136,114 +207,1031 @@ pub mod ars_token {
         let mint_state = &mut ctx.accounts.mint_state;
         let current_time = Clock::get()?.unix_timestamp;
         
-        let epoch_end = mint_state.epoch_start
-            .checked_add(mint_state.epoch_duration)
+        let closed = mint_state
+            .maybe_roll_epoch(current_time)?
+            .ok_or(ErrorCode::EpochNotComplete)?;
+        
+        let epoch_history = &mut ctx.accounts.epoch_history;
+        epoch_history.epoch_number = closed.epoch_number;
+        epoch_history.start_time = closed.start_time;
+        epoch_history.end_time = closed.end_time;
+        epoch_history.total_minted = closed.minted;
+        epoch_history.total_burned = closed.burned;
+        epoch_history.net_supply_change = (closed.minted as i64)
+            .checked_sub(closed.burned as i64)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
+        epoch_history.final_supply = mint_state.total_supply;
+        epoch_history.skipped_epochs = closed.skipped_epochs;
+        
+        log_event!(EpochStarted {
+            epoch: mint_state.current_epoch,
+            start_time: mint_state.epoch_start,
+            skipped_epochs: closed.skipped_epochs,
+            previous_minted: epoch_history.total_minted,
+            previous_burned: epoch_history.total_burned,
+        });
+        
+        Ok(())
+    }
+
+    /// Hand minting rights to `governance`, normally the ars-core global_state
+    /// PDA so that passed MintARU proposals mint through CPI
+    pub fn set_governance(
+        ctx: Context<UpdateMintConfig>,
+        governance: Pubkey,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
         
         require!(
-            current_time >= epoch_end,
-            ErrorCode::EpochNotComplete
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
         );
         
-        let epoch_history = &mut ctx.accounts.epoch_history;
-        epoch_history.epoch_number = mint_state.current_epoch;
-        epoch_history.start_time = mint_state.epoch_start;
-        epoch_history.end_time = current_time;
-        epoch_history.total_minted = mint_state.epoch_minted;
-        epoch_history.total_burned = mint_state.epoch_burned;
-        epoch_history.net_supply_change = (mint_state.epoch_minted as i64)
-            .checked_sub(mint_state.epoch_burned as i64)
+        mint_state.governance = governance;
+        
+        Ok(())
+    }
+
+    /// Switch between rolling epochs and calendar-aligned epochs. The current
+    /// epoch keeps its start; once aligned it ends at the next boundary.
+    pub fn set_epoch_alignment(
+        ctx: Context<UpdateMintConfig>,
+        epoch_aligned: bool,
+        epoch_offset: i64,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        require!(
+            epoch_offset >= 0 && epoch_offset < mint_state.epoch_duration,
+            ErrorCode::InvalidEpochAlignment
+        );
+        
+        mint_state.epoch_aligned = epoch_aligned;
+        mint_state.epoch_offset = epoch_offset;
+        
+        Ok(())
+    }
+
+    /// Select whether this deployment also caps the net supply change per
+    /// epoch, |minted - burned| <= net_cap_per_epoch_bps of supply
+    pub fn set_net_supply_cap(
+        ctx: Context<UpdateMintConfig>,
+        net_cap_enabled: bool,
+        net_cap_per_epoch_bps: u16,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        require!(net_cap_per_epoch_bps <= 10000, ErrorCode::InvalidNetSupplyCap);
+        
+        mint_state.net_cap_enabled = net_cap_enabled;
+        mint_state.net_cap_per_epoch_bps = net_cap_per_epoch_bps;
+        
+        Ok(())
+    }
+
+    /// Halve the per-epoch mint and burn caps until `cooldown_ends`. Called
+    /// by governance when the ars-core circuit breaker clears.
+    pub fn set_breaker_cooldown(
+        ctx: Context<SetBreakerCooldown>,
+        cooldown_ends: i64,
+    ) -> Result<()> {
+        ctx.accounts.mint_state.cooldown_ends = cooldown_ends;
+        
+        Ok(())
+    }
+
+    pub fn authorize_evm_mint(
+        ctx: Context<AuthorizeEvmMint>,
+        amount: u64,
+        recipient: [u8; 32],
+        target_chain: u16,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        mint_state.require_epoch_open(current_time)?;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        
+        let mint_cap = mint_state.epoch_cap(mint_state.mint_cap_per_epoch_bps, current_time)?;
+        
+        let new_epoch_minted = mint_state.epoch_minted
+            .checked_add(amount)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
-        epoch_history.final_supply = mint_state.total_supply;
         
-        mint_state.current_epoch = mint_state.current_epoch
+        require!(
+            new_epoch_minted <= mint_cap,
+            ErrorCode::MintCapExceeded
+        );
+        mint_state.check_net_cap(new_epoch_minted, mint_state.epoch_burned)?;
+        
+        let nonce = mint_state.bridge_sequence;
+        let message = AruCrossChainMessage {
+            payload_id: PAYLOAD_MINT_AUTHORIZATION,
+            counterparty: recipient,
+            amount,
+            target_chain,
+            epoch: mint_state.current_epoch,
+            epoch_cap: mint_cap,
+            epoch_used: new_epoch_minted,
+            nonce,
+        };
+        
+        let emitter_seeds: &[&[u8]] = &[b"emitter", &[ctx.bumps.emitter]];
+        post_wormhole_message(
+            WormholePostAccounts {
+                bridge: &ctx.accounts.wormhole_bridge,
+                message: &ctx.accounts.wormhole_message.to_account_info(),
+                emitter: &ctx.accounts.emitter,
+                sequence: &ctx.accounts.wormhole_sequence,
+                payer: &ctx.accounts.authority.to_account_info(),
+                fee_collector: &ctx.accounts.wormhole_fee_collector,
+                clock: &ctx.accounts.clock.to_account_info(),
+                rent: &ctx.accounts.rent.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                wormhole_program: &ctx.accounts.wormhole_program,
+            },
+            message.encode(),
+            nonce as u32,
+            emitter_seeds,
+        )?;
+        
+        // ARU minted on the EVM mirror is a liability of the Solana reserve
+        mint_state.epoch_minted = new_epoch_minted;
+        mint_state.bridged_out = mint_state.bridged_out
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        mint_state.bridge_sequence = nonce
             .checked_add(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
-        mint_state.epoch_start = current_time;
-        mint_state.epoch_minted = 0;
-        mint_state.epoch_burned = 0;
+        
+        ars_common::unlock(mint_state);
         
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = MintState::LEN,
-        seeds = [b"mint_state", authority.key().as_ref()],
-        bump
-    )]
-    pub mint_state: Account<'info, MintState>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub aru_mint: Account<'info, Mint>,
-    
-    pub system_program: Program<'info, System>,
-}
+    pub fn set_bridge_adapter(
+        ctx: Context<SetBridgeAdapter>,
+        bridge_adapter: Pubkey,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        
+        mint_state.bridge_adapter = bridge_adapter;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct MintARU<'info> {
-    #[account(
-        mut,
-        seeds = [b"mint_state", mint_state.authority.as_ref()],
-        bump = mint_state.bump
-    )]
-    pub mint_state: Account<'info, MintState>,
-    
-    #[account(mut)]
-    pub aru_mint: Account<'info, Mint>,
-    
-    #[account(mut)]
-    pub destination: Account<'info, TokenAccount>,
-    
-    pub token_program: Program<'info, Token>,
-}
+    pub fn bridge_out(
+        ctx: Context<BridgeOut>,
+        amount: u64,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
+        
+        require!(
+            ctx.accounts.bridge_adapter.key() == mint_state.bridge_adapter,
+            ErrorCode::Unauthorized
+        );
+        
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.aru_mint.to_account_info(),
+                    from: ctx.accounts.source.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        // Supply moves to a remote chain; global supply is unchanged
+        mint_state.total_supply = mint_state.total_supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        mint_state.bridged_out = mint_state.bridged_out
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        ars_common::unlock(mint_state);
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct BurnARU<'info> {
-    #[account(
-        mut,
-        seeds = [b"mint_state", mint_state.authority.as_ref()],
-        bump = mint_state.bump
-    )]
-    pub mint_state: Account<'info, MintState>,
-    
-    #[account(mut)]
-    pub aru_mint: Account<'info, Mint>,
-    
-    #[account(mut)]
-    pub source: Account<'info, TokenAccount>,
-    
-    pub authority: Signer<'info>,
-    
-    pub token_program: Program<'info, Token>,
-}
+    pub fn bridge_in(
+        ctx: Context<BridgeIn>,
+        amount: u64,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
+        
+        require!(
+            ctx.accounts.bridge_adapter.key() == mint_state.bridge_adapter,
+            ErrorCode::Unauthorized
+        );
+        
+        let new_bridged_in = mint_state.bridged_in
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Remote chains can only return what was sent to them
+        require!(
+            new_bridged_in <= mint_state.bridged_out,
+            ErrorCode::BridgedSupplyExceeded
+        );
+        
+        let mint_seeds = &[
+            b"mint_state",
+            mint_state.authority.as_ref(),
+            &[mint_state.bump],
+        ];
+        let signer = &[&mint_seeds[..]];
+        
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.aru_mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: mint_state.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        
+        mint_state.bridged_in = new_bridged_in;
+        mint_state.total_supply = mint_state.total_supply
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        ars_common::unlock(mint_state);
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct StartNewEpoch<'info> {
-    #[account(
-        mut,
-        seeds = [b"mint_state", mint_state.authority.as_ref()],
-        bump = mint_state.bump
-    )]
-    pub mint_state: Account<'info, MintState>,
-    
-    #[account(
-        init,
-        payer = authority,
-        space = EpochHistory::LEN,
-        seeds = [b"epoch_history", mint_state.current_epoch.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub epoch_history: Account<'info, EpochHistory>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Record ARU burned on the EVM mirror, from the BurnNotice the mirror
+    /// published. The burn counts against this epoch's burn and net caps like
+    /// a Solana burn and retires the amount from the bridged supply. Notices
+    /// must be recorded in nonce order; one that would exceed the caps waits
+    /// for a later epoch.
+    pub fn record_evm_burn(
+        ctx: Context<RecordEvmBurn>,
+        payload: Vec<u8>,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
+        
+        require!(
+            ctx.accounts.bridge_adapter.key() == mint_state.bridge_adapter,
+            ErrorCode::Unauthorized
+        );
+        
+        let message = AruCrossChainMessage::decode(&payload)?;
+        require!(
+            message.payload_id == PAYLOAD_BURN_NOTICE
+                && message.nonce == mint_state.evm_burn_sequence,
+            ErrorCode::InvalidBridgeMessage
+        );
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        mint_state.require_epoch_open(current_time)?;
+        
+        let burn_cap = mint_state.epoch_cap(mint_state.burn_cap_per_epoch_bps, current_time)?;
+        let new_epoch_burned = mint_state.epoch_burned
+            .checked_add(message.amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        require!(
+            new_epoch_burned <= burn_cap,
+            ErrorCode::BurnCapExceeded
+        );
+        mint_state.check_net_cap(mint_state.epoch_minted, new_epoch_burned)?;
+        
+        // The burned ARU no longer exists remotely, so it leaves the bridged
+        // supply without being minted back here
+        let new_bridged_in = mint_state.bridged_in
+            .checked_add(message.amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            new_bridged_in <= mint_state.bridged_out,
+            ErrorCode::BridgedSupplyExceeded
+        );
+        
+        mint_state.epoch_burned = new_epoch_burned;
+        mint_state.bridged_in = new_bridged_in;
+        mint_state.evm_burn_sequence = message.nonce
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        log_event!(EvmBurnRecorded {
+            burner: message.counterparty,
+            amount: message.amount,
+            source_chain: message.target_chain,
+            epoch: mint_state.current_epoch,
+            nonce: message.nonce,
+        });
+        
+        ars_common::unlock(mint_state);
+        
+        Ok(())
+    }
+
+    pub fn register_gauge(ctx: Context<RegisterGauge>) -> Result<()> {
+        let mint_state = &ctx.accounts.mint_state;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        
+        let gauge = &mut ctx.accounts.gauge;
+        gauge.mint_state = mint_state.key();
+        gauge.lp_mint = ctx.accounts.lp_mint.key();
+        gauge.lp_vault = ctx.accounts.lp_vault.key();
+        gauge.total_staked = 0;
+        gauge.epoch_reward = 0;
+        gauge.acc_reward_per_share = 0;
+        gauge.last_update = Clock::get()?.unix_timestamp;
+        gauge.bump = ctx.bumps.gauge;
+        
+        Ok(())
+    }
+
+    pub fn set_gauge_reward(
+        ctx: Context<SetGaugeReward>,
+        epoch_reward: u64,
+    ) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        let gauge = &mut ctx.accounts.gauge;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let mint_cap = mint_state.epoch_cap(mint_state.mint_cap_per_epoch_bps, current_time)?;
+        
+        // The cap bounds all gauges together, not each one on its own
+        let gauge_emissions = mint_state.gauge_emissions
+            .saturating_sub(gauge.epoch_reward)
+            .checked_add(epoch_reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        require!(gauge_emissions <= mint_cap, ErrorCode::MintCapExceeded);
+        
+        gauge.accrue(current_time, mint_state.epoch_duration)?;
+        gauge.epoch_reward = epoch_reward;
+        mint_state.gauge_emissions = gauge_emissions;
+        
+        Ok(())
+    }
+
+    pub fn stake_lp(
+        ctx: Context<GaugeDeposit>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        let gauge = &mut ctx.accounts.gauge;
+        let stake = &mut ctx.accounts.gauge_stake;
+        
+        gauge.accrue(Clock::get()?.unix_timestamp, ctx.accounts.mint_state.epoch_duration)?;
+        stake.settle(gauge.acc_reward_per_share)?;
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_lp_account.to_account_info(),
+                    to: ctx.accounts.lp_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        stake.gauge = gauge.key();
+        stake.owner = ctx.accounts.owner.key();
+        stake.amount = stake.amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake.bump = ctx.bumps.gauge_stake;
+        stake.checkpoint(gauge.acc_reward_per_share);
+        
+        gauge.total_staked = gauge.total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+
+    pub fn unstake_lp(
+        ctx: Context<GaugeWithdraw>,
+        amount: u64,
+    ) -> Result<()> {
+        let gauge = &mut ctx.accounts.gauge;
+        let stake = &mut ctx.accounts.gauge_stake;
+        
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(amount <= stake.amount, ErrorCode::InsufficientStake);
+        
+        gauge.accrue(Clock::get()?.unix_timestamp, ctx.accounts.mint_state.epoch_duration)?;
+        stake.settle(gauge.acc_reward_per_share)?;
+        
+        let gauge_seeds = &[
+            b"gauge",
+            gauge.lp_mint.as_ref(),
+            &[gauge.bump],
+        ];
+        let signer = &[&gauge_seeds[..]];
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.lp_vault.to_account_info(),
+                    to: ctx.accounts.owner_lp_account.to_account_info(),
+                    authority: gauge.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        
+        stake.amount = stake.amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake.checkpoint(gauge.acc_reward_per_share);
+        
+        gauge.total_staked = gauge.total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+
+    pub fn claim_gauge_rewards(ctx: Context<ClaimGaugeRewards>) -> Result<()> {
+        let mint_state = &mut ctx.accounts.mint_state;
+        ars_common::lock(mint_state, ctx.program_id)?;
+        let gauge = &mut ctx.accounts.gauge;
+        let stake = &mut ctx.accounts.gauge_stake;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        mint_state.require_epoch_open(current_time)?;
+        
+        gauge.accrue(current_time, mint_state.epoch_duration)?;
+        stake.settle(gauge.acc_reward_per_share)?;
+        stake.checkpoint(gauge.acc_reward_per_share);
+        
+        let amount = stake.pending_rewards;
+        require!(amount > 0, ErrorCode::NoRewards);
+        
+        // Gauge emissions are new supply and count against the epoch mint cap
+        let mint_cap = mint_state.epoch_cap(mint_state.mint_cap_per_epoch_bps, current_time)?;
+        
+        let new_epoch_minted = mint_state.epoch_minted
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        require!(
+            new_epoch_minted <= mint_cap,
+            ErrorCode::MintCapExceeded
+        );
+        mint_state.check_net_cap(new_epoch_minted, mint_state.epoch_burned)?;
+        
+        let mint_seeds = &[
+            b"mint_state",
+            mint_state.authority.as_ref(),
+            &[mint_state.bump],
+        ];
+        let signer = &[&mint_seeds[..]];
+        
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.aru_mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: mint_state.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        
+        stake.pending_rewards = 0;
+        mint_state.epoch_minted = new_epoch_minted;
+        mint_state.total_supply = mint_state.total_supply
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        ars_common::unlock(mint_state);
+        
+        Ok(())
+    }
+
+    /// Simulate-only check of whether mint_aru(amount) would pass the supply
+    /// limits now, for wallets to explain a rejection before sending
+    pub fn can_mint(ctx: Context<ViewMintState>, amount: u64) -> Result<SupplyVerdict> {
+        ctx.accounts.mint_state.supply_verdict(true, amount, Clock::get()?.unix_timestamp)
+    }
+
+    /// Simulate-only check of whether burn_aru(amount) would pass the supply
+    /// limits now
+    pub fn can_burn(ctx: Context<ViewMintState>, amount: u64) -> Result<SupplyVerdict> {
+        ctx.accounts.mint_state.supply_verdict(false, amount, Clock::get()?.unix_timestamp)
+    }
+
+    /// Fail unless the deployed build matches the expected flavor, so clients
+    /// can check (e.g. by simulation) that a program id serves a mainnet build
+    /// without devnet-only instructions
+    pub fn assert_build_profile(_ctx: Context<AssertBuildProfile>, devnet: bool) -> Result<()> {
+        require!(devnet == DEVNET_BUILD, ErrorCode::BuildProfileMismatch);
+        Ok(())
+    }
+
+    /// Close an epoch history record older than EPOCH_HISTORY_RETENTION epochs,
+    /// refunding its rent to the mint authority. The record is first folded
+    /// into the archive commitment, so records must be closed in epoch order.
+    pub fn close_epoch_history(
+        ctx: Context<CloseEpochHistory>,
+        epoch_number: u64,
+    ) -> Result<()> {
+        let mint_state = &ctx.accounts.mint_state;
+        
+        require!(
+            ctx.accounts.authority.key() == mint_state.authority,
+            ErrorCode::Unauthorized
+        );
+        
+        let retained_from = mint_state.current_epoch.saturating_sub(EPOCH_HISTORY_RETENTION);
+        require!(epoch_number < retained_from, ErrorCode::EpochHistoryRetained);
+        
+        let epoch_history = &ctx.accounts.epoch_history;
+        let archive = &mut ctx.accounts.archive;
+        require!(
+            epoch_number == archive.next_epoch,
+            ErrorCode::EpochArchiveOutOfOrder
+        );
+        
+        archive.mint_state = mint_state.key();
+        archive.bump = ctx.bumps.archive;
+        archive.append(&epoch_history.try_to_vec()?);
+        // Skipped aligned epochs have no record; the next record follows them
+        archive.next_epoch = epoch_number
+            .checked_add(1)
+            .and_then(|epoch| epoch.checked_add(epoch_history.skipped_epochs))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = MintState::LEN,
+        seeds = [b"mint_state", authority.key().as_ref()],
+        bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub aru_mint: Account<'info, Mint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintARU<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump,
+        has_one = governance @ ErrorCode::Unauthorized
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(mut)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub governance: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct BurnARU<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(mut)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub source: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct StartNewEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = EpochHistory::LEN,
+        seeds = [b"epoch_history", mint_state.current_epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch_history: Account<'info, EpochHistory>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AuthorizeEvmMint<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Emitter PDA signing Wormhole messages for this program
+    #[account(seeds = [b"emitter"], bump)]
+    pub emitter: AccountInfo<'info>,
+    
+    /// CHECK: Wormhole bridge config, validated by the Wormhole program
+    #[account(mut)]
+    pub wormhole_bridge: AccountInfo<'info>,
+    
+    /// Fresh message account, initialized by the Wormhole program
+    #[account(mut)]
+    pub wormhole_message: Signer<'info>,
+    
+    /// CHECK: Emitter sequence tracker, validated by the Wormhole program
+    #[account(mut)]
+    pub wormhole_sequence: AccountInfo<'info>,
+    
+    /// CHECK: Wormhole fee collector, validated by the Wormhole program
+    #[account(mut)]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Validated against WORMHOLE_PROGRAM_ID
+    pub wormhole_program: AccountInfo<'info>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetBridgeAdapter<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMintConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetBreakerCooldown<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump,
+        has_one = governance @ ErrorCode::Unauthorized
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    pub governance: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BridgeOut<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(mut)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub source: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub bridge_adapter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct BridgeIn<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(mut)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub bridge_adapter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RecordEvmBurn<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    pub bridge_adapter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterGauge<'info> {
+    #[account(
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Gauge::LEN,
+        seeds = [b"gauge", lp_mint.key().as_ref()],
+        bump
+    )]
+    pub gauge: Account<'info, Gauge>,
+    
+    pub lp_mint: Account<'info, Mint>,
+    
+    #[account(
+        constraint = lp_vault.mint == lp_mint.key(),
+        constraint = lp_vault.owner == gauge.key()
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetGaugeReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        mut,
+        seeds = [b"gauge", gauge.lp_mint.as_ref()],
+        bump = gauge.bump,
+        has_one = mint_state
+    )]
+    pub gauge: Account<'info, Gauge>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GaugeDeposit<'info> {
+    #[account(
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        mut,
+        seeds = [b"gauge", gauge.lp_mint.as_ref()],
+        bump = gauge.bump,
+        has_one = mint_state,
+        has_one = lp_vault
+    )]
+    pub gauge: Account<'info, Gauge>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = GaugeStake::LEN,
+        seeds = [b"gauge_stake", gauge.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub gauge_stake: Account<'info, GaugeStake>,
+    
+    #[account(mut)]
+    pub lp_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner_lp_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GaugeWithdraw<'info> {
+    #[account(
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        mut,
+        seeds = [b"gauge", gauge.lp_mint.as_ref()],
+        bump = gauge.bump,
+        has_one = mint_state,
+        has_one = lp_vault
+    )]
+    pub gauge: Account<'info, Gauge>,
+    
+    #[account(
+        mut,
+        seeds = [b"gauge_stake", gauge.key().as_ref(), owner.key().as_ref()],
+        bump = gauge_stake.bump
+    )]
+    pub gauge_stake: Account<'info, GaugeStake>,
+    
+    #[account(mut)]
+    pub lp_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner_lp_account: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimGaugeRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        mut,
+        seeds = [b"gauge", gauge.lp_mint.as_ref()],
+        bump = gauge.bump,
+        has_one = mint_state
+    )]
+    pub gauge: Account<'info, Gauge>,
+    
+    #[account(
+        mut,
+        seeds = [b"gauge_stake", gauge.key().as_ref(), owner.key().as_ref()],
+        bump = gauge_stake.bump
+    )]
+    pub gauge_stake: Account<'info, GaugeStake>,
+    
+    #[account(mut, address = mint_state.aru_mint)]
+    pub aru_mint: Account<'info, Mint>,
+    
+    /// Created on demand (paid by the owner) if the owner has no ARU ATA yet
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = aru_mint,
+        associated_token::authority = owner
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch_number: u64)]
+pub struct CloseEpochHistory<'info> {
+    #[account(
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"epoch_history", epoch_number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch_history: Account<'info, EpochHistory>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EpochArchive::LEN,
+        seeds = [b"epoch_archive", mint_state.key().as_ref()],
+        bump
+    )]
+    pub archive: Account<'info, EpochArchive>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ViewMintState<'info> {
+    #[account(
+        seeds = [b"mint_state", mint_state.authority.as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+}
+
+#[derive(Accounts)]
+pub struct AssertBuildProfile {}
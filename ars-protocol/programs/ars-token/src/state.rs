@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use ars_common::ACCOUNT_RESERVED_LEN;
 
 #[account]
 pub struct MintState {
@@ -12,7 +13,30 @@ pub struct MintState {
     pub epoch_burned: u64,
     pub mint_cap_per_epoch_bps: u16,
     pub burn_cap_per_epoch_bps: u16,
+    pub bridge_adapter: Pubkey,
+    pub bridged_out: u64,
+    pub bridged_in: u64,
+    pub bridge_sequence: u64,
+    pub epoch_aligned: bool,
+    pub epoch_offset: i64,
+    pub locked: bool,
+    /// Signer allowed to mint ARU (the ars-core global_state PDA once
+    /// governance is wired up)
+    pub governance: Pubkey,
+    /// Also cap |epoch_minted - epoch_burned| at net_cap_per_epoch_bps of
+    /// supply, on top of the per-direction caps
+    pub net_cap_enabled: bool,
+    pub net_cap_per_epoch_bps: u16,
+    /// Until this time the per-epoch mint and burn caps are halved (set by
+    /// governance when the ars-core circuit breaker clears)
+    pub cooldown_ends: i64,
     pub bump: u8,
+    /// Sum of every gauge's epoch_reward, kept within the epoch mint cap
+    pub gauge_emissions: u64,
+    /// Nonce the next EVM burn notice must carry
+    pub evm_burn_sequence: u64,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN - 16],
 }
 
 impl MintState {
@@ -27,9 +51,247 @@ impl MintState {
         8 + // epoch_burned
         2 + // mint_cap_per_epoch_bps
         2 + // burn_cap_per_epoch_bps
-        1; // bump
+        32 + // bridge_adapter
+        8 + // bridged_out
+        8 + // bridged_in
+        8 + // bridge_sequence
+        1 + // epoch_aligned
+        8 + // epoch_offset
+        1 + // locked
+        32 + // governance
+        1 + // net_cap_enabled
+        2 + // net_cap_per_epoch_bps
+        8 + // cooldown_ends
+        1 + // bump
+        8 + // gauge_emissions
+        8 + // evm_burn_sequence
+        (ACCOUNT_RESERVED_LEN - 16); // _reserved
+
+    /// ARU currently living on remote chains (a liability of this reserve)
+    pub fn bridged_supply(&self) -> Result<u64> {
+        Ok(self.bridged_out
+            .checked_sub(self.bridged_in)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?)
+    }
+
+    /// Start of the calendar-aligned epoch containing `timestamp`: epochs begin
+    /// at multiples of epoch_duration since the Unix epoch (UTC midnight),
+    /// shifted by epoch_offset seconds
+    pub fn aligned_epoch_start(&self, timestamp: i64) -> i64 {
+        let since_offset = timestamp - self.epoch_offset;
+        since_offset - since_offset.rem_euclid(self.epoch_duration) + self.epoch_offset
+    }
+
+    /// Scheduled end of the current epoch. Aligned epochs end at the first
+    /// aligned boundary after epoch_start, so switching to alignment mid-epoch
+    /// shortens that one epoch instead of drifting.
+    pub fn epoch_end(&self) -> Result<i64> {
+        let base = if self.epoch_aligned {
+            self.aligned_epoch_start(self.epoch_start)
+        } else {
+            self.epoch_start
+        };
+        Ok(base
+            .checked_add(self.epoch_duration)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?)
+    }
+
+    /// Caps only apply while the epoch is running: once its scheduled end has
+    /// passed, minting and burning wait for start_new_epoch instead of
+    /// spending the old epoch's headroom late
+    pub fn require_epoch_open(&self, timestamp: i64) -> Result<()> {
+        require!(
+            timestamp < self.epoch_end()?,
+            crate::errors::ErrorCode::EpochExpired
+        );
+        Ok(())
+    }
+
+    /// The only place epoch_minted/epoch_burned are reset. Returns None while
+    /// the current epoch is still open; otherwise closes it and advances
+    /// current_epoch. Mint and burn never roll inline: they are rejected by
+    /// require_epoch_open until start_new_epoch has recorded the history.
+    ///
+    /// Rolling epochs run crank to crank. Aligned epochs keep the calendar:
+    /// a late crank jumps to the epoch containing now, and the aligned
+    /// epochs in between are skipped with zero mint/burn activity.
+    pub fn maybe_roll_epoch(&mut self, now: i64) -> Result<Option<ClosedEpoch>> {
+        let epoch_end = self.epoch_end()?;
+        if now < epoch_end {
+            return Ok(None);
+        }
+        
+        let (end_time, next_start, skipped_epochs) = if self.epoch_aligned {
+            let next_start = self.aligned_epoch_start(now);
+            let skipped = (next_start - epoch_end) / self.epoch_duration;
+            (epoch_end, next_start, skipped as u64)
+        } else {
+            (now, now, 0)
+        };
+        
+        let closed = ClosedEpoch {
+            epoch_number: self.current_epoch,
+            start_time: self.epoch_start,
+            end_time,
+            minted: self.epoch_minted,
+            burned: self.epoch_burned,
+            skipped_epochs,
+        };
+        
+        self.current_epoch = self.current_epoch
+            .checked_add(1)
+            .and_then(|epoch| epoch.checked_add(skipped_epochs))
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        self.epoch_start = next_start;
+        self.epoch_minted = 0;
+        self.epoch_burned = 0;
+        
+        Ok(Some(closed))
+    }
+
+    /// Per-epoch cap for `cap_bps` of global supply, halved while the
+    /// post-incident cooldown runs
+    pub fn epoch_cap(&self, cap_bps: u16, now: i64) -> Result<u64> {
+        let cap = self.global_supply()?
+            .checked_mul(cap_bps as u64)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        if now < self.cooldown_ends {
+            return Ok(cap / 2);
+        }
+        Ok(cap)
+    }
+
+    /// Enforce the net supply cap for an epoch that would end up with
+    /// `minted` and `burned`. Always passes when the mode is disabled.
+    pub fn check_net_cap(&self, minted: u64, burned: u64) -> Result<()> {
+        if !self.net_cap_enabled {
+            return Ok(());
+        }
+        
+        let net_cap = (self.global_supply()? as u128)
+            .checked_mul(self.net_cap_per_epoch_bps as u128)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        require!(
+            (minted.abs_diff(burned) as u128) <= net_cap,
+            crate::errors::ErrorCode::NetSupplyCapExceeded
+        );
+        Ok(())
+    }
+
+    /// Supply across all chains: local supply plus net bridged-out supply
+    pub fn global_supply(&self) -> Result<u64> {
+        Ok(self.total_supply
+            .checked_add(self.bridged_supply()?)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?)
+    }
+
+    /// Whether mint_aru (`minting`) or burn_aru of `amount` would pass the
+    /// epoch, cap and net-cap checks at `now`, and if not, which one binds
+    pub fn supply_verdict(&self, minting: bool, amount: u64, now: i64) -> Result<SupplyVerdict> {
+        let (cap_bps, used) = if minting {
+            (self.mint_cap_per_epoch_bps, self.epoch_minted)
+        } else {
+            (self.burn_cap_per_epoch_bps, self.epoch_burned)
+        };
+        let epoch_end = self.epoch_end()?;
+        
+        if now >= epoch_end {
+            return Ok(SupplyVerdict {
+                binding_limit: Some(SupplyLimit::EpochExpired),
+                headroom: 0,
+                unblocks_at: Some(epoch_end),
+            });
+        }
+        
+        let headroom = self.epoch_cap(cap_bps, now)?.saturating_sub(used);
+        if amount > headroom {
+            let full_cap = self.epoch_cap(cap_bps, self.cooldown_ends)?;
+            let unblocks_at = if amount <= full_cap.saturating_sub(used) {
+                Some(self.cooldown_ends.min(epoch_end))
+            } else if amount <= full_cap {
+                Some(epoch_end)
+            } else {
+                None
+            };
+            return Ok(SupplyVerdict {
+                binding_limit: Some(SupplyLimit::EpochCap),
+                headroom,
+                unblocks_at,
+            });
+        }
+        
+        let used = used.checked_add(amount).ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        let (minted, burned) = if minting {
+            (used, self.epoch_burned)
+        } else {
+            (self.epoch_minted, used)
+        };
+        if self.check_net_cap(minted, burned).is_err() {
+            return Ok(SupplyVerdict {
+                binding_limit: Some(SupplyLimit::NetSupplyCap),
+                headroom,
+                unblocks_at: Some(epoch_end),
+            });
+        }
+        
+        Ok(SupplyVerdict {
+            binding_limit: None,
+            headroom,
+            unblocks_at: None,
+        })
+    }
+}
+
+/// Limit that would reject a can_mint / can_burn amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SupplyLimit {
+    /// The epoch has ended; start_new_epoch must run first
+    EpochExpired,
+    /// Per-direction epoch cap (halved during a breaker cooldown)
+    EpochCap,
+    /// |minted - burned| cap of the net supply mode
+    NetSupplyCap,
+}
+
+/// Answer of can_mint / can_burn
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SupplyVerdict {
+    /// Limit the amount would hit now, None if it would go through
+    pub binding_limit: Option<SupplyLimit>,
+    /// Amount the epoch cap still allows right now
+    pub headroom: u64,
+    /// When waiting alone lifts the binding limit: the cooldown end, or the
+    /// epoch end after which start_new_epoch resets the counters. None if
+    /// nothing binds or the amount exceeds a whole epoch's cap.
+    pub unblocks_at: Option<i64>,
+}
+
+impl ars_common::Lockable for MintState {
+    fn locked(&self) -> bool {
+        self.locked
+    }
+
+    fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
+}
+
+/// Totals of the epoch closed by MintState::maybe_roll_epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClosedEpoch {
+    pub epoch_number: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub minted: u64,
+    pub burned: u64,
+    pub skipped_epochs: u64,
 }
 
+/// Closed epochs kept on-chain before their history may be closed for rent
+pub const EPOCH_HISTORY_RETENTION: u64 = 52;
+
 #[account]
 pub struct EpochHistory {
     pub epoch_number: u64,
@@ -39,6 +301,9 @@ pub struct EpochHistory {
     pub total_burned: u64,
     pub net_supply_change: i64,
     pub final_supply: u64,
+    pub skipped_epochs: u64,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
 impl EpochHistory {
@@ -49,5 +314,129 @@ impl EpochHistory {
         8 + // total_minted
         8 + // total_burned
         8 + // net_supply_change
-        8; // final_supply
+        8 + // final_supply
+        8 + // skipped_epochs
+        ACCOUNT_RESERVED_LEN; // _reserved
+}
+
+/// Running commitment over pruned EpochHistory records:
+/// commitment_n = sha256(commitment_{n-1} || borsh(EpochHistory_n))
+#[account]
+pub struct EpochArchive {
+    pub mint_state: Pubkey,
+    pub commitment: [u8; 32],
+    pub archived_count: u64,
+    pub next_epoch: u64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl EpochArchive {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint_state
+        32 + // commitment
+        8 + // archived_count
+        8 + // next_epoch
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Fold one serialized record into the commitment
+    pub fn append(&mut self, record: &[u8]) {
+        self.commitment = anchor_lang::solana_program::hash::hashv(&[&self.commitment, record])
+            .to_bytes();
+        self.archived_count = self.archived_count.saturating_add(1);
+    }
+}
+
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+#[account]
+pub struct Gauge {
+    pub mint_state: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_vault: Pubkey,
+    pub total_staked: u64,
+    pub epoch_reward: u64,
+    pub acc_reward_per_share: u128,
+    pub last_update: i64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl Gauge {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint_state
+        32 + // lp_mint
+        32 + // lp_vault
+        8 + // total_staked
+        8 + // epoch_reward
+        16 + // acc_reward_per_share
+        8 + // last_update
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Accrue rewards up to `now`, streaming epoch_reward evenly over epoch_duration
+    pub fn accrue(&mut self, now: i64, epoch_duration: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_update).max(0) as u128;
+        
+        if self.total_staked > 0 && elapsed > 0 && epoch_duration > 0 {
+            let reward = (self.epoch_reward as u128)
+                .checked_mul(elapsed)
+                .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?
+                / epoch_duration as u128;
+            let increment = reward
+                .checked_mul(REWARD_PRECISION)
+                .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?
+                / self.total_staked as u128;
+            self.acc_reward_per_share = self.acc_reward_per_share
+                .checked_add(increment)
+                .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        self.last_update = now;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct GaugeStake {
+    pub gauge: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub reward_debt: u128,
+    pub pending_rewards: u64,
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl GaugeStake {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // gauge
+        32 + // owner
+        8 + // amount
+        16 + // reward_debt
+        8 + // pending_rewards
+        1 + // bump
+        ACCOUNT_RESERVED_LEN; // _reserved
+
+    /// Move rewards earned since the last checkpoint into pending_rewards
+    pub fn settle(&mut self, acc_reward_per_share: u128) -> Result<()> {
+        let accrued = (self.amount as u128)
+            .checked_mul(acc_reward_per_share)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?
+            / REWARD_PRECISION;
+        let earned = accrued.saturating_sub(self.reward_debt) as u64;
+        
+        self.pending_rewards = self.pending_rewards
+            .checked_add(earned)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn checkpoint(&mut self, acc_reward_per_share: u128) {
+        self.reward_debt = (self.amount as u128).saturating_mul(acc_reward_per_share) / REWARD_PRECISION;
+    }
 }
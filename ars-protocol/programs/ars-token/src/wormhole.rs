@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    pubkey,
+    system_instruction,
+};
+
+use crate::errors::ErrorCode;
+
+/// Wormhole core bridge program ID (devnet)
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("3u8hJUVTA4jH1wYAyUur7FFZVQ8H635K3tSHHF4ssjQ5");
+
+/// Payload version understood by the EVM mirror contract
+pub const ARU_MESSAGE_VERSION: u8 = 1;
+
+/// Payload ID: Solana authorizes the EVM mirror to mint
+pub const PAYLOAD_MINT_AUTHORIZATION: u8 = 1;
+
+/// Payload ID: EVM mirror reports a burn back to Solana
+pub const PAYLOAD_BURN_NOTICE: u8 = 2;
+
+/// Encoded size of an ARU cross-chain message
+pub const ARU_MESSAGE_LEN: usize = 76;
+
+/// Wormhole consistency level (1 = finalized)
+pub const CONSISTENCY_FINALIZED: u8 = 1;
+
+/// Canonical cross-chain message for the EVM ARU mirror
+///
+/// All integers are big-endian so the EVM side can decode with plain
+/// `abi.decodePacked`-style slicing. See documentation/EVM_MIRROR_SPEC.md.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AruCrossChainMessage {
+    /// PAYLOAD_MINT_AUTHORIZATION or PAYLOAD_BURN_NOTICE
+    pub payload_id: u8,
+    /// Recipient (mint) or burner (burn notice), 32-byte left-padded address
+    pub counterparty: [u8; 32],
+    /// ARU amount (6 decimals)
+    pub amount: u64,
+    /// Wormhole chain ID of the EVM deployment
+    pub target_chain: u16,
+    /// Solana epoch the amount is accounted in
+    pub epoch: u64,
+    /// Epoch mint (or burn) cap at the time of authorization
+    pub epoch_cap: u64,
+    /// Epoch minted (or burned) total including this message
+    pub epoch_used: u64,
+    /// Monotonic bridge sequence number (replay protection)
+    pub nonce: u64,
+}
+
+impl AruCrossChainMessage {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(ARU_MESSAGE_LEN);
+        data.push(self.payload_id);
+        data.push(ARU_MESSAGE_VERSION);
+        data.extend_from_slice(&self.counterparty);
+        data.extend_from_slice(&self.amount.to_be_bytes());
+        data.extend_from_slice(&self.target_chain.to_be_bytes());
+        data.extend_from_slice(&self.epoch.to_be_bytes());
+        data.extend_from_slice(&self.epoch_cap.to_be_bytes());
+        data.extend_from_slice(&self.epoch_used.to_be_bytes());
+        data.extend_from_slice(&self.nonce.to_be_bytes());
+        data
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(data.len() == ARU_MESSAGE_LEN, ErrorCode::InvalidBridgeMessage);
+        require!(data[1] == ARU_MESSAGE_VERSION, ErrorCode::InvalidBridgeMessage);
+        require!(
+            data[0] == PAYLOAD_MINT_AUTHORIZATION || data[0] == PAYLOAD_BURN_NOTICE,
+            ErrorCode::InvalidBridgeMessage
+        );
+
+        let u64_at = |i: usize| u64::from_be_bytes(data[i..i + 8].try_into().unwrap());
+
+        Ok(Self {
+            payload_id: data[0],
+            counterparty: data[2..34].try_into().unwrap(),
+            amount: u64_at(34),
+            target_chain: u16::from_be_bytes([data[42], data[43]]),
+            epoch: u64_at(44),
+            epoch_cap: u64_at(52),
+            epoch_used: u64_at(60),
+            nonce: u64_at(68),
+        })
+    }
+}
+
+/// Accounts required by the Wormhole core bridge `post_message` instruction
+pub struct WormholePostAccounts<'a, 'info> {
+    pub bridge: &'a AccountInfo<'info>,
+    pub message: &'a AccountInfo<'info>,
+    pub emitter: &'a AccountInfo<'info>,
+    pub sequence: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub wormhole_program: &'a AccountInfo<'info>,
+}
+
+/// Pay the bridge fee and post `payload` with the program's emitter PDA as signer
+pub fn post_wormhole_message(
+    accounts: WormholePostAccounts,
+    payload: Vec<u8>,
+    nonce: u32,
+    emitter_seeds: &[&[u8]],
+) -> Result<()> {
+    require!(
+        accounts.wormhole_program.key() == WORMHOLE_PROGRAM_ID,
+        ErrorCode::InvalidBridgeProgram
+    );
+
+    // BridgeData layout: guardian_set_index u32, last_lamports u64,
+    // guardian_set_expiration_time u32, fee u64
+    let fee = {
+        let data = accounts.bridge.try_borrow_data()?;
+        require!(data.len() >= 24, ErrorCode::InvalidBridgeMessage);
+        u64::from_le_bytes(data[16..24].try_into().unwrap())
+    };
+
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(accounts.payer.key, accounts.fee_collector.key, fee),
+            &[
+                accounts.payer.clone(),
+                accounts.fee_collector.clone(),
+                accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
+    // Instruction data: [tag = 1 (PostMessage), nonce: u32, payload: Vec<u8>, consistency: u8]
+    let mut data = Vec::with_capacity(1 + 4 + 4 + payload.len() + 1);
+    data.push(1);
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(&payload);
+    data.push(CONSISTENCY_FINALIZED);
+
+    invoke_signed(
+        &Instruction {
+            program_id: WORMHOLE_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*accounts.bridge.key, false),
+                AccountMeta::new(*accounts.message.key, true),
+                AccountMeta::new_readonly(*accounts.emitter.key, true),
+                AccountMeta::new(*accounts.sequence.key, false),
+                AccountMeta::new(*accounts.payer.key, true),
+                AccountMeta::new(*accounts.fee_collector.key, false),
+                AccountMeta::new_readonly(*accounts.clock.key, false),
+                AccountMeta::new_readonly(*accounts.rent.key, false),
+                AccountMeta::new_readonly(*accounts.system_program.key, false),
+            ],
+            data,
+        },
+        &[
+            accounts.bridge.clone(),
+            accounts.message.clone(),
+            accounts.emitter.clone(),
+            accounts.sequence.clone(),
+            accounts.payer.clone(),
+            accounts.fee_collector.clone(),
+            accounts.clock.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+        ],
+        &[emitter_seeds],
+    )?;
+
+    Ok(())
+}
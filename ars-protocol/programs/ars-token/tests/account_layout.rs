@@ -0,0 +1,10 @@
+//! Every ars-token account ends with zeroed reserved bytes that its LEN
+//! accounts for, so fields can be added later without reallocating.
+
+use ars_program_test::assert_reserved_layout;
+use ars_token::{EpochArchive, EpochHistory, Gauge, GaugeStake, MintState};
+
+#[test]
+fn accounts_end_with_reserved_space() {
+    assert_reserved_layout!(EpochArchive, EpochHistory, Gauge, GaugeStake, MintState);
+}
@@ -0,0 +1,51 @@
+//! After the ars-core circuit breaker clears, governance sets cooldown_ends
+//! and the per-epoch mint and burn caps are halved until then.
+
+use anchor_lang::prelude::Pubkey;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_token::MintState;
+
+const SUPPLY: u64 = 1_000_000_000;
+const COOLDOWN_ENDS: i64 = 1_700_000_000;
+
+fn mint_state() -> MintState {
+    MintState {
+        authority: Pubkey::new_unique(),
+        aru_mint: Pubkey::new_unique(),
+        current_epoch: 0,
+        epoch_start: 0,
+        epoch_duration: 86_400,
+        total_supply: SUPPLY,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: 1_000,
+        burn_cap_per_epoch_bps: 1_000,
+        bridge_adapter: Pubkey::new_unique(),
+        bridged_out: 0,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: Pubkey::new_unique(),
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends: COOLDOWN_ENDS,
+        bump: 255,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    }
+}
+
+#[test]
+fn caps_are_halved_during_the_cooldown() {
+    let state = mint_state();
+    assert_eq!(state.epoch_cap(1_000, COOLDOWN_ENDS - 1).unwrap(), SUPPLY / 20);
+}
+
+#[test]
+fn caps_are_restored_once_the_cooldown_ends() {
+    let state = mint_state();
+    assert_eq!(state.epoch_cap(1_000, COOLDOWN_ENDS).unwrap(), SUPPLY / 10);
+}
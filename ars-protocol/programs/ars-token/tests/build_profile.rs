@@ -0,0 +1,33 @@
+//! assert_build_profile reports the flavor a program id was built with.
+//! Tests run without the `devnet` feature, i.e. as a mainnet build.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use ars_program_test::program_test;
+use solana_sdk::{instruction::Instruction, signature::Signer, transaction::Transaction};
+
+fn assert_build_profile(devnet: bool) -> Instruction {
+    Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::AssertBuildProfile {}.to_account_metas(None),
+        data: ars_token::instruction::AssertBuildProfile { devnet }.data(),
+    }
+}
+
+#[tokio::test]
+async fn default_build_is_the_mainnet_profile() {
+    assert!(!ars_token::DEVNET_BUILD);
+
+    let mut ctx = program_test!(ars_token).start_with_context().await;
+
+    for (devnet, accepted) in [(false, true), (true, false)] {
+        let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[assert_build_profile(devnet)],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer],
+            blockhash,
+        );
+        let result = ctx.banks_client.simulate_transaction(tx).await.unwrap().result;
+        assert_eq!(matches!(result, Some(Ok(()))), accepted);
+    }
+}
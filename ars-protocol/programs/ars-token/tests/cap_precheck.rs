@@ -0,0 +1,86 @@
+//! can_mint / can_burn report which supply limit binds and when it lifts,
+//! mirroring the checks mint_aru and burn_aru apply.
+
+use anchor_lang::prelude::Pubkey;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_token::{MintState, SupplyLimit, SupplyVerdict};
+
+const SUPPLY: u64 = 1_000_000;
+const EPOCH_START: i64 = 1_700_000_000;
+const EPOCH_END: i64 = EPOCH_START + 86_400;
+const NOW: i64 = EPOCH_START + 100;
+
+fn mint_state() -> MintState {
+    MintState {
+        authority: Pubkey::new_unique(),
+        aru_mint: Pubkey::new_unique(),
+        current_epoch: 0,
+        epoch_start: EPOCH_START,
+        epoch_duration: 86_400,
+        total_supply: SUPPLY,
+        epoch_minted: 60_000,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: 1_000,
+        burn_cap_per_epoch_bps: 1_000,
+        bridge_adapter: Pubkey::new_unique(),
+        bridged_out: 0,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: Pubkey::new_unique(),
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends: 0,
+        bump: 255,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    }
+}
+
+#[test]
+fn amount_within_headroom_is_clear() {
+    let verdict = mint_state().supply_verdict(true, 40_000, NOW).unwrap();
+    assert_eq!(
+        verdict,
+        SupplyVerdict { binding_limit: None, headroom: 40_000, unblocks_at: None }
+    );
+}
+
+#[test]
+fn epoch_cap_lifts_at_the_epoch_end() {
+    let verdict = mint_state().supply_verdict(true, 40_001, NOW).unwrap();
+    assert_eq!(verdict.binding_limit, Some(SupplyLimit::EpochCap));
+    assert_eq!(verdict.unblocks_at, Some(EPOCH_END));
+
+    // More than a whole epoch allows: waiting does not help
+    let verdict = mint_state().supply_verdict(true, 100_001, NOW).unwrap();
+    assert_eq!(verdict.unblocks_at, None);
+}
+
+#[test]
+fn halved_cap_lifts_when_the_cooldown_ends() {
+    let mut state = mint_state();
+    state.epoch_minted = 0;
+    state.cooldown_ends = NOW + 3_600;
+
+    let verdict = state.supply_verdict(false, 80_000, NOW).unwrap();
+    assert_eq!(verdict.binding_limit, Some(SupplyLimit::EpochCap));
+    assert_eq!(verdict.headroom, 50_000);
+    assert_eq!(verdict.unblocks_at, Some(NOW + 3_600));
+}
+
+#[test]
+fn net_cap_and_expired_epoch_are_reported() {
+    let mut state = mint_state();
+    state.net_cap_enabled = true;
+    state.net_cap_per_epoch_bps = 700;
+    let verdict = state.supply_verdict(true, 20_000, NOW).unwrap();
+    assert_eq!(verdict.binding_limit, Some(SupplyLimit::NetSupplyCap));
+
+    let verdict = mint_state().supply_verdict(true, 1, EPOCH_END).unwrap();
+    assert_eq!(verdict.binding_limit, Some(SupplyLimit::EpochExpired));
+    assert_eq!(verdict.unblocks_at, Some(EPOCH_END));
+}
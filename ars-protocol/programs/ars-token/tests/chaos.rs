@@ -0,0 +1,353 @@
+//! Failure-mode scenarios for mint, burn and epoch rollover: a bank clock
+//! that jumps backwards or far forwards, token CPIs that fail after
+//! `mint_state` has been locked, and the same instruction submitted twice in
+//! one transaction. In each case the MintState, the SPL supply and the
+//! reentrancy lock must stay consistent with what actually succeeded.
+
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_program_test::{account, fetch, mint_supply, packed, program_test, send, set_time};
+use ars_token::MintState;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+const INITIAL_SUPPLY: u64 = 1_000_000;
+// 10% of supply per epoch in each direction
+const CAP_BPS: u16 = 1_000;
+const EPOCH_START: i64 = 1_700_000_000;
+const EPOCH_DURATION: i64 = 86_400;
+const EPOCH_END: i64 = EPOCH_START + EPOCH_DURATION;
+const SMALL_BALANCE: u64 = 10;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    mint_state: Pubkey,
+    aru_mint: Pubkey,
+    governance: Keypair,
+    holder: Keypair,
+    holder_token_account: Pubkey,
+    /// Holder account the token program refuses to credit or debit
+    frozen_token_account: Pubkey,
+    /// Holder account with less than any burn these tests attempt
+    small_token_account: Pubkey,
+}
+
+fn token_account(
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+    state: spl_token::state::AccountState,
+) -> Account {
+    account(
+        packed(spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            delegate: COption::None,
+            state,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }),
+        spl_token::id(),
+    )
+}
+
+async fn setup() -> Setup {
+    let mut program = program_test!(ars_token);
+
+    let authority = Pubkey::new_unique();
+    let governance = Keypair::new();
+    let holder = Keypair::new();
+    let aru_mint = Pubkey::new_unique();
+    let holder_token_account = Pubkey::new_unique();
+    let frozen_token_account = Pubkey::new_unique();
+    let small_token_account = Pubkey::new_unique();
+    let (mint_state, bump) =
+        Pubkey::find_program_address(&[b"mint_state", authority.as_ref()], &ars_token::id());
+
+    let state = MintState {
+        authority,
+        aru_mint,
+        current_epoch: 0,
+        epoch_start: EPOCH_START,
+        epoch_duration: EPOCH_DURATION,
+        total_supply: INITIAL_SUPPLY,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: CAP_BPS,
+        burn_cap_per_epoch_bps: CAP_BPS,
+        bridge_adapter: authority,
+        bridged_out: 0,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: governance.pubkey(),
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends: 0,
+        bump,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    };
+    let mut data = Vec::with_capacity(MintState::LEN);
+    state.try_serialize(&mut data).unwrap();
+    data.resize(MintState::LEN, 0);
+    program.add_account(mint_state, account(data, ars_token::id()));
+
+    program.add_account(
+        aru_mint,
+        account(
+            packed(spl_token::state::Mint {
+                mint_authority: COption::Some(mint_state),
+                supply: INITIAL_SUPPLY + SMALL_BALANCE,
+                decimals: 6,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            }),
+            spl_token::id(),
+        ),
+    );
+    program.add_account(
+        holder_token_account,
+        token_account(
+            aru_mint,
+            holder.pubkey(),
+            INITIAL_SUPPLY,
+            spl_token::state::AccountState::Initialized,
+        ),
+    );
+    program.add_account(
+        frozen_token_account,
+        token_account(aru_mint, holder.pubkey(), 0, spl_token::state::AccountState::Frozen),
+    );
+    program.add_account(
+        small_token_account,
+        token_account(
+            aru_mint,
+            holder.pubkey(),
+            SMALL_BALANCE,
+            spl_token::state::AccountState::Initialized,
+        ),
+    );
+
+    Setup {
+        ctx: program.start_with_context().await,
+        mint_state,
+        aru_mint,
+        governance,
+        holder,
+        holder_token_account,
+        frozen_token_account,
+        small_token_account,
+    }
+}
+
+fn mint_ix(setup: &Setup, destination: Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::MintARU {
+            mint_state: setup.mint_state,
+            aru_mint: setup.aru_mint,
+            destination,
+            governance: setup.governance.pubkey(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::MintAru { amount }.data(),
+    }
+}
+
+fn burn_ix(setup: &Setup, source: Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::BurnARU {
+            mint_state: setup.mint_state,
+            aru_mint: setup.aru_mint,
+            source,
+            authority: setup.holder.pubkey(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::BurnAru { amount }.data(),
+    }
+}
+
+fn start_new_epoch_ix(setup: &Setup, current_epoch: u64) -> Instruction {
+    let (epoch_history, _) = Pubkey::find_program_address(
+        &[b"epoch_history", current_epoch.to_le_bytes().as_ref()],
+        &ars_token::id(),
+    );
+    Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::StartNewEpoch {
+            mint_state: setup.mint_state,
+            epoch_history,
+            authority: setup.ctx.payer.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::StartNewEpoch {}.data(),
+    }
+}
+
+async fn mint(setup: &mut Setup, amount: u64) -> Result<(), BanksClientError> {
+    let ix = mint_ix(setup, setup.holder_token_account, amount);
+    let governance = setup.governance.insecure_clone();
+    send(&mut setup.ctx, &[ix], &[&governance]).await
+}
+
+async fn start_new_epoch(setup: &mut Setup) -> Result<(), BanksClientError> {
+    let current_epoch = mint_state(setup).await.current_epoch;
+    let ix = start_new_epoch_ix(setup, current_epoch);
+    send(&mut setup.ctx, &[ix], &[]).await
+}
+
+async fn mint_state(setup: &mut Setup) -> MintState {
+    fetch(&mut setup.ctx, setup.mint_state).await
+}
+
+async fn spl_supply(setup: &mut Setup) -> u64 {
+    mint_supply(&mut setup.ctx, setup.aru_mint).await
+}
+
+#[tokio::test]
+async fn clock_moving_backwards_neither_reopens_nor_rolls_an_epoch() {
+    let mut setup = setup().await;
+
+    set_time(&mut setup.ctx, EPOCH_START + 100).await;
+    mint(&mut setup, 100_000).await.unwrap();
+
+    set_time(&mut setup.ctx, EPOCH_END).await;
+    start_new_epoch(&mut setup).await.unwrap();
+    assert_eq!(mint_state(&mut setup).await.epoch_start, EPOCH_END);
+
+    // Back inside epoch 0: the spent headroom stays spent and counts against
+    // epoch 1, whose cap is recomputed from the larger supply
+    set_time(&mut setup.ctx, EPOCH_START + 200).await;
+    mint(&mut setup, 110_000).await.unwrap();
+    assert!(mint(&mut setup, 1).await.is_err());
+    assert!(start_new_epoch(&mut setup).await.is_err());
+
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 1);
+    assert_eq!(state.epoch_start, EPOCH_END);
+    assert_eq!(state.epoch_minted, 110_000);
+    assert_eq!(state.total_supply, INITIAL_SUPPLY + 210_000);
+    assert!(!state.locked);
+}
+
+#[tokio::test]
+async fn clock_jumping_forward_rolls_exactly_one_epoch() {
+    let mut setup = setup().await;
+
+    set_time(&mut setup.ctx, EPOCH_START + 100).await;
+    mint(&mut setup, 50_000).await.unwrap();
+
+    let later = EPOCH_END + 10 * EPOCH_DURATION;
+    set_time(&mut setup.ctx, later).await;
+    assert!(mint(&mut setup, 1).await.is_err());
+
+    start_new_epoch(&mut setup).await.unwrap();
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 1);
+    assert_eq!(state.epoch_start, later);
+    assert_eq!(state.epoch_minted, 0);
+
+    assert!(start_new_epoch(&mut setup).await.is_err());
+    mint(&mut setup, 100_000).await.unwrap();
+}
+
+#[tokio::test]
+async fn failed_mint_cpi_leaves_state_and_lock_untouched() {
+    let mut setup = setup().await;
+    set_time(&mut setup.ctx, EPOCH_START + 100).await;
+
+    // The token program rejects crediting a frozen account after mint_aru
+    // has locked mint_state and passed the cap check
+    let ix = mint_ix(&setup, setup.frozen_token_account, 50_000);
+    let governance = setup.governance.insecure_clone();
+    assert!(send(&mut setup.ctx, &[ix], &[&governance]).await.is_err());
+
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.epoch_minted, 0);
+    assert_eq!(state.total_supply, INITIAL_SUPPLY);
+    assert!(!state.locked);
+    assert_eq!(spl_supply(&mut setup).await, INITIAL_SUPPLY + SMALL_BALANCE);
+
+    // The full cap is still available
+    mint(&mut setup, 100_000).await.unwrap();
+}
+
+#[tokio::test]
+async fn failed_burn_cpi_leaves_state_and_lock_untouched() {
+    let mut setup = setup().await;
+    set_time(&mut setup.ctx, EPOCH_START + 100).await;
+
+    let holder = setup.holder.insecure_clone();
+    let ix = burn_ix(&setup, setup.small_token_account, SMALL_BALANCE + 1);
+    assert!(send(&mut setup.ctx, &[ix], &[&holder]).await.is_err());
+    let ix = burn_ix(&setup, setup.frozen_token_account, 0);
+    assert!(send(&mut setup.ctx, &[ix], &[&holder]).await.is_err());
+
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.epoch_burned, 0);
+    assert_eq!(state.total_supply, INITIAL_SUPPLY);
+    assert!(!state.locked);
+
+    let ix = burn_ix(&setup, setup.small_token_account, SMALL_BALANCE);
+    send(&mut setup.ctx, &[ix], &[&holder]).await.unwrap();
+    assert_eq!(mint_state(&mut setup).await.epoch_burned, SMALL_BALANCE);
+}
+
+#[tokio::test]
+async fn duplicate_mints_in_one_transaction_share_the_cap() {
+    let mut setup = setup().await;
+    set_time(&mut setup.ctx, EPOCH_START + 100).await;
+    let governance = setup.governance.insecure_clone();
+
+    // Each fits the cap alone; together they do not, so neither applies
+    let ix = mint_ix(&setup, setup.holder_token_account, 60_000);
+    assert!(send(&mut setup.ctx, &[ix.clone(), ix], &[&governance]).await.is_err());
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.epoch_minted, 0);
+    assert_eq!(spl_supply(&mut setup).await, INITIAL_SUPPLY + SMALL_BALANCE);
+
+    let ix = mint_ix(&setup, setup.holder_token_account, 40_000);
+    send(&mut setup.ctx, &[ix.clone(), ix], &[&governance]).await.unwrap();
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.epoch_minted, 80_000);
+    assert_eq!(state.total_supply, INITIAL_SUPPLY + 80_000);
+    assert_eq!(spl_supply(&mut setup).await, INITIAL_SUPPLY + SMALL_BALANCE + 80_000);
+}
+
+#[tokio::test]
+async fn duplicate_rollover_in_one_transaction_is_rejected_whole() {
+    let mut setup = setup().await;
+    set_time(&mut setup.ctx, EPOCH_START + 100).await;
+    mint(&mut setup, 30_000).await.unwrap();
+
+    set_time(&mut setup.ctx, EPOCH_END + 5 * EPOCH_DURATION).await;
+    let ix = start_new_epoch_ix(&setup, 0);
+    assert!(send(&mut setup.ctx, &[ix.clone(), ix], &[]).await.is_err());
+
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 0);
+    assert_eq!(state.epoch_minted, 30_000);
+
+    start_new_epoch(&mut setup).await.unwrap();
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 1);
+    assert_eq!(state.epoch_minted, 0);
+}
@@ -0,0 +1,245 @@
+//! Audits the epoch_minted/epoch_burned reset around epoch boundaries.
+//!
+//! `MintState::maybe_roll_epoch` is the only path that resets the counters.
+//! Mint and burn never roll inline: from `epoch_end` on they are rejected
+//! until `start_new_epoch` records the closed epoch, so activity on either
+//! side of the boundary is attributed to exactly one epoch.
+
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_program_test::{account, fetch, packed, program_test, send, set_time};
+use ars_token::{EpochHistory, MintState};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+const INITIAL_SUPPLY: u64 = 1_000_000;
+// 10% of supply per epoch in each direction
+const CAP_BPS: u16 = 1_000;
+const EPOCH_START: i64 = 1_700_000_000;
+const EPOCH_DURATION: i64 = 86_400;
+const EPOCH_END: i64 = EPOCH_START + EPOCH_DURATION;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    mint_state: Pubkey,
+    aru_mint: Pubkey,
+    governance: Keypair,
+    holder: Keypair,
+    holder_token_account: Pubkey,
+}
+
+/// Starts from an initialized rolling-epoch MintState with existing supply,
+/// since caps are a share of supply and a fresh mint could never mint
+async fn setup() -> Setup {
+    let mut program = program_test!(ars_token);
+
+    let authority = Pubkey::new_unique();
+    let governance = Keypair::new();
+    let holder = Keypair::new();
+    let aru_mint = Pubkey::new_unique();
+    let holder_token_account = Pubkey::new_unique();
+    let (mint_state, bump) =
+        Pubkey::find_program_address(&[b"mint_state", authority.as_ref()], &ars_token::id());
+
+    let state = MintState {
+        authority,
+        aru_mint,
+        current_epoch: 0,
+        epoch_start: EPOCH_START,
+        epoch_duration: EPOCH_DURATION,
+        total_supply: INITIAL_SUPPLY,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: CAP_BPS,
+        burn_cap_per_epoch_bps: CAP_BPS,
+        bridge_adapter: authority,
+        bridged_out: 0,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: governance.pubkey(),
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends: 0,
+        bump,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    };
+    let mut data = Vec::with_capacity(MintState::LEN);
+    state.try_serialize(&mut data).unwrap();
+    data.resize(MintState::LEN, 0);
+    program.add_account(mint_state, account(data, ars_token::id()));
+
+    program.add_account(
+        aru_mint,
+        account(
+            packed(spl_token::state::Mint {
+                mint_authority: COption::Some(mint_state),
+                supply: INITIAL_SUPPLY,
+                decimals: 6,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            }),
+            spl_token::id(),
+        ),
+    );
+    program.add_account(
+        holder_token_account,
+        account(
+            packed(spl_token::state::Account {
+                mint: aru_mint,
+                owner: holder.pubkey(),
+                amount: INITIAL_SUPPLY,
+                delegate: COption::None,
+                state: spl_token::state::AccountState::Initialized,
+                is_native: COption::None,
+                delegated_amount: 0,
+                close_authority: COption::None,
+            }),
+            spl_token::id(),
+        ),
+    );
+
+    Setup {
+        ctx: program.start_with_context().await,
+        mint_state,
+        aru_mint,
+        governance,
+        holder,
+        holder_token_account,
+    }
+}
+
+async fn mint(setup: &mut Setup, amount: u64) -> Result<(), BanksClientError> {
+    let ix = Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::MintARU {
+            mint_state: setup.mint_state,
+            aru_mint: setup.aru_mint,
+            destination: setup.holder_token_account,
+            governance: setup.governance.pubkey(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::MintAru { amount }.data(),
+    };
+    let governance = setup.governance.insecure_clone();
+    send(&mut setup.ctx, &[ix], &[&governance]).await
+}
+
+async fn burn(setup: &mut Setup, amount: u64) -> Result<(), BanksClientError> {
+    let ix = Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::BurnARU {
+            mint_state: setup.mint_state,
+            aru_mint: setup.aru_mint,
+            source: setup.holder_token_account,
+            authority: setup.holder.pubkey(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::BurnAru { amount }.data(),
+    };
+    let holder = setup.holder.insecure_clone();
+    send(&mut setup.ctx, &[ix], &[&holder]).await
+}
+
+async fn start_new_epoch(setup: &mut Setup) -> Result<Pubkey, BanksClientError> {
+    let current_epoch = mint_state(setup).await.current_epoch;
+    let (epoch_history, _) = Pubkey::find_program_address(
+        &[b"epoch_history", current_epoch.to_le_bytes().as_ref()],
+        &ars_token::id(),
+    );
+    let ix = Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::StartNewEpoch {
+            mint_state: setup.mint_state,
+            epoch_history,
+            authority: setup.ctx.payer.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::StartNewEpoch {}.data(),
+    };
+    send(&mut setup.ctx, &[ix], &[]).await?;
+    Ok(epoch_history)
+}
+
+async fn mint_state(setup: &mut Setup) -> MintState {
+    fetch(&mut setup.ctx, setup.mint_state).await
+}
+
+#[tokio::test]
+async fn mint_exactly_at_boundary_waits_for_rollover() {
+    let mut setup = setup().await;
+
+    set_time(&mut setup.ctx, EPOCH_END - 1).await;
+    mint(&mut setup, 60_000).await.unwrap();
+
+    // The boundary second already belongs to the next epoch
+    set_time(&mut setup.ctx, EPOCH_END).await;
+    assert!(mint(&mut setup, 1).await.is_err());
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 0);
+    assert_eq!(state.epoch_minted, 60_000);
+
+    let history = start_new_epoch(&mut setup).await.unwrap();
+    let history: EpochHistory = fetch(&mut setup.ctx, history).await;
+    assert_eq!(history.epoch_number, 0);
+    assert_eq!(history.end_time, EPOCH_END);
+    assert_eq!(history.total_minted, 60_000);
+    assert_eq!(history.total_burned, 0);
+    assert_eq!(history.final_supply, INITIAL_SUPPLY + 60_000);
+
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 1);
+    assert_eq!(state.epoch_start, EPOCH_END);
+    assert_eq!(state.epoch_minted, 0);
+
+    // More than the old epoch's remaining headroom: only a reset allows it
+    mint(&mut setup, 100_000).await.unwrap();
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.epoch_minted, 100_000);
+    assert_eq!(state.total_supply, INITIAL_SUPPLY + 160_000);
+}
+
+#[tokio::test]
+async fn burn_straddling_boundary_is_split_between_epochs() {
+    let mut setup = setup().await;
+
+    set_time(&mut setup.ctx, EPOCH_END - 10).await;
+    burn(&mut setup, 30_000).await.unwrap();
+
+    set_time(&mut setup.ctx, EPOCH_END + 10).await;
+    assert!(burn(&mut setup, 25_000).await.is_err());
+    assert_eq!(mint_state(&mut setup).await.epoch_burned, 30_000);
+
+    let history = start_new_epoch(&mut setup).await.unwrap();
+    burn(&mut setup, 20_000).await.unwrap();
+
+    let history: EpochHistory = fetch(&mut setup.ctx, history).await;
+    assert_eq!(history.total_burned, 30_000);
+    assert_eq!(history.net_supply_change, -30_000);
+    assert_eq!(history.final_supply, INITIAL_SUPPLY - 30_000);
+
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.current_epoch, 1);
+    assert_eq!(state.epoch_start, EPOCH_END + 10);
+    assert_eq!(state.epoch_burned, 20_000);
+    assert_eq!(state.epoch_minted, 0);
+    assert_eq!(state.total_supply, INITIAL_SUPPLY - 50_000);
+
+    // A second rollover before the new epoch ends is refused and resets nothing
+    assert!(start_new_epoch(&mut setup).await.is_err());
+    assert_eq!(mint_state(&mut setup).await.epoch_burned, 20_000);
+}
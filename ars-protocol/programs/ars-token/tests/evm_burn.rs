@@ -0,0 +1,150 @@
+//! Burns on the EVM mirror are recorded from their BurnNotice in nonce order,
+//! count against the epoch burn cap and leave the bridged supply.
+
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_program_test::{account, fetch, program_test, send, set_time};
+use ars_token::{AruCrossChainMessage, MintState, PAYLOAD_BURN_NOTICE, PAYLOAD_MINT_AUTHORIZATION};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const TOTAL_SUPPLY: u64 = 990_000;
+const BRIDGED_OUT: u64 = 10_000;
+// 1% of the 1_000_000 global supply
+const CAP_BPS: u16 = 100;
+const BURN_CAP: u64 = 10_000;
+const EPOCH_START: i64 = 1_700_000_000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    mint_state: Pubkey,
+    bridge_adapter: Keypair,
+}
+
+async fn setup() -> Setup {
+    let mut program = program_test!(ars_token);
+
+    let authority = Pubkey::new_unique();
+    let bridge_adapter = Keypair::new();
+    let (mint_state, bump) =
+        Pubkey::find_program_address(&[b"mint_state", authority.as_ref()], &ars_token::id());
+    let state = MintState {
+        authority,
+        aru_mint: Pubkey::new_unique(),
+        current_epoch: 0,
+        epoch_start: EPOCH_START,
+        epoch_duration: 86_400,
+        total_supply: TOTAL_SUPPLY,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: CAP_BPS,
+        burn_cap_per_epoch_bps: CAP_BPS,
+        bridge_adapter: bridge_adapter.pubkey(),
+        bridged_out: BRIDGED_OUT,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: authority,
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends: 0,
+        bump,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    };
+    let mut data = Vec::with_capacity(MintState::LEN);
+    state.try_serialize(&mut data).unwrap();
+    data.resize(MintState::LEN, 0);
+    program.add_account(mint_state, account(data, ars_token::id()));
+
+    let mut ctx = program.start_with_context().await;
+    set_time(&mut ctx, EPOCH_START).await;
+
+    Setup {
+        ctx,
+        mint_state,
+        bridge_adapter,
+    }
+}
+
+fn burn_notice(amount: u64, nonce: u64) -> Vec<u8> {
+    AruCrossChainMessage {
+        payload_id: PAYLOAD_BURN_NOTICE,
+        counterparty: [7; 32],
+        amount,
+        target_chain: 2,
+        epoch: 0,
+        epoch_cap: 0,
+        epoch_used: 0,
+        nonce,
+    }
+    .encode()
+}
+
+async fn record_evm_burn(setup: &mut Setup, payload: Vec<u8>) -> bool {
+    let instruction = Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::RecordEvmBurn {
+            mint_state: setup.mint_state,
+            bridge_adapter: setup.bridge_adapter.pubkey(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::RecordEvmBurn { payload }.data(),
+    };
+    let bridge_adapter = setup.bridge_adapter.insecure_clone();
+    send(&mut setup.ctx, &[instruction], &[&bridge_adapter]).await.is_ok()
+}
+
+async fn mint_state(setup: &mut Setup) -> MintState {
+    fetch(&mut setup.ctx, setup.mint_state).await
+}
+
+#[tokio::test]
+async fn evm_burns_count_against_the_burn_cap_and_bridged_supply() {
+    let mut setup = setup().await;
+
+    assert!(record_evm_burn(&mut setup, burn_notice(4_000, 0)).await);
+    let state = mint_state(&mut setup).await;
+    assert_eq!(state.epoch_burned, 4_000);
+    assert_eq!(state.bridged_supply().unwrap(), BRIDGED_OUT - 4_000);
+    assert_eq!(state.total_supply, TOTAL_SUPPLY);
+    assert_eq!(state.evm_burn_sequence, 1);
+
+    // The remaining bridged supply would fit, the burn cap does not
+    assert!(!record_evm_burn(&mut setup, burn_notice(BURN_CAP - 4_000 + 1, 1)).await);
+    assert!(record_evm_burn(&mut setup, burn_notice(BURN_CAP - 4_000, 1)).await);
+    assert_eq!(mint_state(&mut setup).await.epoch_burned, BURN_CAP);
+}
+
+#[tokio::test]
+async fn notices_are_recorded_once_and_in_order() {
+    let mut setup = setup().await;
+
+    assert!(!record_evm_burn(&mut setup, burn_notice(1_000, 1)).await);
+    assert!(record_evm_burn(&mut setup, burn_notice(1_000, 0)).await);
+    // Nonce 0 again, whatever else the notice says
+    let mut replay = burn_notice(1_000, 0);
+    replay[2] = 8;
+    assert!(!record_evm_burn(&mut setup, replay).await);
+    assert_eq!(mint_state(&mut setup).await.epoch_burned, 1_000);
+}
+
+#[tokio::test]
+async fn only_burn_notices_from_the_adapter_are_accepted() {
+    let mut setup = setup().await;
+
+    let mut mint_authorization = burn_notice(1_000, 0);
+    mint_authorization[0] = PAYLOAD_MINT_AUTHORIZATION;
+    assert!(!record_evm_burn(&mut setup, mint_authorization).await);
+
+    setup.bridge_adapter = Keypair::new();
+    assert!(!record_evm_burn(&mut setup, burn_notice(1_000, 0)).await);
+    assert_eq!(mint_state(&mut setup).await.evm_burn_sequence, 0);
+}
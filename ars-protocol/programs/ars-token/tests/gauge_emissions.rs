@@ -0,0 +1,185 @@
+//! Governance can split the epoch mint cap across gauges but not exceed it in
+//! total: each set_gauge_reward is checked against the sum of every gauge's
+//! epoch_reward, with the gauge's own previous reward replaced. Like every
+//! other mint path, the cap is halved during the post-breaker cooldown.
+
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_program_test::{account, fetch, packed, program_test, send};
+use ars_token::MintState;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+const SUPPLY: u64 = 1_000_000;
+// 10% of supply per epoch, so 100_000 ARU across all gauges
+const CAP_BPS: u16 = 1_000;
+const MINT_CAP: u64 = 100_000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    authority: Keypair,
+    mint_state: Pubkey,
+    gauges: [Pubkey; 2],
+}
+
+async fn setup(cooldown_ends: i64) -> Setup {
+    let mut program = program_test!(ars_token);
+
+    let authority = Keypair::new();
+    let (mint_state, bump) =
+        Pubkey::find_program_address(&[b"mint_state", authority.pubkey().as_ref()], &ars_token::id());
+    let state = MintState {
+        authority: authority.pubkey(),
+        aru_mint: Pubkey::new_unique(),
+        current_epoch: 0,
+        epoch_start: 0,
+        epoch_duration: 86_400,
+        total_supply: SUPPLY,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: CAP_BPS,
+        burn_cap_per_epoch_bps: CAP_BPS,
+        bridge_adapter: authority.pubkey(),
+        bridged_out: 0,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: authority.pubkey(),
+        net_cap_enabled: false,
+        net_cap_per_epoch_bps: 0,
+        cooldown_ends,
+        bump,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    };
+    let mut data = Vec::with_capacity(MintState::LEN);
+    state.try_serialize(&mut data).unwrap();
+    data.resize(MintState::LEN, 0);
+    program.add_account(mint_state, account(data, ars_token::id()));
+    program.add_account(authority.pubkey(), account(vec![], system_program::id()));
+
+    let mut registrations = Vec::new();
+    let mut gauges = [Pubkey::default(); 2];
+    for gauge in &mut gauges {
+        let lp_mint = Pubkey::new_unique();
+        let lp_vault = Pubkey::new_unique();
+        (*gauge, _) = Pubkey::find_program_address(&[b"gauge", lp_mint.as_ref()], &ars_token::id());
+        program.add_account(
+            lp_mint,
+            account(
+                packed(spl_token::state::Mint {
+                    mint_authority: COption::None,
+                    supply: 0,
+                    decimals: 6,
+                    is_initialized: true,
+                    freeze_authority: COption::None,
+                }),
+                spl_token::id(),
+            ),
+        );
+        program.add_account(
+            lp_vault,
+            account(
+                packed(spl_token::state::Account {
+                    mint: lp_mint,
+                    owner: *gauge,
+                    amount: 0,
+                    delegate: COption::None,
+                    state: spl_token::state::AccountState::Initialized,
+                    is_native: COption::None,
+                    delegated_amount: 0,
+                    close_authority: COption::None,
+                }),
+                spl_token::id(),
+            ),
+        );
+        registrations.push(Instruction {
+            program_id: ars_token::id(),
+            accounts: ars_token::accounts::RegisterGauge {
+                mint_state,
+                gauge: *gauge,
+                lp_mint,
+                lp_vault,
+                authority: authority.pubkey(),
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: ars_token::instruction::RegisterGauge {}.data(),
+        });
+    }
+
+    let mut ctx = program.start_with_context().await;
+    send(&mut ctx, &registrations, &[&authority]).await.unwrap();
+
+    Setup {
+        ctx,
+        authority,
+        mint_state,
+        gauges,
+    }
+}
+
+async fn set_gauge_reward(setup: &mut Setup, gauge: usize, epoch_reward: u64) -> bool {
+    let instruction = Instruction {
+        program_id: ars_token::id(),
+        accounts: ars_token::accounts::SetGaugeReward {
+            mint_state: setup.mint_state,
+            gauge: setup.gauges[gauge],
+            authority: setup.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: ars_token::instruction::SetGaugeReward { epoch_reward }.data(),
+    };
+    let authority = setup.authority.insecure_clone();
+    send(&mut setup.ctx, &[instruction], &[&authority]).await.is_ok()
+}
+
+async fn gauge_emissions(setup: &mut Setup) -> u64 {
+    fetch::<MintState>(&mut setup.ctx, setup.mint_state)
+        .await
+        .gauge_emissions
+}
+
+#[tokio::test]
+async fn gauges_share_one_mint_cap() {
+    let mut setup = setup(0).await;
+
+    assert!(set_gauge_reward(&mut setup, 0, 60_000).await);
+    // Within the cap on its own, but not on top of the first gauge
+    assert!(!set_gauge_reward(&mut setup, 1, 60_000).await);
+    assert!(set_gauge_reward(&mut setup, 1, MINT_CAP - 60_000).await);
+    assert_eq!(gauge_emissions(&mut setup).await, MINT_CAP);
+}
+
+#[tokio::test]
+async fn updating_a_gauge_replaces_its_previous_reward() {
+    let mut setup = setup(0).await;
+
+    assert!(set_gauge_reward(&mut setup, 0, MINT_CAP).await);
+    assert!(set_gauge_reward(&mut setup, 0, 30_000).await);
+    assert!(set_gauge_reward(&mut setup, 1, MINT_CAP - 30_000).await);
+    assert_eq!(gauge_emissions(&mut setup).await, MINT_CAP);
+
+    assert!(!set_gauge_reward(&mut setup, 0, 30_001).await);
+    assert_eq!(gauge_emissions(&mut setup).await, MINT_CAP);
+}
+
+#[tokio::test]
+async fn gauges_share_half_the_cap_during_the_breaker_cooldown() {
+    let mut setup = setup(i64::MAX).await;
+
+    assert!(!set_gauge_reward(&mut setup, 0, MINT_CAP).await);
+    assert!(set_gauge_reward(&mut setup, 0, MINT_CAP / 2).await);
+    assert!(!set_gauge_reward(&mut setup, 1, 1).await);
+    assert_eq!(gauge_emissions(&mut setup).await, MINT_CAP / 2);
+}
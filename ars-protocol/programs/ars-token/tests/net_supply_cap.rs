@@ -0,0 +1,80 @@
+//! The optional net supply cap: |epoch_minted - epoch_burned| stays within
+//! ±net_cap_per_epoch_bps of supply, independent of the per-direction caps.
+
+use anchor_lang::prelude::Pubkey;
+use ars_common::ACCOUNT_RESERVED_LEN;
+use ars_token::MintState;
+use proptest::prelude::*;
+
+const SUPPLY: u64 = 1_000_000_000;
+// ±2% of supply
+const NET_CAP_BPS: u16 = 200;
+const NET_CAP: u64 = SUPPLY / 50;
+
+fn mint_state(net_cap_enabled: bool) -> MintState {
+    MintState {
+        authority: Pubkey::new_unique(),
+        aru_mint: Pubkey::new_unique(),
+        current_epoch: 0,
+        epoch_start: 0,
+        epoch_duration: 86_400,
+        total_supply: SUPPLY,
+        epoch_minted: 0,
+        epoch_burned: 0,
+        mint_cap_per_epoch_bps: 1_000,
+        burn_cap_per_epoch_bps: 1_000,
+        bridge_adapter: Pubkey::new_unique(),
+        bridged_out: 0,
+        bridged_in: 0,
+        bridge_sequence: 0,
+        epoch_aligned: false,
+        epoch_offset: 0,
+        locked: false,
+        governance: Pubkey::new_unique(),
+        net_cap_enabled,
+        net_cap_per_epoch_bps: NET_CAP_BPS,
+        cooldown_ends: 0,
+        bump: 255,
+        gauge_emissions: 0,
+        evm_burn_sequence: 0,
+        _reserved: [0; ACCOUNT_RESERVED_LEN - 16],
+    }
+}
+
+#[test]
+fn disabled_mode_only_leaves_the_per_direction_caps() {
+    let state = mint_state(false);
+    assert!(state.check_net_cap(SUPPLY / 10, 0).is_ok());
+}
+
+#[test]
+fn net_change_is_capped_in_both_directions() {
+    let state = mint_state(true);
+    assert!(state.check_net_cap(NET_CAP, 0).is_ok());
+    assert!(state.check_net_cap(NET_CAP + 1, 0).is_err());
+    assert!(state.check_net_cap(0, NET_CAP).is_ok());
+    assert!(state.check_net_cap(0, NET_CAP + 1).is_err());
+}
+
+#[test]
+fn offsetting_activity_does_not_count_against_the_net_cap() {
+    let state = mint_state(true);
+    assert!(state.check_net_cap(SUPPLY / 20, SUPPLY / 20 - NET_CAP).is_ok());
+}
+
+#[test]
+fn bridged_supply_counts_toward_the_cap_base() {
+    let mut state = mint_state(true);
+    state.bridged_out = SUPPLY;
+    assert!(state.check_net_cap(2 * NET_CAP, 0).is_ok());
+    assert!(state.check_net_cap(2 * NET_CAP + 1, 0).is_err());
+}
+
+proptest! {
+    #[test]
+    fn accepted_epochs_stay_within_two_percent(minted in 0..SUPPLY, burned in 0..SUPPLY) {
+        let state = mint_state(true);
+        let accepted = state.check_net_cap(minted, burned).is_ok();
+        prop_assert_eq!(accepted, minted.abs_diff(burned) * 10_000 <= SUPPLY * NET_CAP_BPS as u64);
+    }
+}
@@ -0,0 +1,118 @@
+#!/usr/bin/env python3
+"""Regenerate agent_messages.json.
+
+Keys are derived from fixed seeds so output is byte-for-byte reproducible.
+Message layouts must mirror programs/ars-core/src/signing.rs exactly.
+
+Usage: python3 generate.py > agent_messages.json
+"""
+import json
+import struct
+
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+from cryptography.hazmat.primitives.serialization import Encoding, PublicFormat
+
+SEEDS = {
+    "agent_a": bytes([1] * 32),
+    "agent_b": bytes([2] * 32),
+}
+
+
+def keypair(seed):
+    sk = Ed25519PrivateKey.from_private_bytes(seed)
+    pk = sk.public_key().public_bytes(Encoding.Raw, PublicFormat.Raw)
+    return sk, pk
+
+
+def u64(v):
+    return struct.pack("<Q", v)
+
+
+def i64(v):
+    return struct.pack("<q", v)
+
+
+DOMAIN = b"ARS_AGENT_MSG"
+VERSION = 1
+
+
+def create_agent_message(action, fields):
+    parts = [action] + fields
+    return (
+        DOMAIN
+        + bytes([VERSION])
+        + struct.pack("<H", len(fields))
+        + b"".join(struct.pack("<H", len(p)) + p for p in parts)
+    )
+
+
+def create_legacy_agent_message(action, fields):
+    return action + b"".join(fields)
+
+
+FORMATS = {
+    "v1": create_agent_message,
+    "legacy": create_legacy_agent_message,
+}
+
+
+def ili_update(fmt, agent, ili_value, timestamp):
+    return FORMATS[fmt](b"ili_update", [agent, u64(ili_value), i64(timestamp)])
+
+
+def mint(fmt, agent, amount, epoch):
+    return FORMATS[fmt](b"mint", [agent, u64(amount), u64(epoch)])
+
+
+def burn(fmt, agent, amount, epoch):
+    return FORMATS[fmt](b"burn", [agent, u64(amount), u64(epoch)])
+
+
+def vote(fmt, agent, proposal_id, vote_yes, stake_amount):
+    return FORMATS[fmt](
+        b"vote", [agent, u64(proposal_id), bytes([int(vote_yes)]), u64(stake_amount)]
+    )
+
+
+CASES = [
+    ("ili_update", "agent_a", ili_update, {"ili_value": 10500, "timestamp": 1_700_000_000}),
+    ("ili_update", "agent_b", ili_update, {"ili_value": 0, "timestamp": 0}),
+    ("ili_update", "agent_a", ili_update, {"ili_value": 2**64 - 1, "timestamp": -1}),
+    ("mint", "agent_a", mint, {"amount": 1_000_000, "epoch": 42}),
+    ("burn", "agent_b", burn, {"amount": 250_000, "epoch": 7}),
+    ("vote", "agent_a", vote, {"proposal_id": 3, "vote_yes": True, "stake_amount": 100_000_000}),
+    ("vote", "agent_b", vote, {"proposal_id": 3, "vote_yes": False, "stake_amount": 1}),
+]
+
+
+def main():
+    keys = {name: keypair(seed) for name, seed in SEEDS.items()}
+    vectors = []
+    cases = [(fmt, *case) for fmt in FORMATS for case in CASES]
+    for fmt, kind, key_name, build, inputs in cases:
+        sk, pk = keys[key_name]
+        message = build(fmt, pk, *inputs.values())
+        vectors.append({
+            "format": fmt,
+            "kind": kind,
+            "signer": key_name,
+            # Integers are strings so 64-bit values survive JSON parsers
+            "inputs": {
+                k: v if isinstance(v, bool) else str(v) for k, v in inputs.items()
+            },
+            "message_hex": message.hex(),
+            "signature_hex": sk.sign(message).hex(),
+        })
+
+    print(json.dumps({
+        "version": 2,
+        "keys": {
+            name: {"seed_hex": seed.hex(), "pubkey_hex": keys[name][1].hex()}
+            for name, seed in SEEDS.items()
+        },
+        "vectors": vectors,
+    }, indent=2))
+
+
+if __name__ == "__main__":
+    main()
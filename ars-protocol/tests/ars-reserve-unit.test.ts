@@ -15,6 +15,7 @@ describe("ars-reserve unit tests", () => {
   let authority: Keypair;
   let usdcMint: PublicKey;
   let usdcVault: PublicKey;
+  let assetConfig: PublicKey;
   let solVault: Keypair;
   let msolVault: Keypair;
   let jitosolVault: Keypair;
@@ -54,6 +55,11 @@ describe("ars-reserve unit tests", () => {
       [Buffer.from("vault"), authority.publicKey.toBuffer()],
       program.programId
     );
+
+    [assetConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("asset_config"), vault.toBuffer(), usdcMint.toBuffer()],
+      program.programId
+    );
   });
 
   describe("initialize", () => {
@@ -79,7 +85,7 @@ describe("ars-reserve unit tests", () => {
       expect(vaultAccount.authority.toString()).to.equal(authority.publicKey.toString());
       expect(vaultAccount.minVhr).to.equal(15000);
       expect(vaultAccount.rebalanceThresholdBps).to.equal(17500);
-      expect(vaultAccount.totalValueUsd.toNumber()).to.equal(0);
+      expect(vaultAccount.totalValue.toNumber()).to.equal(0);
       expect(vaultAccount.vhr).to.equal(65535); // u16::MAX
     });
 
@@ -132,6 +138,26 @@ describe("ars-reserve unit tests", () => {
     });
   });
 
+  describe("register asset", () => {
+    it("should record the mint decimals", async () => {
+      await program.methods
+        .registerAsset(10_000, 0, 10_000, 500, PublicKey.default)
+        .accounts({
+          vault,
+          assetConfig,
+          mint: usdcMint,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const config = await program.account.assetConfig.fetch(assetConfig);
+      expect(config.mint.toString()).to.equal(usdcMint.toString());
+      expect(config.decimals).to.equal(6);
+    });
+  });
+
   describe("deposit", () => {
     let user: Keypair;
     let userTokenAccount: PublicKey;
@@ -178,19 +204,20 @@ describe("ars-reserve unit tests", () => {
       const depositAmount = new anchor.BN(100_000_000); // 100 USDC
 
       await program.methods
-        .deposit(depositAmount)
+        .deposit(depositAmount, null)
         .accounts({
           vault,
           user: user.publicKey,
           userTokenAccount,
           vaultTokenAccount,
+          assetConfig,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([user])
         .rpc();
 
       const vaultAccount = await program.account.reserveVault.fetch(vault);
-      expect(vaultAccount.totalValueUsd.toNumber()).to.equal(100_000_000);
+      expect(vaultAccount.totalValue.toNumber()).to.equal(100_000_000);
 
       const vaultTokenAccountInfo = await getAccount(provider.connection, vaultTokenAccount);
       expect(Number(vaultTokenAccountInfo.amount)).to.equal(100_000_000);
@@ -201,12 +228,13 @@ describe("ars-reserve unit tests", () => {
       
       try {
         await program.methods
-          .deposit(zeroAmount)
+          .deposit(zeroAmount, null)
           .accounts({
             vault,
             user: user.publicKey,
             userTokenAccount,
             vaultTokenAccount,
+            assetConfig,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([user])
@@ -222,19 +250,20 @@ describe("ars-reserve unit tests", () => {
       const depositAmount = new anchor.BN(50_000_000); // 50 USDC
 
       await program.methods
-        .deposit(depositAmount)
+        .deposit(depositAmount, null)
         .accounts({
           vault,
           user: user.publicKey,
           userTokenAccount,
           vaultTokenAccount,
+          assetConfig,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([user])
         .rpc();
 
       const vaultAccount = await program.account.reserveVault.fetch(vault);
-      expect(vaultAccount.totalValueUsd.toNumber()).to.equal(150_000_000);
+      expect(vaultAccount.totalValue.toNumber()).to.equal(150_000_000);
       // VHR should be recalculated
       expect(vaultAccount.vhr).to.be.greaterThan(0);
     });
@@ -271,7 +300,7 @@ describe("ars-reserve unit tests", () => {
       const withdrawAmount = new anchor.BN(25_000_000); // 25 USDC
 
       const vaultBefore = await program.account.reserveVault.fetch(vault);
-      const totalValueBefore = vaultBefore.totalValueUsd.toNumber();
+      const totalValueBefore = vaultBefore.totalValue.toNumber();
 
       await program.methods
         .withdraw(withdrawAmount)
@@ -280,13 +309,15 @@ describe("ars-reserve unit tests", () => {
           user: user.publicKey,
           userTokenAccount,
           vaultTokenAccount,
+          assetConfig,
+          mint: usdcMint,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([user])
         .rpc();
 
       const vaultAfter = await program.account.reserveVault.fetch(vault);
-      expect(vaultAfter.totalValueUsd.toNumber()).to.equal(totalValueBefore - 25_000_000);
+      expect(vaultAfter.totalValue.toNumber()).to.equal(totalValueBefore - 25_000_000);
 
       const userTokenAccountInfo = await getAccount(provider.connection, userTokenAccount);
       expect(Number(userTokenAccountInfo.amount)).to.equal(25_000_000);
@@ -304,6 +335,8 @@ describe("ars-reserve unit tests", () => {
             user: user.publicKey,
             userTokenAccount,
             vaultTokenAccount,
+            assetConfig,
+            mint: usdcMint,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([user])
@@ -326,6 +359,8 @@ describe("ars-reserve unit tests", () => {
             user: user.publicKey,
             userTokenAccount,
             vaultTokenAccount,
+            assetConfig,
+            mint: usdcMint,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([user])
@@ -340,10 +375,10 @@ describe("ars-reserve unit tests", () => {
 
   describe("rebalance", () => {
     it("should trigger rebalance when VHR below threshold", async () => {
-      const rebalanceAmount = new anchor.BN(10_000_000); // 10 USDC
+      const targetWeights = [4000, 3000, 1500, 1500]; // USDC/SOL/mSOL/JitoSOL
 
       await program.methods
-        .rebalance(rebalanceAmount)
+        .rebalance(targetWeights)
         .accounts({
           vault,
           authority: authority.publicKey,
@@ -353,13 +388,14 @@ describe("ars-reserve unit tests", () => {
 
       const vaultAccount = await program.account.reserveVault.fetch(vault);
       expect(vaultAccount.lastRebalance.toNumber()).to.be.greaterThan(0);
+      expect(vaultAccount.targetWeightsBps).to.deep.equal(targetWeights);
     });
 
     it("should fail when rebalance not needed", async () => {
       // If VHR is above threshold, rebalance should fail
       try {
         await program.methods
-          .rebalance(new anchor.BN(10_000_000))
+          .rebalance([10_000, 0, 0, 0])
           .accounts({
             vault,
             authority: authority.publicKey,
@@ -401,12 +437,13 @@ describe("ars-reserve unit tests", () => {
       );
 
       await program.methods
-        .deposit(new anchor.BN(500_000_000))
+        .deposit(new anchor.BN(500_000_000), null)
         .accounts({
           vault,
           user: user.publicKey,
           userTokenAccount,
           vaultTokenAccount: usdcVault,
+          assetConfig,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([user])
@@ -416,11 +453,11 @@ describe("ars-reserve unit tests", () => {
       
       // VHR = (total_value * 10000) / liabilities
       // If liabilities = 0, VHR = u16::MAX
-      if (vaultAccount.liabilitiesUsd.toNumber() === 0) {
+      if (vaultAccount.liabilities.toNumber() === 0) {
         expect(vaultAccount.vhr).to.equal(65535);
       } else {
         const expectedVhr = Math.floor(
-          (vaultAccount.totalValueUsd.toNumber() * 10000) / vaultAccount.liabilitiesUsd.toNumber()
+          (vaultAccount.totalValue.toNumber() * 10000) / vaultAccount.liabilities.toNumber()
         );
         expect(vaultAccount.vhr).to.be.closeTo(expectedVhr, 10);
       }
@@ -456,12 +493,13 @@ describe("ars-reserve unit tests", () => {
 
       try {
         await program.methods
-          .deposit(maxAmount)
+          .deposit(maxAmount, null)
           .accounts({
             vault,
             user: user.publicKey,
             userTokenAccount,
             vaultTokenAccount: usdcVault,
+            assetConfig,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([user])
@@ -153,8 +153,10 @@ describe("ars-token unit tests", () => {
           mintState,
           aruMint,
           destination,
+          governance: authority.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .signers([authority])
         .rpc();
 
       const mintStateAccount = await program.account.mintState.fetch(mintState);
@@ -181,8 +183,10 @@ describe("ars-token unit tests", () => {
             mintState,
             aruMint,
             destination,
+            governance: authority.publicKey,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
+          .signers([authority])
           .rpc();
         
         expect.fail("Should have thrown error");
@@ -203,8 +207,10 @@ describe("ars-token unit tests", () => {
           mintState,
           aruMint,
           destination,
+          governance: authority.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .signers([authority])
         .rpc();
 
       const mintStateAfter = await program.account.mintState.fetch(mintState);
@@ -231,8 +237,10 @@ describe("ars-token unit tests", () => {
           mintState,
           aruMint,
           destination: source,
+          governance: authority.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .signers([authority])
         .rpc();
     });
 
@@ -420,8 +428,10 @@ describe("ars-token unit tests", () => {
             mintState,
             aruMint,
             destination,
+            governance: authority.publicKey,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
+          .signers([authority])
           .rpc();
 
         const mintStateAfter = await program.account.mintState.fetch(mintState);
@@ -467,8 +477,10 @@ describe("ars-token unit tests", () => {
               aruMint,
               authority.publicKey
             ),
+            governance: authority.publicKey,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
+          .signers([authority])
           .rpc();
         
         expect.fail("Should have thrown error");
@@ -92,7 +92,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
           aruMint,
           agentTokenAccount,
           authority,
-          10_000_000_000 // 10,000 ARU
+          1_000_000_000_000 // Silver tier, the lowest allowed to submit ILI updates
         );
 
         const stakeEscrow = await createAccount(
@@ -103,7 +103,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
         );
 
         await program.methods
-          .registerAgent(new anchor.BN(10_000_000_000))
+          .registerAgent(new anchor.BN(1_000_000_000_000))
           .accounts({
             agentRegistry,
             agent: agent.publicKey,
@@ -134,6 +134,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -148,6 +149,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -171,6 +173,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -196,6 +199,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -210,6 +214,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i + 2],
             agent: agents[i + 2].publicKey,
+            authority: agents[i + 2].publicKey,
           })
           .signers([agents[i + 2]])
           .rpc();
@@ -234,6 +239,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
           globalState,
           agentRegistry: agentRegistries[0],
           agent: agents[0].publicKey,
+          authority: agents[0].publicKey,
         })
         .signers([agents[0]])
         .rpc();
@@ -245,6 +251,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
           globalState,
           agentRegistry: agentRegistries[1],
           agent: agents[1].publicKey,
+          authority: agents[1].publicKey,
         })
         .signers([agents[1]])
         .rpc();
@@ -285,7 +292,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
         aruMint,
         agentTokenAccount,
         authority,
-        10_000_000_000
+        1_000_000_000_000
       );
 
       const stakeEscrow = await createAccount(
@@ -296,7 +303,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
       );
 
       await program.methods
-        .registerAgent(new anchor.BN(10_000_000_000))
+        .registerAgent(new anchor.BN(1_000_000_000_000))
         .accounts({
           agentRegistry,
           agent: agent.publicKey,
@@ -320,6 +327,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry,
             agent: agent.publicKey,
+            authority: agent.publicKey,
           })
           .signers([agent])
           .rpc();
@@ -341,6 +349,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry,
             agent: agent.publicKey,
+            authority: agent.publicKey,
           })
           .signers([agent])
           .rpc();
@@ -362,6 +371,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
           globalState,
           agentRegistry,
           agent: agent.publicKey,
+          authority: agent.publicKey,
         })
         .signers([agent])
         .rpc();
@@ -375,6 +385,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry,
             agent: agent.publicKey,
+            authority: agent.publicKey,
           })
           .signers([agent])
           .rpc();
@@ -421,7 +432,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
         aruMint,
         agentTokenAccount,
         authority,
-        10_000_000_000
+        1_000_000_000_000
       );
 
       const stakeEscrow = await createAccount(
@@ -432,7 +443,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
       );
 
       await program.methods
-        .registerAgent(new anchor.BN(10_000_000_000))
+        .registerAgent(new anchor.BN(1_000_000_000_000))
         .accounts({
           agentRegistry,
           agent: agent.publicKey,
@@ -459,6 +470,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry,
             agent: wrongSigner.publicKey, // Wrong signer
+            authority: wrongSigner.publicKey,
           })
           .signers([wrongSigner])
           .rpc();
@@ -480,6 +492,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
           globalState,
           agentRegistry,
           agent: agent.publicKey,
+          authority: agent.publicKey,
         })
         .signers([agent])
         .rpc();
@@ -493,6 +506,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry,
             agent: agent.publicKey,
+            authority: agent.publicKey,
           })
           .signers([agent])
           .rpc();
@@ -518,6 +532,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: unregisteredRegistry,
             agent: unregisteredAgent.publicKey,
+            authority: unregisteredAgent.publicKey,
           })
           .signers([unregisteredAgent])
           .rpc();
@@ -563,7 +578,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
           aruMint,
           agentTokenAccount,
           authority,
-          10_000_000_000
+          1_000_000_000_000
         );
 
         const stakeEscrow = await createAccount(
@@ -574,7 +589,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
         );
 
         await program.methods
-          .registerAgent(new anchor.BN(10_000_000_000))
+          .registerAgent(new anchor.BN(1_000_000_000_000))
           .accounts({
             agentRegistry,
             agent: agent.publicKey,
@@ -606,6 +621,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -620,6 +636,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -648,6 +665,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -662,6 +680,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
             globalState,
             agentRegistry: agentRegistries[i],
             agent: agents[i].publicKey,
+            authority: agents[i].publicKey,
           })
           .signers([agents[i]])
           .rpc();
@@ -693,6 +712,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
               globalState,
               agentRegistry: agentRegistries[i],
               agent: agents[i].publicKey,
+              authority: agents[i].publicKey,
             })
             .signers([agents[i]])
             .rpc();
@@ -707,6 +727,7 @@ describe("Byzantine Fault Tolerance Tests", () => {
               globalState,
               agentRegistry: agentRegistries[i],
               agent: agents[i].publicKey,
+              authority: agents[i].publicKey,
             })
             .signers([agents[i]])
             .rpc();
@@ -24,6 +24,8 @@ import {
   getAccount,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { proposalHashPda } from "./utils/proposal-hash";
+import { proposalMetadata } from "./utils/proposal-metadata";
 
 describe("Devnet Instructions Test", () => {
   // Configure provider for devnet
@@ -428,6 +430,7 @@ describe("Devnet Instructions Test", () => {
           globalState,
           agentRegistry,
           agent: agent1.publicKey,
+          authority: agent1.publicKey,
         })
         .signers([agent1])
         .rpc();
@@ -454,6 +457,7 @@ describe("Devnet Instructions Test", () => {
           globalState,
           agentRegistry,
           agent: agent2.publicKey,
+          authority: agent2.publicKey,
         })
         .signers([agent2])
         .rpc();
@@ -480,6 +484,7 @@ describe("Devnet Instructions Test", () => {
           globalState,
           agentRegistry,
           agent: agent3.publicKey,
+          authority: agent3.publicKey,
         })
         .signers([agent3])
         .rpc();
@@ -511,18 +516,17 @@ describe("Devnet Instructions Test", () => {
         arsCoreProgram.programId
       );
 
-      const policyParams = Buffer.from([1, 0, 0, 0]); // Simple params
+      const policyParams = {
+        mintParams: { amount: new BN(1_000_000), destination: agent1.publicKey },
+      };
       const votingPeriod = new BN(86400); // 24 hours
 
       const tx = await arsCoreProgram.methods
-        .createProposal(
-          { mintAru: {} }, // PolicyType enum
-          Array.from(policyParams),
-          votingPeriod
-        )
+        .createProposal(policyParams, votingPeriod, proposalMetadata(), null)
         .accounts({
           globalState,
           proposal: proposalPda,
+          proposalHash: proposalHashPda(arsCoreProgram, policyParams),
           proposer: agent1.publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
@@ -553,6 +557,7 @@ describe("Devnet Instructions Test", () => {
           proposal: proposalPda,
           agentRegistry,
           voter: agent1.publicKey,
+          authority: agent1.publicKey,
         })
         .signers([agent1])
         .rpc();
@@ -585,6 +590,7 @@ describe("Devnet Instructions Test", () => {
           proposal: proposalPda,
           agentRegistry,
           voter: agent2.publicKey,
+          authority: agent2.publicKey,
         })
         .signers([agent2])
         .rpc();
@@ -599,18 +605,39 @@ describe("Devnet Instructions Test", () => {
   });
 
   describe("5. Vault Deposits and Withdrawals", () => {
+    let assetConfig: web3.PublicKey;
+
+    before(async () => {
+      [assetConfig] = web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_config"), reserveVault.toBuffer(), usdcMint.toBuffer()],
+        arsReserveProgram.programId
+      );
+
+      await arsReserveProgram.methods
+        .registerAsset(10_000, 0, 10_000, 500, web3.PublicKey.default)
+        .accounts({
+          vault: reserveVault,
+          assetConfig,
+          mint: usdcMint,
+          authority,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+    });
+
     it("Should deposit USDC to reserve vault", async () => {
       console.log("\n💰 Depositing USDC to vault...");
 
       const depositAmount = new BN(100_000_000); // 100 USDC
 
       const tx = await arsReserveProgram.methods
-        .deposit(depositAmount)
+        .deposit(depositAmount, null)
         .accounts({
           vault: reserveVault,
           user: authority,
           userTokenAccount: userUsdcAccount,
           vaultTokenAccount: vaultUsdcAccount,
+          assetConfig,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc();
@@ -619,10 +646,10 @@ describe("Devnet Instructions Test", () => {
 
       const vaultAccount = await arsReserveProgram.account.reserveVault.fetch(reserveVault);
       console.log(`✅ Deposit successful:`);
-      console.log(`   Total value: ${vaultAccount.totalValueUsd.toString()}`);
+      console.log(`   Total value: ${vaultAccount.totalValue.toString()}`);
       console.log(`   VHR: ${vaultAccount.vhr}`);
       
-      assert.ok(vaultAccount.totalValueUsd.toNumber() > 0);
+      assert.ok(vaultAccount.totalValue.toNumber() > 0);
     });
 
     it("Should withdraw USDC from reserve vault", async () => {
@@ -637,6 +664,7 @@ describe("Devnet Instructions Test", () => {
           user: authority,
           userTokenAccount: userUsdcAccount,
           vaultTokenAccount: vaultUsdcAccount,
+          assetConfig,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc();
@@ -645,7 +673,7 @@ describe("Devnet Instructions Test", () => {
 
       const vaultAccount = await arsReserveProgram.account.reserveVault.fetch(reserveVault);
       console.log(`✅ Withdrawal successful:`);
-      console.log(`   Total value: ${vaultAccount.totalValueUsd.toString()}`);
+      console.log(`   Total value: ${vaultAccount.totalValue.toString()}`);
       console.log(`   VHR: ${vaultAccount.vhr}`);
     });
   });
@@ -6,6 +6,8 @@ import { ArsToken } from "../target/types/ars_token";
 import { expect } from "chai";
 import { Keypair, PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo } from "@solana/spl-token";
+import { proposalHashPda } from "./utils/proposal-hash";
+import { proposalMetadata } from "./utils/proposal-metadata";
 
 describe("Economic Attack Simulations", () => {
   const provider = anchor.AnchorProvider.env();
@@ -133,15 +135,14 @@ describe("Economic Attack Simulations", () => {
         coreProgram.programId
       )[0];
 
+      const policyParams = { mintParams: { amount: new anchor.BN("18446744073709551615"), destination: attacker.publicKey } };
+
       await coreProgram.methods
-        .createProposal(
-          { mintAru: {} },
-          [1, 2, 3, 4],
-          new anchor.BN(86400)
-        )
+        .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
         .accounts({
           globalState,
           proposal,
+          proposalHash: proposalHashPda(coreProgram, policyParams),
           proposer: attacker.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -155,6 +156,7 @@ describe("Economic Attack Simulations", () => {
           proposal,
           agentRegistry: attackerRegistry,
           voter: attacker.publicKey,
+          authority: attacker.publicKey,
         })
         .signers([attacker])
         .rpc();
@@ -200,15 +202,14 @@ describe("Economic Attack Simulations", () => {
             coreProgram.programId
           )[0];
 
+          const policyParams = { burnParams: { amount: new anchor.BN(i + 1) } };
+
           await coreProgram.methods
-            .createProposal(
-              { mintAru: {} },
-              [i],
-              new anchor.BN(86400)
-            )
+            .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
             .accounts({
               globalState,
               proposal,
+              proposalHash: proposalHashPda(coreProgram, policyParams),
               proposer: attacker.publicKey,
               systemProgram: SystemProgram.programId,
             })
@@ -244,6 +245,7 @@ describe("Economic Attack Simulations", () => {
             globalState,
             agentRegistry: attackerRegistry,
             agent: attacker.publicKey,
+            authority: attacker.publicKey,
           })
           .signers([attacker])
           .rpc();
@@ -300,6 +302,7 @@ describe("Economic Attack Simulations", () => {
             globalState,
             agentRegistry: attackerRegistry,
             agent: attacker.publicKey,
+            authority: attacker.publicKey,
           })
           .signers([attacker])
           .rpc();
@@ -491,7 +494,7 @@ describe("Economic Attack Simulations", () => {
 
       try {
         await coreProgram.methods
-          .triggerCircuitBreaker("Griefing attack")
+          .triggerCircuitBreaker({ other: {} }, null)
           .accounts({
             globalState,
             agentRegistry: attackerRegistry,
@@ -516,7 +519,7 @@ describe("Economic Attack Simulations", () => {
       // Second trigger should fail (circuit breaker already active)
       try {
         await coreProgram.methods
-          .triggerCircuitBreaker("Second trigger")
+          .triggerCircuitBreaker({ other: {} }, null)
           .accounts({
             globalState,
             agentRegistry: PublicKey.findProgramAddressSync(
@@ -540,7 +543,7 @@ describe("Economic Attack Simulations", () => {
       
       try {
         await coreProgram.methods
-          .triggerCircuitBreaker("No deposit attack")
+          .triggerCircuitBreaker({ other: {} }, null)
           .accounts({
             globalState,
             agentRegistry: PublicKey.findProgramAddressSync(
@@ -6,6 +6,8 @@ import { ArsToken } from "../target/types/ars_token";
 import { expect } from "chai";
 import { Keypair, PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
+import { proposalHashPda } from "./utils/proposal-hash";
+import { proposalMetadata } from "./utils/proposal-metadata";
 
 describe("Multi-program integration tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -135,15 +137,14 @@ describe("Multi-program integration tests", () => {
         coreProgram.programId
       )[0];
 
+      const policyParams = { rebalanceParams: { weights: [4000, 3000, 1500, 1500] } };
+
       await coreProgram.methods
-        .createProposal(
-          { rebalanceVault: {} },
-          [1, 2, 3, 4],
-          new anchor.BN(86400)
-        )
+        .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
         .accounts({
           globalState,
           proposal,
+          proposalHash: proposalHashPda(coreProgram, policyParams),
           proposer: authority.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -177,7 +178,7 @@ describe("Multi-program integration tests", () => {
       )[0];
 
       await coreProgram.methods
-        .triggerCircuitBreaker("Emergency test")
+        .triggerCircuitBreaker({ other: {} }, null)
         .accounts({
           globalState,
           agentRegistry,
@@ -201,12 +202,16 @@ describe("Multi-program integration tests", () => {
 
       try {
         await reserveProgram.methods
-          .deposit(new anchor.BN(1_000_000))
+          .deposit(new anchor.BN(1_000_000), null)
           .accounts({
             vault,
             user: user.publicKey,
             userTokenAccount,
             vaultTokenAccount: usdcVault,
+            assetConfig: PublicKey.findProgramAddressSync(
+              [Buffer.from("asset_config"), vault.toBuffer(), usdcMint.toBuffer()],
+              reserveProgram.programId
+            )[0],
             tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([user])
@@ -239,6 +244,7 @@ describe("Multi-program integration tests", () => {
             coreProgram.programId
           )[0],
           agent: agent1.publicKey,
+          authority: agent1.publicKey,
         })
         .signers([agent1])
         .rpc();
@@ -283,18 +289,15 @@ describe("Multi-program integration tests", () => {
       )[0];
 
       const mintAmount = 1_000_000_000; // 1,000 ARU
-      const policyParams = Buffer.alloc(8);
-      policyParams.writeBigUInt64LE(BigInt(mintAmount));
+
+      const policyParams = { mintParams: { amount: new anchor.BN(mintAmount), destination: authority.publicKey } };
 
       await coreProgram.methods
-        .createProposal(
-          { mintAru: {} },
-          Array.from(policyParams),
-          new anchor.BN(86400)
-        )
+        .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
         .accounts({
           globalState,
           proposal,
+          proposalHash: proposalHashPda(coreProgram, policyParams),
           proposer: authority.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -339,18 +342,15 @@ describe("Multi-program integration tests", () => {
       )[0];
 
       const burnAmount = 1_000_000; // 1 ARU
-      const policyParams = Buffer.alloc(8);
-      policyParams.writeBigUInt64LE(BigInt(burnAmount));
+
+      const policyParams = { burnParams: { amount: new anchor.BN(burnAmount) } };
 
       await coreProgram.methods
-        .createProposal(
-          { burnAru: {} },
-          Array.from(policyParams),
-          new anchor.BN(86400)
-        )
+        .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
         .accounts({
           globalState,
           proposal,
+          proposalHash: proposalHashPda(coreProgram, policyParams),
           proposer: authority.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -380,15 +380,14 @@ describe("Multi-program integration tests", () => {
         coreProgram.programId
       )[0];
 
+      const policyParams = { paramUpdate: { field: { mintBurnCapBps: {} }, value: new anchor.BN(200) } };
+
       await coreProgram.methods
-        .createProposal(
-          { startNewEpoch: {} },
-          [],
-          new anchor.BN(86400)
-        )
+        .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
         .accounts({
           globalState,
           proposal,
+          proposalHash: proposalHashPda(coreProgram, policyParams),
           proposer: authority.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -495,6 +494,7 @@ describe("Multi-program integration tests", () => {
           globalState,
           agentRegistry: agentRegistry1,
           agent: agent1.publicKey,
+          authority: agent1.publicKey,
         })
         .signers([agent1])
         .rpc();
@@ -506,6 +506,7 @@ describe("Multi-program integration tests", () => {
           globalState,
           agentRegistry: agentRegistry2,
           agent: agent2.publicKey,
+          authority: agent2.publicKey,
         })
         .signers([agent2])
         .rpc();
@@ -517,6 +518,7 @@ describe("Multi-program integration tests", () => {
           globalState,
           agentRegistry: agentRegistry3,
           agent: agent3.publicKey,
+          authority: agent3.publicKey,
         })
         .signers([agent3])
         .rpc();
@@ -532,15 +534,14 @@ describe("Multi-program integration tests", () => {
         coreProgram.programId
       )[0];
 
+      const policyParams = { mintParams: { amount: new anchor.BN(1_000_000), destination: authority.publicKey } };
+
       await coreProgram.methods
-        .createProposal(
-          { mintAru: {} },
-          [1, 2, 3, 4],
-          new anchor.BN(86400)
-        )
+        .createProposal(policyParams, new anchor.BN(86400), proposalMetadata(), null)
         .accounts({
           globalState,
           proposal,
+          proposalHash: proposalHashPda(coreProgram, policyParams),
           proposer: agent1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -554,6 +555,7 @@ describe("Multi-program integration tests", () => {
           proposal,
           agentRegistry: agentRegistry1,
           voter: agent1.publicKey,
+          authority: agent1.publicKey,
         })
         .signers([agent1])
         .rpc();
@@ -564,6 +566,7 @@ describe("Multi-program integration tests", () => {
           proposal,
           agentRegistry: agentRegistry2,
           voter: agent2.publicKey,
+          authority: agent2.publicKey,
         })
         .signers([agent2])
         .rpc();
@@ -600,6 +603,7 @@ describe("Multi-program integration tests", () => {
           globalState,
           agentRegistry: agentRegistry1,
           agent: agent1.publicKey,
+          authority: agent1.publicKey,
         })
         .signers([agent1])
         .rpc();
@@ -611,6 +615,7 @@ describe("Multi-program integration tests", () => {
           globalState,
           agentRegistry: agentRegistry2,
           agent: agent2.publicKey,
+          authority: agent2.publicKey,
         })
         .signers([agent2])
         .rpc();
@@ -623,6 +628,7 @@ describe("Multi-program integration tests", () => {
           globalState,
           agentRegistry: agentRegistry3,
           agent: agent3.publicKey,
+          authority: agent3.publicKey,
         })
         .signers([agent3])
         .rpc();
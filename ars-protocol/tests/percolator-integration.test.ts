@@ -152,6 +152,7 @@ describe("Percolator Integration Tests", () => {
         iliOracle: iliOraclePda,
         agentRegistry: agentRegistryPda,
         agent: agent.publicKey,
+        authority: agent.publicKey,
       })
       .signers([agent])
       .rpc();
@@ -0,0 +1,102 @@
+import { expect } from "chai";
+import { createPublicKey, verify } from "crypto";
+import * as fs from "fs";
+import * as path from "path";
+
+/**
+ * Canonical agent message vectors
+ * Validates that off-chain agents build byte-identical messages to ars-core's
+ * signing module (see test-vectors/agent_messages.json)
+ */
+describe("Agent Signature Test Vectors", () => {
+  const doc = JSON.parse(
+    fs.readFileSync(
+      path.join(__dirname, "..", "test-vectors", "agent_messages.json"),
+      "utf8",
+    ),
+  );
+
+  const u64 = (value: string): Buffer => {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+  };
+
+  const i64 = (value: string): Buffer => {
+    const buf = Buffer.alloc(8);
+    buf.writeBigInt64LE(BigInt(value));
+    return buf;
+  };
+
+  const u16 = (value: number): Buffer => {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    return buf;
+  };
+
+  // domain || version || field count || length-prefixed action and fields
+  const versioned = (action: string, fields: Buffer[]): Buffer =>
+    Buffer.concat([
+      Buffer.from("ARS_AGENT_MSG"),
+      Buffer.from([1]),
+      u16(fields.length),
+      ...[Buffer.from(action), ...fields].flatMap((part) => [
+        u16(part.length),
+        part,
+      ]),
+    ]);
+
+  const legacy = (action: string, fields: Buffer[]): Buffer =>
+    Buffer.concat([Buffer.from(action), ...fields]);
+
+  const buildMessage = (
+    format: string,
+    kind: string,
+    agent: Buffer,
+    inputs: any,
+  ): Buffer => {
+    const encode = format === "v1" ? versioned : legacy;
+    switch (kind) {
+      case "ili_update":
+        return encode("ili_update", [
+          agent,
+          u64(inputs.ili_value),
+          i64(inputs.timestamp),
+        ]);
+      case "mint":
+      case "burn":
+        return encode(kind, [agent, u64(inputs.amount), u64(inputs.epoch)]);
+      case "vote":
+        return encode("vote", [
+          agent,
+          u64(inputs.proposal_id),
+          Buffer.from([inputs.vote_yes ? 1 : 0]),
+          u64(inputs.stake_amount),
+        ]);
+      default:
+        throw new Error(`unknown vector kind ${kind}`);
+    }
+  };
+
+  for (const vector of doc.vectors) {
+    it(`${vector.format} ${vector.kind} by ${vector.signer} matches the canonical bytes`, () => {
+      const pubkey = Buffer.from(doc.keys[vector.signer].pubkey_hex, "hex");
+      const message = buildMessage(
+        vector.format,
+        vector.kind,
+        pubkey,
+        vector.inputs,
+      );
+
+      expect(message.toString("hex")).to.equal(vector.message_hex);
+
+      const key = createPublicKey({
+        key: { kty: "OKP", crv: "Ed25519", x: pubkey.toString("base64url") },
+        format: "jwk",
+      });
+      expect(
+        verify(null, message, key, Buffer.from(vector.signature_hex, "hex")),
+      ).to.equal(true);
+    });
+  }
+});
@@ -0,0 +1,16 @@
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+
+/**
+ * ProposalHash PDA for a PolicyParams value: sha256(policy_type || borsh(params)).
+ * The PolicyParams variant tag is the PolicyType discriminant.
+ */
+export function proposalHashPda(program: Program<any>, policyParams: object): PublicKey {
+  const params = program.coder.types.encode("PolicyParams", policyParams);
+  const hash = createHash("sha256").update(Buffer.from([params[0]])).update(params).digest();
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal_hash"), hash],
+    program.programId
+  )[0];
+}
@@ -0,0 +1,11 @@
+import { createHash } from "crypto";
+
+/**
+ * ProposalMetadata for a description document: its sha256 and where it is published.
+ */
+export function proposalMetadata(description = "", uri = ""): { descriptionHash: number[]; uri: string } {
+  return {
+    descriptionHash: Array.from(createHash("sha256").update(description).digest()),
+    uri,
+  };
+}